@@ -0,0 +1,136 @@
+//! Chromatic adaptation, which predicts how a color seen under one white (illuminant) would need to change to
+//! look the same under another.
+//!
+//! The built-in conversions between [`LinearColorSpace`][crate::traits::LinearColorSpace]s already adapt between
+//! their white points. The functions here allow adapting between any two whites, given as a [`WhitePoint`] or as
+//! `xy` chromaticity coordinates, with a choice of [`AdaptationMethod`]. This is useful for white balancing.
+//!
+//! ```
+//! use colstodian::{Color, adaptation::*, temperature::planckian_xy};
+//! use colstodian::basic_encodings::LinearSrgb;
+//!
+//! // white balance a color captured under a 3200K tungsten light for display with a D65 white
+//! let tungsten = planckian_xy(3200.0);
+//! let captured = Color::linear_srgb(0.9, 0.6, 0.35);
+//! let balanced = captured.adapt(AdaptationMethod::Bradford, tungsten, WhitePoint::D65);
+//! assert!(balanced.b > captured.b);
+//! ```
+
+use crate::linear_spaces;
+use crate::traits::{LinearConvertFromRaw, LinearLight};
+use crate::Color;
+
+use glam::{Mat3, Vec2, Vec3};
+
+pub use kolor::details::color::WhitePoint;
+
+/// A method of chromatic adaptation. Each is a von Kries-style transform: the color is scaled by the ratio of the
+/// two whites in a space approximating the responses of the eye's cones, which is where the methods differ.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdaptationMethod {
+    /// The original von Kries transform, using the Hunt-Pointer-Estévez cone fundamentals.
+    VonKries,
+    /// The Bradford transform, the most widely used method, including by ICC color management.
+    Bradford,
+    /// The transform from the CIECAM02 color appearance model.
+    Cat02,
+    /// The transform from the CAM16 color appearance model, which fixes some issues of CAT02.
+    Cat16,
+}
+
+const fn from_rows(rows: [[f32; 3]; 3]) -> Mat3 {
+    Mat3::from_cols(
+        Vec3::new(rows[0][0], rows[1][0], rows[2][0]),
+        Vec3::new(rows[0][1], rows[1][1], rows[2][1]),
+        Vec3::new(rows[0][2], rows[1][2], rows[2][2]),
+    )
+}
+
+const VON_KRIES: Mat3 = from_rows([
+    [0.40024, 0.7076, -0.08081],
+    [-0.2263, 1.16532, 0.0457],
+    [0.0, 0.0, 0.91822],
+]);
+
+const BRADFORD: Mat3 = from_rows([
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+]);
+
+const CAT02: Mat3 = from_rows([
+    [0.7328, 0.4296, -0.1624],
+    [-0.7036, 1.6975, 0.0061],
+    [0.003, 0.0136, 0.9834],
+]);
+
+const CAT16: Mat3 = from_rows([
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+]);
+
+impl AdaptationMethod {
+    /// The matrix from CIE XYZ to the cone response space of this method.
+    #[inline]
+    pub const fn cone_matrix(self) -> Mat3 {
+        match self {
+            AdaptationMethod::VonKries => VON_KRIES,
+            AdaptationMethod::Bradford => BRADFORD,
+            AdaptationMethod::Cat02 => CAT02,
+            AdaptationMethod::Cat16 => CAT16,
+        }
+    }
+
+    /// The matrix which adapts CIE XYZ values seen under `src_white` to `dst_white`.
+    pub fn matrix(self, src_white: impl AdaptationWhite, dst_white: impl AdaptationWhite) -> Mat3 {
+        let cone = self.cone_matrix();
+        let src = cone * src_white.xyz();
+        let dst = cone * dst_white.xyz();
+        cone.inverse() * Mat3::from_diagonal(dst / src) * cone
+    }
+}
+
+/// Something which can be used as a source or destination white for chromatic adaptation: either a [`WhitePoint`],
+/// or the `xy` chromaticity coordinates of a white as a [`Vec2`].
+pub trait AdaptationWhite {
+    /// The CIE XYZ values of this white, normalized to a `Y` of `1.0`.
+    fn xyz(self) -> Vec3;
+}
+
+impl AdaptationWhite for WhitePoint {
+    #[inline]
+    fn xyz(self) -> Vec3 {
+        Vec3::from(*self.values())
+    }
+}
+
+impl AdaptationWhite for Vec2 {
+    #[inline]
+    fn xyz(self) -> Vec3 {
+        Vec3::new(self.x / self.y, 1.0, (1.0 - self.x - self.y) / self.y)
+    }
+}
+
+impl<E> Color<E>
+where
+    E: LinearLight,
+    linear_spaces::CieXYZ: LinearConvertFromRaw<E::LinearSpace>,
+    E::LinearSpace: LinearConvertFromRaw<linear_spaces::CieXYZ>,
+{
+    /// Adapts `self` from being seen under `src_white` to being seen under `dst_white`, using `method`.
+    ///
+    /// Any alpha component is left unchanged.
+    pub fn adapt(
+        self,
+        method: AdaptationMethod,
+        src_white: impl AdaptationWhite,
+        dst_white: impl AdaptationWhite,
+    ) -> Self {
+        let (mut raw, alpha) = E::src_transform_raw(self.repr);
+        <linear_spaces::CieXYZ as LinearConvertFromRaw<E::LinearSpace>>::linear_part_raw(&mut raw);
+        raw = method.matrix(src_white, dst_white) * raw;
+        <E::LinearSpace as LinearConvertFromRaw<linear_spaces::CieXYZ>>::linear_part_raw(&mut raw);
+        Color::from_repr(E::dst_transform_raw(raw, alpha))
+    }
+}
@@ -0,0 +1,160 @@
+//! Contrast metrics between foreground and background colors, for checking the accessibility of text and UI.
+//!
+//! Both metrics take their colors as anything convertible to [`SrgbU8`], since they are defined in terms of
+//! colors shown on an sRGB display.
+//!
+//! ```
+//! use colstodian::{Color, contrast::*};
+//! # use colstodian::equals_eps::*;
+//! # use colstodian::*;
+//!
+//! let text = Color::srgb_u8(0x88, 0x88, 0x88);
+//! let background = Color::srgb_u8(0xff, 0xff, 0xff);
+//!
+//! assert_eq_eps!(contrast_ratio_wcag21(text, background), 3.54, 0.01);
+//! assert_eq_eps!(apca_contrast(text, background), 63.06, 0.01);
+//!
+//! // darken the text until it passes WCAG AA for body text
+//! let adjusted = adjust_for_contrast(text, background, ContrastTarget::Wcag21(4.5)).unwrap();
+//! assert!(contrast_ratio_wcag21(adjusted, background) >= 4.5);
+//! ```
+
+use crate::encodings::{Oklab, SrgbU8};
+use crate::traits::RgbEncoding;
+use crate::{Color, ColorInto};
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use num_traits::float::Float;
+
+/// The contrast ratio between `a` and `b` as defined by WCAG 2.1, from `1.0` (no contrast) to `21.0`
+/// (black on white). The order of the colors does not matter.
+///
+/// WCAG 2.1 level AA requires a ratio of at least `4.5` for body text and `3.0` for large text.
+#[inline]
+pub fn contrast_ratio_wcag21(
+    a: impl ColorInto<Color<SrgbU8>>,
+    b: impl ColorInto<Color<SrgbU8>>,
+) -> f32 {
+    let a = a.color_into().luminance();
+    let b = b.color_into().luminance();
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// The APCA (Accessible Perceptual Contrast Algorithm, version 0.0.98G-4g) lightness contrast `Lc` of `text` over
+/// `background`, roughly from `-108.0` to `106.0`.
+///
+/// Unlike the WCAG 2.1 ratio, APCA depends on polarity: dark text on a light background gives a positive value,
+/// and light text on a dark background gives a negative value. `Lc` values of magnitude `75.0` are a common
+/// minimum for body text, and `60.0` for larger text.
+pub fn apca_contrast(
+    text: impl ColorInto<Color<SrgbU8>>,
+    background: impl ColorInto<Color<SrgbU8>>,
+) -> f32 {
+    let text = apca_luminance(text.color_into());
+    let background = apca_luminance(background.color_into());
+
+    if (background - text).abs() < 0.0005 {
+        return 0.0;
+    }
+
+    let contrast = if background > text {
+        let sapc = (background.powf(0.56) - text.powf(0.57)) * 1.14;
+        if sapc < 0.1 {
+            0.0
+        } else {
+            sapc - 0.027
+        }
+    } else {
+        let sapc = (background.powf(0.65) - text.powf(0.62)) * 1.14;
+        if sapc > -0.1 {
+            0.0
+        } else {
+            sapc + 0.027
+        }
+    };
+
+    contrast * 100.0
+}
+
+/// The screen luminance estimate used by APCA, including its soft clamp of near-black values.
+fn apca_luminance(color: Color<SrgbU8>) -> f32 {
+    let rgb = SrgbU8::encoded_rgb(color.repr);
+    let y = 0.2126729 * rgb.x.powf(2.4) + 0.7151522 * rgb.y.powf(2.4) + 0.072175 * rgb.z.powf(2.4);
+    if y < 0.022 {
+        y + (0.022 - y).powf(1.414)
+    } else {
+        y
+    }
+}
+
+/// A minimum contrast for [`adjust_for_contrast`] to reach.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ContrastTarget {
+    /// A minimum [`contrast_ratio_wcag21`].
+    Wcag21(f32),
+    /// A minimum magnitude of [`apca_contrast`], in either polarity.
+    Apca(f32),
+}
+
+impl ContrastTarget {
+    fn is_met_by(self, color: Color<Oklab>, background: Color<SrgbU8>) -> bool {
+        match self {
+            ContrastTarget::Wcag21(ratio) => contrast_ratio_wcag21(color, background) >= ratio,
+            ContrastTarget::Apca(lc) => apca_contrast(color, background).abs() >= lc,
+        }
+    }
+}
+
+/// Adjusts the Oklab lightness of `color` by as little as possible, keeping its hue and chroma, so that it meets
+/// `target` when shown over `background`.
+///
+/// Both making `color` lighter and darker are tried, and whichever needs the smaller change is returned. If `color`
+/// already meets `target` it is returned unchanged, and if no lightness can meet it, `None` is returned.
+pub fn adjust_for_contrast(
+    color: impl ColorInto<Color<Oklab>>,
+    background: impl ColorInto<Color<SrgbU8>>,
+    target: ContrastTarget,
+) -> Option<Color<Oklab>> {
+    let color: Color<Oklab> = color.color_into();
+    let background = background.color_into();
+
+    if target.is_met_by(color, background) {
+        return Some(color);
+    }
+
+    let with_lightness = |l: f32| Color::<Oklab>::oklab(l, color.a, color.b);
+
+    let search = |extreme: f32| {
+        if !target.is_met_by(with_lightness(extreme), background) {
+            return None;
+        }
+        // `failing` doesn't meet the target and `passing` does, so the closest passing lightness is between them.
+        let mut failing = color.l;
+        let mut passing = extreme;
+        for _ in 0..24 {
+            let mid = (failing + passing) * 0.5;
+            if target.is_met_by(with_lightness(mid), background) {
+                passing = mid;
+            } else {
+                failing = mid;
+            }
+        }
+        Some(passing)
+    };
+
+    let darker = search(0.0);
+    let lighter = search(1.0);
+    let l = match (darker, lighter) {
+        (Some(darker), Some(lighter)) => {
+            if color.l - darker <= lighter - color.l {
+                darker
+            } else {
+                lighter
+            }
+        }
+        (Some(l), None) | (None, Some(l)) => l,
+        (None, None) => return None,
+    };
+
+    Some(with_lightness(l))
+}
@@ -1,3 +1,4 @@
+use crate::reprs::*;
 use crate::traits::*;
 
 /*
@@ -115,6 +116,73 @@ impl<E: ColorEncoding> Color<E> {
     }
 }
 
+impl<E: ColorEncoding<Repr = U8Repr>> Color<E> {
+    /// Creates a [`Color`] from a packed `0xRRGGBB` integer. The top 8 bits are ignored.
+    ///
+    /// ```
+    /// # use colstodian::Color;
+    /// # use colstodian::basic_encodings::SrgbU8;
+    /// const CORNFLOWER: Color<SrgbU8> = Color::from_u32_rgb(0x6495ed);
+    ///
+    /// assert_eq!(CORNFLOWER, Color::srgb_u8(100, 149, 237));
+    /// ```
+    #[inline(always)]
+    pub const fn from_u32_rgb(rgb: u32) -> Self {
+        let [_, r, g, b] = rgb.to_be_bytes();
+        Self::from_repr([r, g, b])
+    }
+
+    /// Packs `self` into a `0xRRGGBB` integer. The top 8 bits are zero.
+    #[inline(always)]
+    pub const fn to_u32_rgb(self) -> u32 {
+        let [r, g, b] = self.repr;
+        u32::from_be_bytes([0, r, g, b])
+    }
+}
+
+impl<E: ColorEncoding<Repr = U8ARepr>> Color<E> {
+    /// Creates a [`Color`] from a packed `0xRRGGBBAA` integer.
+    ///
+    /// The components are taken as-is, so for a premultiplied encoding like
+    /// [`SrgbAU8Premultiplied`][crate::details::encodings::SrgbAU8Premultiplied], the color
+    /// components must already be premultiplied.
+    ///
+    /// ```
+    /// # use colstodian::Color;
+    /// # use colstodian::basic_encodings::SrgbAU8;
+    /// const TRANSLUCENT_RED: Color<SrgbAU8> = Color::from_u32_rgba(0xff000080);
+    ///
+    /// assert_eq!(TRANSLUCENT_RED, Color::srgba_u8(255, 0, 0, 128));
+    /// assert_eq!(TRANSLUCENT_RED.to_u32_argb(), 0x80ff0000);
+    /// ```
+    #[inline(always)]
+    pub const fn from_u32_rgba(rgba: u32) -> Self {
+        Self::from_repr(rgba.to_be_bytes())
+    }
+
+    /// Creates a [`Color`] from a packed `0xAARRGGBB` integer.
+    ///
+    /// See [`Color::from_u32_rgba`] for more.
+    #[inline(always)]
+    pub const fn from_u32_argb(argb: u32) -> Self {
+        let [a, r, g, b] = argb.to_be_bytes();
+        Self::from_repr([r, g, b, a])
+    }
+
+    /// Packs `self` into a `0xRRGGBBAA` integer.
+    #[inline(always)]
+    pub const fn to_u32_rgba(self) -> u32 {
+        u32::from_be_bytes(self.repr)
+    }
+
+    /// Packs `self` into a `0xAARRGGBB` integer.
+    #[inline(always)]
+    pub const fn to_u32_argb(self) -> u32 {
+        let [r, g, b, a] = self.repr;
+        u32::from_be_bytes([a, r, g, b])
+    }
+}
+
 impl<SrcEnc: ColorEncoding> Color<SrcEnc> {
     /// Converts `self` from one color encoding to another.
     ///
@@ -148,23 +216,7 @@ impl<SrcEnc: ColorEncoding> Color<SrcEnc> {
         DstEnc: ColorEncoding + ConvertFrom<SrcEnc>,
         DstEnc::LinearSpace: LinearConvertFromRaw<SrcEnc::LinearSpace>,
     {
-        let mut repr = self.repr;
-
-        // src conversion map
-        <DstEnc as ConvertFrom<SrcEnc>>::map_src(&mut repr);
-
-        // src transform
-        let (mut raw, alpha) = SrcEnc::src_transform_raw(self.repr);
-
-        // linear part
-        <DstEnc::LinearSpace as LinearConvertFromRaw<SrcEnc::LinearSpace>>::linear_part_raw(
-            &mut raw,
-        );
-
-        // dst transform
-        let dst_repr = DstEnc::dst_transform_raw(raw, alpha);
-
-        Color::from_repr(dst_repr)
+        Color::from_repr(<DstEnc as ConvertFrom<SrcEnc>>::convert_raw(self.repr))
     }
 
     /// Interprets this color as `DstEnc`. Requires that `DstEnc`'s `ColorEncoding::Repr` is the same as `self`'s.
@@ -184,6 +236,45 @@ impl<E: ColorEncoding + Saturate> Color<E> {
     }
 }
 
+impl<E> Color<E>
+where
+    E: ColorEncoding,
+    crate::linear_spaces::CieXYZ: LinearConvertFromRaw<E::LinearSpace>,
+{
+    /// The relative luminance of `self`, i.e. its CIE Y component, where the white of its
+    /// [`LinearColorSpace`] has a luminance of `1.0`.
+    ///
+    /// This is computed from the row of the RGB to XYZ matrix for `E`'s linear color space, so it is
+    /// correct for any set of primaries.
+    #[inline]
+    pub fn luminance(self) -> f32 {
+        let (mut raw, _) = E::src_transform_raw(self.repr);
+        <crate::linear_spaces::CieXYZ as LinearConvertFromRaw<E::LinearSpace>>::linear_part_raw(
+            &mut raw,
+        );
+        raw.y
+    }
+}
+
+impl<E: RgbEncoding> Color<E> {
+    /// The luma of `self`: the weighted sum of its encoded components, using the
+    /// [`LumaCoefficients`] of its encoding.
+    ///
+    /// Unlike [`luminance`][Color::luminance], luma is computed from gamma-compressed values and so is only
+    /// an approximation of brightness, but it is what video standards and many image processing
+    /// algorithms are defined in terms of.
+    #[inline]
+    pub fn luma(self) -> f32 {
+        self.luma_with(E::luma_coefficients())
+    }
+
+    /// The luma of `self`, using the given `coefficients` instead of those of its encoding.
+    #[inline]
+    pub fn luma_with(self, coefficients: LumaCoefficients) -> f32 {
+        E::encoded_rgb(self.repr).dot(coefficients.weights())
+    }
+}
+
 impl<E> Color<E>
 where
     E: ColorEncoding + AlphaOver,
@@ -193,6 +284,25 @@ where
     pub fn alpha_over(self, under: Self) -> Color<E> {
         <E as AlphaOver>::composite(self, under)
     }
+
+    /// Composite `self` as the source with `dst` as the destination, using the Porter-Duff operator `op`.
+    ///
+    /// ```
+    /// # use colstodian::*;
+    /// # use colstodian::equals_eps::*;
+    /// use colstodian::details::traits::PorterDuff;
+    ///
+    /// let mask = Color::linear_srgba(0.0, 0.0, 0.0, 0.5);
+    /// let image = Color::linear_srgba(0.2, 0.6, 1.0, 1.0);
+    ///
+    /// // keep the part of the image inside the mask
+    /// let masked = image.porter_duff(mask, PorterDuff::SourceIn);
+    /// assert_eq_eps!(masked, Color::linear_srgba(0.2, 0.6, 1.0, 0.5), 1e-6);
+    /// ```
+    #[inline(always)]
+    pub fn porter_duff(self, dst: Self, op: PorterDuff) -> Color<E> {
+        <E as AlphaOver>::porter_duff(self, dst, op)
+    }
 }
 
 impl<E> Color<E>
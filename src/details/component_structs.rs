@@ -47,6 +47,30 @@ unsafe impl ComponentStructFor<U8Repr> for Rgb<u8> {
     }
 }
 
+unsafe impl ComponentStructFor<U16Repr> for Rgb<u16> {
+    fn cast(repr: &U16Repr) -> &Self {
+        // SAFETY: [u16; 3] is guaranteed to have the same layout as Self
+        unsafe { &*(repr as *const U16Repr as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut U16Repr) -> &mut Self {
+        // SAFETY: [u16; 3] is guaranteed to have the same layout as Self
+        unsafe { &mut *(repr as *mut U16Repr as *mut Self) }
+    }
+}
+
+unsafe impl ComponentStructFor<F16Repr> for Rgb<F16> {
+    fn cast(repr: &F16Repr) -> &Self {
+        // SAFETY: [F16; 3] is guaranteed to have the same layout as Self
+        unsafe { &*(repr as *const F16Repr as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut F16Repr) -> &mut Self {
+        // SAFETY: [F16; 3] is guaranteed to have the same layout as Self
+        unsafe { &mut *(repr as *mut F16Repr as *mut Self) }
+    }
+}
+
 unsafe impl ComponentStructFor<F32Repr> for Rgb<f32> {
     fn cast(repr: &F32Repr) -> &Self {
         // SAFETY: Vec3 is guaranteed to have the same layout as Self
@@ -59,6 +83,18 @@ unsafe impl ComponentStructFor<F32Repr> for Rgb<f32> {
     }
 }
 
+unsafe impl ComponentStructFor<F64Repr> for Rgb<f64> {
+    fn cast(repr: &F64Repr) -> &Self {
+        // SAFETY: DVec3 is guaranteed to have the same layout as Self
+        unsafe { &*(repr as *const F64Repr as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut F64Repr) -> &mut Self {
+        // SAFETY: DVec3 is guaranteed to have the same layout as Self
+        unsafe { &mut *(repr as *mut F64Repr as *mut Self) }
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl<T: fmt::Display> fmt::Display for Rgb<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -96,6 +132,30 @@ unsafe impl ComponentStructFor<U8ARepr> for RgbA<u8> {
     }
 }
 
+unsafe impl ComponentStructFor<U16ARepr> for RgbA<u16> {
+    fn cast(repr: &U16ARepr) -> &Self {
+        // SAFETY: [u16; 4] is guaranteed to have the same layout as Self
+        unsafe { &*(repr as *const U16ARepr as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut U16ARepr) -> &mut Self {
+        // SAFETY: [u16; 4] is guaranteed to have the same layout as Self
+        unsafe { &mut *(repr as *mut U16ARepr as *mut Self) }
+    }
+}
+
+unsafe impl ComponentStructFor<F16ARepr> for RgbA<F16> {
+    fn cast(repr: &F16ARepr) -> &Self {
+        // SAFETY: [F16; 4] is guaranteed to have the same layout as Self
+        unsafe { &*(repr as *const F16ARepr as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut F16ARepr) -> &mut Self {
+        // SAFETY: [F16; 4] is guaranteed to have the same layout as Self
+        unsafe { &mut *(repr as *mut F16ARepr as *mut Self) }
+    }
+}
+
 unsafe impl ComponentStructFor<F32ARepr> for RgbA<f32> {
     fn cast(repr: &F32ARepr) -> &Self {
         // SAFETY: Vec4 is guaranteed to have the same layout as Self
@@ -108,6 +168,18 @@ unsafe impl ComponentStructFor<F32ARepr> for RgbA<f32> {
     }
 }
 
+unsafe impl ComponentStructFor<F64ARepr> for RgbA<f64> {
+    fn cast(repr: &F64ARepr) -> &Self {
+        // SAFETY: DVec4 is guaranteed to have the same layout as Self
+        unsafe { &*(repr as *const F64ARepr as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut F64ARepr) -> &mut Self {
+        // SAFETY: DVec4 is guaranteed to have the same layout as Self
+        unsafe { &mut *(repr as *mut F64ARepr as *mut Self) }
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl<T: fmt::Display> fmt::Display for RgbA<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -164,6 +236,18 @@ unsafe impl ComponentStructFor<F32Repr> for Lab<f32> {
     }
 }
 
+unsafe impl ComponentStructFor<F64Repr> for Lab<f64> {
+    fn cast(repr: &F64Repr) -> &Self {
+        // SAFETY: DVec3 is guaranteed to have the same layout as Self
+        unsafe { &*(repr as *const F64Repr as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut F64Repr) -> &mut Self {
+        // SAFETY: DVec3 is guaranteed to have the same layout as Self
+        unsafe { &mut *(repr as *mut F64Repr as *mut Self) }
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl<T: fmt::Display> fmt::Display for Lab<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -177,3 +261,79 @@ impl<T: fmt::Display> fmt::Debug for Lab<T> {
         write!(f, "L: {}, a: {}, b: {}", self.l, self.a, self.b)
     }
 }
+
+/// A bag of components with names I, Ct, Cp. Some `Color`s with ICtCp color encodings
+/// will `Deref`/`DerefMut` to this struct so that you can access their components with dot-syntax.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct ICtCp<T> {
+    pub i: T,
+    pub ct: T,
+    pub cp: T,
+}
+
+unsafe impl ComponentStructFor<F32Repr> for ICtCp<f32> {
+    fn cast(repr: &F32Repr) -> &Self {
+        // SAFETY: Vec3 is guaranteed to have the same layout as Self
+        unsafe { &*(repr as *const F32Repr as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut F32Repr) -> &mut Self {
+        // SAFETY: Vec3 is guaranteed to have the same layout as Self
+        unsafe { &mut *(repr as *mut F32Repr as *mut Self) }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<T: fmt::Display> fmt::Display for ICtCp<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "I: {:.3}, Ct: {:.3}, Cp: {:.3}",
+            self.i, self.ct, self.cp
+        )
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<T: fmt::Display> fmt::Debug for ICtCp<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "I: {}, Ct: {}, Cp: {}", self.i, self.ct, self.cp)
+    }
+}
+
+/// A bag of components with names X, Y, Z. Some `Color`s with CIE XYZ color encodings
+/// will `Deref`/`DerefMut` to this struct so that you can access their components with dot-syntax.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Xyz<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+unsafe impl ComponentStructFor<F32Repr> for Xyz<f32> {
+    fn cast(repr: &F32Repr) -> &Self {
+        // SAFETY: Vec3 is guaranteed to have the same layout as Self
+        unsafe { &*(repr as *const F32Repr as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut F32Repr) -> &mut Self {
+        // SAFETY: Vec3 is guaranteed to have the same layout as Self
+        unsafe { &mut *(repr as *mut F32Repr as *mut Self) }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<T: fmt::Display> fmt::Display for Xyz<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "X: {:.3}, Y: {:.3}, Z: {:.3}", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<T: fmt::Display> fmt::Debug for Xyz<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "X: {}, Y: {}, Z: {}", self.x, self.y, self.z)
+    }
+}
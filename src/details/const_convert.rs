@@ -0,0 +1,311 @@
+use crate::const_math;
+use crate::encodings::*;
+use crate::traits::*;
+use crate::Color;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Identifies the built-in encodings that [`Color::const_convert`] knows how to convert between.
+#[doc(hidden)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConstEncodingKind {
+    SrgbU8,
+    SrgbF32,
+    SrgbAU8,
+    SrgbAF32,
+    SrgbAU8Premultiplied,
+    LinearSrgb,
+    LinearSrgbA,
+    LinearSrgbAPremultiplied,
+    Oklab,
+}
+
+/// Implemented by color encodings which can be converted between in `const` contexts using
+/// [`Color::const_convert`].
+///
+/// This trait is sealed. It is implemented for the sRGB family of encodings ([`SrgbU8`], [`SrgbF32`],
+/// [`SrgbAU8`], [`SrgbAF32`], [`SrgbAU8Premultiplied`]), the linear sRGB encodings ([`LinearSrgb`],
+/// [`LinearSrgbA`], [`LinearSrgbAPremultiplied`]) and [`Oklab`].
+pub trait ConstConvert: ColorEncoding + private::Sealed {
+    #[doc(hidden)]
+    const KIND: ConstEncodingKind;
+}
+
+macro_rules! impl_const_convert {
+    ($($enc:ident),+) => {
+        $(
+            impl private::Sealed for $enc {}
+
+            impl ConstConvert for $enc {
+                const KIND: ConstEncodingKind = ConstEncodingKind::$enc;
+            }
+        )+
+    };
+}
+
+impl_const_convert!(
+    SrgbU8,
+    SrgbF32,
+    SrgbAU8,
+    SrgbAF32,
+    SrgbAU8Premultiplied,
+    LinearSrgb,
+    LinearSrgbA,
+    LinearSrgbAPremultiplied,
+    Oklab
+);
+
+/// Used to reinterpret a generic `Repr` as the concrete array type it is known to be
+/// layout-compatible with, since trait methods can't be called in `const fn`s.
+union Transmute<A: Copy, B: Copy> {
+    from: A,
+    to: B,
+}
+
+/// Reinterprets `from` as a `B`.
+///
+/// # Safety
+///
+/// `A` and `B` must have the same size and every bit pattern of `A` must be a valid `B`.
+const unsafe fn transmute<A: Copy, B: Copy>(from: A) -> B {
+    Transmute { from }.to
+}
+
+// Oklab matrices to and from linear sRGB, from https://bottosson.github.io/posts/oklab/
+const LINEAR_SRGB_TO_LMS: [[f64; 3]; 3] = [
+    [0.4122214708, 0.5363325363, 0.0514459929],
+    [0.2119034982, 0.6806995451, 0.1073969566],
+    [0.0883024619, 0.2817188376, 0.6299787005],
+];
+const LMS_TO_OKLAB: [[f64; 3]; 3] = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+const OKLAB_TO_LMS: [[f64; 3]; 3] = [
+    [1.0, 0.3963377774, 0.2158037573],
+    [1.0, -0.1055613458, -0.0638541728],
+    [1.0, -0.0894841775, -1.2914855480],
+];
+const LMS_TO_LINEAR_SRGB: [[f64; 3]; 3] = [
+    [4.0767416621, -3.3077115913, 0.2309699292],
+    [-1.2684380046, 2.6097574011, -0.3413193965],
+    [-0.0041960863, -0.7034186147, 1.7076147010],
+];
+
+const fn mul(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+const fn srgb_eotf(x: f64) -> f64 {
+    if x < 0.04045 {
+        x / 12.92
+    } else {
+        const_math::powf((x + 0.055) / 1.055, 2.4)
+    }
+}
+
+const fn srgb_oetf(x: f64) -> f64 {
+    if x < 0.0031308 {
+        x * 12.92
+    } else {
+        1.055 * const_math::powf(x, 1.0 / 2.4) - 0.055
+    }
+}
+
+const fn u8_to_f64(x: u8) -> f64 {
+    x as f64 / 255.0
+}
+
+// Matches the rounding of the runtime conversions, which work in `f32`
+const fn f64_to_u8(x: f64) -> u8 {
+    ((x as f32).clamp(0.0, 1.0) * 255.0) as u8
+}
+
+const fn f64_to_f32(v: [f64; 3]) -> [f32; 3] {
+    [v[0] as f32, v[1] as f32, v[2] as f32]
+}
+
+const fn f32_to_f64(v: [f32; 3]) -> [f64; 3] {
+    [v[0] as f64, v[1] as f64, v[2] as f64]
+}
+
+const fn decode_srgb(electro: [f64; 3]) -> [f64; 3] {
+    [
+        srgb_eotf(electro[0]),
+        srgb_eotf(electro[1]),
+        srgb_eotf(electro[2]),
+    ]
+}
+
+const fn encode_srgb(optical: [f64; 3]) -> [f64; 3] {
+    [
+        srgb_oetf(optical[0]),
+        srgb_oetf(optical[1]),
+        srgb_oetf(optical[2]),
+    ]
+}
+
+/// Divides premultiplied components by `alpha`, leaving fully transparent colors black like the runtime conversions.
+const fn divide(v: [f64; 3], alpha: f64) -> [f64; 3] {
+    if alpha > 0.0 {
+        [v[0] / alpha, v[1] / alpha, v[2] / alpha]
+    } else {
+        [0.0; 3]
+    }
+}
+
+const fn multiply(v: [f64; 3], alpha: f64) -> [f64; 3] {
+    [v[0] * alpha, v[1] * alpha, v[2] * alpha]
+}
+
+const fn oklab_to_linear_srgb(lab: [f64; 3]) -> [f64; 3] {
+    let [l, m, s] = mul(&OKLAB_TO_LMS, lab);
+    mul(&LMS_TO_LINEAR_SRGB, [l * l * l, m * m * m, s * s * s])
+}
+
+const fn linear_srgb_to_oklab(rgb: [f64; 3]) -> [f64; 3] {
+    let [l, m, s] = mul(&LINEAR_SRGB_TO_LMS, rgb);
+    mul(
+        &LMS_TO_OKLAB,
+        [
+            const_math::cbrt(l),
+            const_math::cbrt(m),
+            const_math::cbrt(s),
+        ],
+    )
+}
+
+/// Decodes `repr` to linear sRGB and a separate (not premultiplied) alpha.
+const fn decode<E: ConstConvert>(repr: E::Repr) -> ([f64; 3], f64) {
+    // SAFETY: each `ConstEncodingKind` is only used by encodings with the matching `Repr`, and
+    // `Vec3` and `Vec4` have the same layout as `[f32; 3]` and `[f32; 4]`
+    unsafe {
+        match E::KIND {
+            ConstEncodingKind::SrgbU8 => {
+                let [r, g, b] = transmute::<E::Repr, [u8; 3]>(repr);
+                (decode_srgb([u8_to_f64(r), u8_to_f64(g), u8_to_f64(b)]), 1.0)
+            }
+            ConstEncodingKind::SrgbF32 => {
+                let rgb = transmute::<E::Repr, [f32; 3]>(repr);
+                (decode_srgb(f32_to_f64(rgb)), 1.0)
+            }
+            ConstEncodingKind::SrgbAU8 => {
+                let [r, g, b, a] = transmute::<E::Repr, [u8; 4]>(repr);
+                let rgb = decode_srgb([u8_to_f64(r), u8_to_f64(g), u8_to_f64(b)]);
+                (rgb, u8_to_f64(a))
+            }
+            ConstEncodingKind::SrgbAF32 => {
+                let [r, g, b, a] = transmute::<E::Repr, [f32; 4]>(repr);
+                (decode_srgb(f32_to_f64([r, g, b])), a as f64)
+            }
+            ConstEncodingKind::SrgbAU8Premultiplied => {
+                let [r, g, b, a] = transmute::<E::Repr, [u8; 4]>(repr);
+                let a = u8_to_f64(a);
+                let rgb = decode_srgb([u8_to_f64(r), u8_to_f64(g), u8_to_f64(b)]);
+                (divide(rgb, a), a)
+            }
+            ConstEncodingKind::LinearSrgb => {
+                let rgb = transmute::<E::Repr, [f32; 3]>(repr);
+                (f32_to_f64(rgb), 1.0)
+            }
+            ConstEncodingKind::LinearSrgbA => {
+                let [r, g, b, a] = transmute::<E::Repr, [f32; 4]>(repr);
+                (f32_to_f64([r, g, b]), a as f64)
+            }
+            ConstEncodingKind::LinearSrgbAPremultiplied => {
+                let [r, g, b, a] = transmute::<E::Repr, [f32; 4]>(repr);
+                let a = a as f64;
+                (divide(f32_to_f64([r, g, b]), a), a)
+            }
+            ConstEncodingKind::Oklab => {
+                let lab = transmute::<E::Repr, [f32; 3]>(repr);
+                (oklab_to_linear_srgb(f32_to_f64(lab)), 1.0)
+            }
+        }
+    }
+}
+
+/// Encodes linear sRGB and a separate alpha into `E::Repr`.
+const fn encode<E: ConstConvert>(rgb: [f64; 3], alpha: f64) -> E::Repr {
+    // SAFETY: see `decode`
+    unsafe {
+        match E::KIND {
+            ConstEncodingKind::SrgbU8 => {
+                let [r, g, b] = encode_srgb(rgb);
+                transmute::<[u8; 3], E::Repr>([f64_to_u8(r), f64_to_u8(g), f64_to_u8(b)])
+            }
+            ConstEncodingKind::SrgbF32 => {
+                transmute::<[f32; 3], E::Repr>(f64_to_f32(encode_srgb(rgb)))
+            }
+            ConstEncodingKind::SrgbAU8 => {
+                let [r, g, b] = encode_srgb(rgb);
+                transmute::<[u8; 4], E::Repr>([
+                    f64_to_u8(r),
+                    f64_to_u8(g),
+                    f64_to_u8(b),
+                    f64_to_u8(alpha),
+                ])
+            }
+            ConstEncodingKind::SrgbAF32 => {
+                let [r, g, b] = f64_to_f32(encode_srgb(rgb));
+                transmute::<[f32; 4], E::Repr>([r, g, b, alpha as f32])
+            }
+            ConstEncodingKind::SrgbAU8Premultiplied => {
+                let [r, g, b] = encode_srgb(multiply(rgb, alpha));
+                transmute::<[u8; 4], E::Repr>([
+                    f64_to_u8(r),
+                    f64_to_u8(g),
+                    f64_to_u8(b),
+                    f64_to_u8(alpha),
+                ])
+            }
+            ConstEncodingKind::LinearSrgb => transmute::<[f32; 3], E::Repr>(f64_to_f32(rgb)),
+            ConstEncodingKind::LinearSrgbA => {
+                let [r, g, b] = f64_to_f32(rgb);
+                transmute::<[f32; 4], E::Repr>([r, g, b, alpha as f32])
+            }
+            ConstEncodingKind::LinearSrgbAPremultiplied => {
+                let [r, g, b] = f64_to_f32(multiply(rgb, alpha));
+                transmute::<[f32; 4], E::Repr>([r, g, b, alpha as f32])
+            }
+            ConstEncodingKind::Oklab => {
+                transmute::<[f32; 3], E::Repr>(f64_to_f32(linear_srgb_to_oklab(rgb)))
+            }
+        }
+    }
+}
+
+impl<SrcEnc: ConstConvert> Color<SrcEnc> {
+    /// Converts `self` from one color encoding to another in a way that can be evaluated in `const` contexts.
+    ///
+    /// This gives the same results as [`convert`][Color::convert] (within floating point error), but is only
+    /// available for encodings that implement [`ConstConvert`]. Since `core`'s float math is not `const`, the
+    /// transfer functions are computed with `const`-friendly series approximations in `f64`, so prefer
+    /// [`convert`][Color::convert] outside of `const` contexts.
+    ///
+    /// ```
+    /// # use colstodian::*;
+    /// # use colstodian::details::encodings::*;
+    /// # use colstodian::equals_eps::*;
+    /// const BG: Color<LinearSrgb> = Color::srgb_u8(102, 51, 153).const_convert();
+    /// const BG_OKLAB: Color<Oklab> = BG.const_convert();
+    ///
+    /// assert_eq_eps!(BG, Color::linear_srgb(0.13287, 0.0331, 0.31855), 0.0001);
+    /// assert_eq_eps!(BG_OKLAB, BG.convert::<Oklab>(), 0.0001);
+    /// ```
+    pub const fn const_convert<DstEnc>(self) -> Color<DstEnc>
+    where
+        DstEnc: ConstConvert + ConvertFrom<SrcEnc>,
+        DstEnc::LinearSpace: LinearConvertFromRaw<SrcEnc::LinearSpace>,
+    {
+        let (rgb, alpha) = decode::<SrcEnc>(self.repr);
+        Color::from_repr(encode::<DstEnc>(rgb, alpha))
+    }
+}
@@ -0,0 +1,162 @@
+//! Math routines which can be evaluated in `const` contexts.
+//!
+//! `core`'s float functions like `sin` and `powf` are not `const`, so these are implemented
+//! directly with series expansions and range reduction in `f64`, then rounded to `f32` by the caller.
+
+use core::f64::consts::{LN_2, PI, SQRT_2, TAU};
+
+/// Computes `(sin(x), cos(x))` for `x` in radians.
+pub(crate) const fn sin_cos(x: f64) -> (f64, f64) {
+    // reduce to [-pi, pi], where the taylor series below converges quickly
+    let mut x = x % TAU;
+    if x > PI {
+        x -= TAU;
+    } else if x < -PI {
+        x += TAU;
+    }
+
+    let x2 = x * x;
+    let mut sin = 0.0;
+    let mut cos = 0.0;
+    let mut sin_term = x;
+    let mut cos_term = 1.0;
+    let mut n = 0;
+    while n < 20 {
+        sin += sin_term;
+        cos += cos_term;
+        let k = (2 * n + 2) as f64;
+        sin_term *= -x2 / (k * (k + 1.0));
+        cos_term *= -x2 / ((k - 1.0) * k);
+        n += 1;
+    }
+
+    (sin, cos)
+}
+
+/// Computes `e^x`.
+pub(crate) const fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.8 {
+        return f64::INFINITY;
+    }
+    if x < -745.2 {
+        return 0.0;
+    }
+
+    // x = k * ln(2) + r, with |r| <= ln(2) / 2
+    let k = round(x / LN_2);
+    let r = x - k * LN_2;
+
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut n = 1;
+    while n < 24 {
+        term *= r / n as f64;
+        sum += term;
+        n += 1;
+    }
+
+    scale_by_pow2(sum, k as i32)
+}
+
+/// Computes the natural logarithm of `x`.
+pub(crate) const fn ln(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return x;
+    }
+
+    // x = m * 2^e, with m in [sqrt(2) / 2, sqrt(2)]
+    let (mut m, mut e) = if x < f64::MIN_POSITIVE {
+        frexp(x * 18014398509481984.0) // 2^54
+    } else {
+        frexp(x)
+    };
+    if x < f64::MIN_POSITIVE {
+        e -= 54;
+    }
+    if m > SQRT_2 {
+        m *= 0.5;
+        e += 1;
+    }
+
+    // ln(m) = 2 * atanh(s), where s = (m - 1) / (m + 1) and |s| < 0.172
+    let s = (m - 1.0) / (m + 1.0);
+    let s2 = s * s;
+    let mut sum = 0.0;
+    let mut power = s;
+    let mut n = 0;
+    while n < 16 {
+        sum += power / (2 * n + 1) as f64;
+        power *= s2;
+        n += 1;
+    }
+
+    e as f64 * LN_2 + 2.0 * sum
+}
+
+/// Computes `x^y` for `x >= 0`. Negative `x` gives `NaN`.
+pub(crate) const fn powf(x: f64, y: f64) -> f64 {
+    if x == 0.0 {
+        return if y > 0.0 { 0.0 } else { f64::INFINITY };
+    }
+    exp(y * ln(x))
+}
+
+/// Computes the cube root of `x`, preserving its sign.
+pub(crate) const fn cbrt(x: f64) -> f64 {
+    if x == 0.0 || x.is_nan() || x.is_infinite() {
+        return x;
+    }
+    let a = if x < 0.0 { -x } else { x };
+    let mut y = powf(a, 1.0 / 3.0);
+    // one newton step to clean up the error from exp/ln
+    y -= (y * y * y - a) / (3.0 * y * y);
+    if x < 0.0 {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Rounds half away from zero. Only valid for `|x| < 2^52`.
+const fn round(x: f64) -> f64 {
+    let truncated = x as i64 as f64;
+    let frac = x - truncated;
+    if frac >= 0.5 {
+        truncated + 1.0
+    } else if frac <= -0.5 {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+/// Splits a positive, normal `x` into `(m, e)` such that `x = m * 2^e` and `m` is in `[1, 2)`.
+const fn frexp(x: f64) -> (f64, i32) {
+    let bits = x.to_bits();
+    let e = ((bits >> 52) & 0x7ff) as i32 - 1023;
+    let m = f64::from_bits((bits & !(0x7ff << 52)) | (1023 << 52));
+    (m, e)
+}
+
+/// Computes `x * 2^k`.
+const fn scale_by_pow2(mut x: f64, mut k: i32) -> f64 {
+    // keep each step within the range of normal powers of two
+    while k > 1023 {
+        x *= f64::from_bits(2046 << 52); // 2^1023
+        k -= 1023;
+    }
+    while k < -1022 {
+        x *= f64::from_bits(1 << 52); // 2^-1022
+        k += 1022;
+    }
+    x * f64::from_bits(((k + 1023) as u64) << 52)
+}
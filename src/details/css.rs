@@ -0,0 +1,1114 @@
+use crate::encodings::*;
+use crate::error::CssParseError;
+use crate::hex::parse_hex;
+use crate::linear_spaces::CieXYZ;
+use crate::named;
+use crate::traits::*;
+use crate::Color;
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use glam::DVec3;
+use glam::Mat3;
+use glam::Vec3;
+use kolor::details::color::WhitePoint;
+use kolor::details::generated_matrices as matrices;
+use kolor::details::transform;
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use num_traits::float::Float;
+
+/// The color spaces which can be expressed in CSS Color Level 4 syntax.
+///
+/// The variant names mirror the CSS functions and `color()` space identifiers that produce them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CssColorSpace {
+    /// `rgb()`, `rgba()`, hex colors, named colors and `color(srgb ...)`.
+    ///
+    /// Components are stored in the `0.0..=1.0` range.
+    Srgb,
+    /// `color(srgb-linear ...)`
+    SrgbLinear,
+    /// `hsl()` and `hsla()`. Components are hue in degrees, and saturation and lightness in `0.0..=100.0`.
+    Hsl,
+    /// `hwb()`. Components are hue in degrees, and whiteness and blackness in `0.0..=100.0`.
+    Hwb,
+    /// `lab()`, i.e. CIELAB with a D50 white point.
+    Lab,
+    /// `lch()`, i.e. the polar form of CIELAB with a D50 white point. Hue is in degrees.
+    Lch,
+    /// `oklab()`
+    Oklab,
+    /// `oklch()`. Hue is in degrees.
+    Oklch,
+    /// `color(display-p3 ...)`
+    DisplayP3,
+    /// `color(a98-rgb ...)`
+    A98Rgb,
+    /// `color(prophoto-rgb ...)`
+    ProphotoRgb,
+    /// `color(rec2020 ...)`
+    Rec2020,
+    /// `color(xyz-d50 ...)`
+    XyzD50,
+    /// `color(xyz ...)` and `color(xyz-d65 ...)`
+    XyzD65,
+}
+
+impl CssColorSpace {
+    const COLOR_FUNCTION_SPACES: [(&'static str, CssColorSpace); 9] = [
+        ("srgb", CssColorSpace::Srgb),
+        ("srgb-linear", CssColorSpace::SrgbLinear),
+        ("display-p3", CssColorSpace::DisplayP3),
+        ("a98-rgb", CssColorSpace::A98Rgb),
+        ("prophoto-rgb", CssColorSpace::ProphotoRgb),
+        ("rec2020", CssColorSpace::Rec2020),
+        ("xyz-d50", CssColorSpace::XyzD50),
+        ("xyz-d65", CssColorSpace::XyzD65),
+        ("xyz", CssColorSpace::XyzD65),
+    ];
+
+    fn from_color_function_ident(ident: &str) -> Option<Self> {
+        Self::COLOR_FUNCTION_SPACES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(ident))
+            .map(|&(_, space)| space)
+    }
+
+    fn color_function_ident(self) -> Option<&'static str> {
+        Self::COLOR_FUNCTION_SPACES
+            .iter()
+            .find(|&&(_, space)| space == self)
+            .map(|&(name, _)| name)
+    }
+}
+
+/// A color parsed from CSS, kept in the color space it was written in.
+///
+/// This is the result of parsing any CSS Color Level 4 color syntax. It can be converted into a strongly-typed
+/// [`Color`] with [`DynamicColor::convert`], or written back out as CSS with its `Display` impl.
+///
+/// Components which were specified as the `none` keyword are stored as `None`. They behave as `0` when the
+/// color is converted, as required by the CSS spec, but are preserved when serializing.
+///
+/// ```
+/// # use colstodian::*;
+/// # use colstodian::basic_encodings::*;
+/// # use colstodian::details::css::*;
+/// # use colstodian::equals_eps::*;
+/// let parsed: DynamicColor = "oklch(62.8% 0.2577 29.23deg / 50%)".parse().unwrap();
+///
+/// assert_eq!(parsed.space, CssColorSpace::Oklch);
+/// assert_eq!(parsed.alpha, Some(0.5));
+///
+/// let col = parsed.convert::<LinearSrgbA>();
+/// assert_eq_eps!(col, Color::linear_srgba(1.0, 0.0, 0.0, 0.5), 0.001);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynamicColor {
+    /// The color space the components are expressed in.
+    pub space: CssColorSpace,
+    /// The color components, in the order and ranges of the CSS syntax for `space`.
+    pub components: [Option<f32>; 3],
+    /// The alpha component in `0.0..=1.0`.
+    pub alpha: Option<f32>,
+}
+
+impl DynamicColor {
+    /// Parses any CSS Color Level 4 color.
+    ///
+    /// Supported syntax includes hex colors, named colors, `transparent`, and the `rgb()`, `rgba()`, `hsl()`, `hsla()`,
+    /// `hwb()`, `lab()`, `lch()`, `oklab()`, `oklch()` and `color()` functions, with both the legacy comma-separated
+    /// and modern space-separated syntaxes where CSS allows them. `currentcolor` and relative colors are not
+    /// supported as they require context from outside the string.
+    pub fn parse_css(s: &str) -> Result<Self, CssParseError> {
+        let s = s.trim();
+
+        if s.starts_with('#') {
+            let [r, g, b, a] = parse_hex(s, true).map_err(CssParseError::Hex)?;
+            return Ok(Self::srgb_u8(r, g, b, a));
+        }
+
+        let open = match s.find('(') {
+            Some(open) => open,
+            None => return parse_keyword(s),
+        };
+
+        let name = &s[..open];
+        let body = match s[open + 1..].strip_suffix(')') {
+            Some(body) => body,
+            None => return Err(CssParseError::InvalidSyntax),
+        };
+        let tokens = Tokens::new(body)?;
+
+        let function = FUNCTIONS
+            .iter()
+            .find(|(fn_name, _)| fn_name.eq_ignore_ascii_case(name))
+            .map(|&(_, function)| function)
+            .ok_or(CssParseError::UnknownFunction)?;
+
+        match function {
+            Function::Rgb => parse_rgb(&tokens),
+            Function::Hsl => parse_hsl(&tokens),
+            Function::Hwb => parse_hwb(&tokens),
+            Function::Lab => parse_lab_like(&tokens, CssColorSpace::Lab, 100.0, 125.0),
+            Function::Oklab => parse_lab_like(&tokens, CssColorSpace::Oklab, 1.0, 0.4),
+            Function::Lch => parse_lch_like(&tokens, CssColorSpace::Lch, 100.0, 150.0),
+            Function::Oklch => parse_lch_like(&tokens, CssColorSpace::Oklch, 1.0, 0.4),
+            Function::Color => parse_color_function(&tokens),
+        }
+    }
+
+    fn srgb_u8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self {
+            space: CssColorSpace::Srgb,
+            components: [
+                Some(r as f32 / 255.0),
+                Some(g as f32 / 255.0),
+                Some(b as f32 / 255.0),
+            ],
+            alpha: Some(a as f32 / 255.0),
+        }
+    }
+
+    /// Converts `self` into a [`Color`] in the encoding `DstEnc`.
+    ///
+    /// The conversion is done through the CIE XYZ color space, so `DstEnc`'s linear space must be convertible
+    /// from [`CieXYZ`]. Colors which are out of gamut for `DstEnc` are handled as they would be by
+    /// [`Color::convert`].
+    pub fn convert<DstEnc>(&self) -> Color<DstEnc>
+    where
+        DstEnc: ColorEncoding,
+        DstEnc::LinearSpace: LinearConvertFromRaw<CieXYZ>,
+    {
+        let mut raw = self.to_xyz_d65();
+        <DstEnc::LinearSpace as LinearConvertFromRaw<CieXYZ>>::linear_part_raw(&mut raw);
+        Color::from_repr(DstEnc::dst_transform_raw(raw, self.alpha.unwrap_or(0.0)))
+    }
+
+    /// Gets the components of `self` converted to the CIE XYZ color space with a D65 white point.
+    fn to_xyz_d65(self) -> Vec3 {
+        let [c0, c1, c2] = self.components;
+        let v = Vec3::new(c0.unwrap_or(0.0), c1.unwrap_or(0.0), c2.unwrap_or(0.0));
+
+        match self.space {
+            CssColorSpace::Srgb => matrices::BT_709_D65_TO_CIE_XYZ_D65 * srgb_eotf_signed(v),
+            CssColorSpace::SrgbLinear => matrices::BT_709_D65_TO_CIE_XYZ_D65 * v,
+            CssColorSpace::Hsl => {
+                let rgb = hsl_to_srgb(v.x, v.y / 100.0, v.z / 100.0);
+                matrices::BT_709_D65_TO_CIE_XYZ_D65 * srgb_eotf_signed(rgb)
+            }
+            CssColorSpace::Hwb => {
+                let rgb = hwb_to_srgb(v.x, v.y / 100.0, v.z / 100.0);
+                matrices::BT_709_D65_TO_CIE_XYZ_D65 * srgb_eotf_signed(rgb)
+            }
+            CssColorSpace::Lab => D50_TO_D65 * lab_to_xyz_d50(v),
+            CssColorSpace::Lch => D50_TO_D65 * lab_to_xyz_d50(polar_to_rectangular(v)),
+            CssColorSpace::Oklab => transform::Oklab_to_XYZ(v, WhitePoint::D65),
+            CssColorSpace::Oklch => {
+                transform::Oklab_to_XYZ(polar_to_rectangular(v), WhitePoint::D65)
+            }
+            CssColorSpace::DisplayP3 => matrices::P3_D65_TO_CIE_XYZ_D65 * srgb_eotf_signed(v),
+            CssColorSpace::A98Rgb => {
+                let linear = map_signed(v, |x| x.powf(563.0 / 256.0));
+                matrices::ADOBE_1998_D65_TO_CIE_XYZ_D65 * linear
+            }
+            CssColorSpace::ProphotoRgb => {
+                let linear = map_signed(v, |x| {
+                    if x <= 16.0 / 512.0 {
+                        x / 16.0
+                    } else {
+                        x.powf(1.8)
+                    }
+                });
+                D50_TO_D65 * (PROPHOTO_TO_XYZ_D50 * linear)
+            }
+            CssColorSpace::Rec2020 => {
+                let linear = map_signed(v, |x| {
+                    if x < REC2020_BETA * 4.5 {
+                        x / 4.5
+                    } else {
+                        ((x + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45)
+                    }
+                });
+                matrices::BT_2020_D65_TO_CIE_XYZ_D65 * linear
+            }
+            CssColorSpace::XyzD50 => D50_TO_D65 * v,
+            CssColorSpace::XyzD65 => v,
+        }
+    }
+}
+
+impl FromStr for DynamicColor {
+    type Err = CssParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_css(s)
+    }
+}
+
+impl TryFrom<&str> for DynamicColor {
+    type Error = CssParseError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse_css(s)
+    }
+}
+
+/// Writes `self` in the modern CSS syntax for its color space.
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for DynamicColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [c0, c1, c2] = self.components;
+        match self.space {
+            CssColorSpace::Srgb => {
+                let scale = |c: Option<f32>| c.map(|c| c * 255.0);
+                f.write_str("rgb(")?;
+                write_components(f, [scale(c0), scale(c1), scale(c2)], ["", "", ""])?;
+            }
+            CssColorSpace::Hsl => {
+                f.write_str("hsl(")?;
+                write_components(f, self.components, ["", "%", "%"])?;
+            }
+            CssColorSpace::Hwb => {
+                f.write_str("hwb(")?;
+                write_components(f, self.components, ["", "%", "%"])?;
+            }
+            CssColorSpace::Lab => {
+                f.write_str("lab(")?;
+                write_components(f, self.components, ["", "", ""])?;
+            }
+            CssColorSpace::Lch => {
+                f.write_str("lch(")?;
+                write_components(f, self.components, ["", "", ""])?;
+            }
+            CssColorSpace::Oklab => {
+                f.write_str("oklab(")?;
+                write_components(f, self.components, ["", "", ""])?;
+            }
+            CssColorSpace::Oklch => {
+                f.write_str("oklch(")?;
+                write_components(f, self.components, ["", "", ""])?;
+            }
+            space => {
+                // all remaining spaces are expressed through `color()`
+                let ident = space.color_function_ident().unwrap_or("srgb");
+                write!(f, "color({} ", ident)?;
+                write_components(f, self.components, ["", "", ""])?;
+            }
+        }
+        match self.alpha {
+            Some(alpha) if alpha >= 1.0 => {}
+            alpha => {
+                f.write_str(" / ")?;
+                write_component(f, alpha, "")?;
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+fn write_component(f: &mut fmt::Formatter<'_>, c: Option<f32>, unit: &str) -> fmt::Result {
+    match c {
+        Some(c) => write!(f, "{}{}", c, unit),
+        None => f.write_str("none"),
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+fn write_components(
+    f: &mut fmt::Formatter<'_>,
+    components: [Option<f32>; 3],
+    units: [&str; 3],
+) -> fmt::Result {
+    for (i, (c, unit)) in components.into_iter().zip(units).enumerate() {
+        if i > 0 {
+            f.write_str(" ")?;
+        }
+        write_component(f, c, unit)?;
+    }
+    Ok(())
+}
+
+impl<E> Color<E>
+where
+    E: ColorEncoding,
+    E::LinearSpace: LinearConvertFromRaw<CieXYZ>,
+{
+    /// Parses any CSS Color Level 4 color and converts it into `self`'s encoding.
+    ///
+    /// See [`DynamicColor::parse_css`] for the supported syntax.
+    ///
+    /// ```
+    /// # use colstodian::*;
+    /// # use colstodian::details::encodings::*;
+    /// # use colstodian::equals_eps::*;
+    /// let col = Color::<SrgbF32>::from_css("hsl(270deg 50% 40%)").unwrap();
+    /// assert_eq_eps!(col, Color::srgb_f32(0.4, 0.2, 0.6), 0.0001);
+    ///
+    /// let col = Color::<LinearSrgb>::from_css("rebeccapurple").unwrap();
+    /// assert_eq_eps!(col, Color::srgb_u8(102, 51, 153).convert(), 0.0001);
+    /// ```
+    pub fn from_css(s: &str) -> Result<Self, CssParseError> {
+        DynamicColor::parse_css(s).map(|dynamic| dynamic.convert())
+    }
+}
+
+/// Implemented by color encodings which can be written out as a CSS color.
+///
+/// This covers the sRGB, linear sRGB, Oklab, CIELAB and CIE XYZ encodings, including their `f64` versions, and
+/// every [`Encoded`] encoding in the [`Srgb`][crate::linear_spaces::Srgb],
+/// [`DisplayP3`][crate::linear_spaces::DisplayP3], [`AdobeRgb`][crate::linear_spaces::AdobeRgb],
+/// [`ProPhotoRgb`][crate::linear_spaces::ProPhotoRgb] and [`Bt2020`][crate::linear_spaces::Bt2020] linear spaces.
+/// Encodings with no CSS equivalent, such as [`ICtCpPq`] and the scene-referred and camera log encodings, don't
+/// implement it; convert them into one of the encodings above first.
+pub trait ToCss: ColorEncoding {
+    /// Writes `color` as CSS.
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Formats a [`Color`] as CSS through its `Display` impl. Created by [`Color::to_css`].
+pub struct Css<E: ColorEncoding>(Color<E>);
+
+#[cfg(not(target_arch = "spirv"))]
+impl<E: ToCss> fmt::Display for Css<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        E::fmt_css(self.0, f)
+    }
+}
+
+impl<E: ToCss> Color<E> {
+    /// Returns a value which formats `self` as a CSS color through its `Display` impl.
+    ///
+    /// Each encoding is written in the CSS form that represents it most directly. For example,
+    /// [`SrgbU8`] is written as `rgb()`, [`LinearSrgb`] is written as `color(srgb-linear ...)` and an [`Encoded`]
+    /// encoding in the Display P3 space is written as `color(display-p3 ...)` re-encoded with the sRGB transfer
+    /// function that CSS uses for it. [`CieLab`] is adapted to the D50 white point of CSS `lab()`.
+    ///
+    /// ```
+    /// # use colstodian::*;
+    /// # use colstodian::basic_encodings::*;
+    /// assert_eq!(Color::srgb_u8(102, 51, 153).to_css().to_string(), "rgb(102 51 153)");
+    /// assert_eq!(
+    ///     Color::srgba_u8(102, 51, 153, 128).to_css().to_string(),
+    ///     "rgb(102 51 153 / 0.5)",
+    /// );
+    /// assert_eq!(
+    ///     Color::linear_srgb(0.5, 0.25, 1.0).to_css().to_string(),
+    ///     "color(srgb-linear 0.5 0.25 1)",
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_css(self) -> Css<E> {
+        Css(self)
+    }
+}
+
+/// Finds the shortest decimal form of an 8 bit alpha value that round-trips back to the same value.
+#[cfg(not(target_arch = "spirv"))]
+fn u8_alpha_to_f32(a: u8) -> f32 {
+    let two_places = (a as f32 / 2.55).round() / 100.0;
+    if (two_places * 255.0).round() as u8 == a {
+        two_places
+    } else {
+        (a as f32 / 0.255).round() / 1000.0
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+fn fmt_css_u8(f: &mut fmt::Formatter<'_>, [r, g, b, a]: [u8; 4]) -> fmt::Result {
+    if a == 255 {
+        write!(f, "rgb({} {} {})", r, g, b)
+    } else {
+        write!(f, "rgb({} {} {} / {})", r, g, b, u8_alpha_to_f32(a))
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+fn fmt_css_f32(f: &mut fmt::Formatter<'_>, prefix: &str, c: Vec3, alpha: f32) -> fmt::Result {
+    write!(f, "{}{} {} {}", prefix, c.x, c.y, c.z)?;
+    if alpha < 1.0 {
+        write!(f, " / {}", alpha)?;
+    }
+    f.write_str(")")
+}
+
+#[cfg(not(target_arch = "spirv"))]
+fn fmt_css_f64(f: &mut fmt::Formatter<'_>, prefix: &str, c: DVec3, alpha: f64) -> fmt::Result {
+    write!(f, "{}{} {} {}", prefix, c.x, c.y, c.z)?;
+    if alpha < 1.0 {
+        write!(f, " / {}", alpha)?;
+    }
+    f.write_str(")")
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for SrgbU8 {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [r, g, b] = color.repr;
+        fmt_css_u8(f, [r, g, b, 255])
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for SrgbAU8 {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_u8(f, color.repr)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for SrgbAU8Premultiplied {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_u8(f, color.convert::<SrgbAU8>().repr)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for SrgbF32 {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_f32(f, "color(srgb ", color.repr, 1.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for SrgbAF32 {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_f32(f, "color(srgb ", color.repr.truncate(), color.repr.w)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for LinearSrgb {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_f32(f, "color(srgb-linear ", color.repr, 1.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for LinearSrgbA {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_f32(f, "color(srgb-linear ", color.repr.truncate(), color.repr.w)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for LinearSrgbAPremultiplied {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        LinearSrgbA::fmt_css(color.convert(), f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for Oklab {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_f32(f, "oklab(", color.repr, 1.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for CieLab {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // CSS `lab()` is relative to D50 rather than D65
+        let (xyz, _) = CieLab::src_transform_raw(color.repr);
+        let lab = xyz_d50_to_lab(D50_TO_D65.inverse() * xyz);
+        fmt_css_f32(f, "lab(", lab, 1.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for CieXyz {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_f32(f, "color(xyz-d65 ", color.repr, 1.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for SrgbF64 {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_f64(f, "color(srgb ", color.repr, 1.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for SrgbAF64 {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_f64(f, "color(srgb ", color.repr.truncate(), color.repr.w)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for LinearSrgbF64 {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_f64(f, "color(srgb-linear ", color.repr, 1.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for LinearSrgbAF64 {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_f64(f, "color(srgb-linear ", color.repr.truncate(), color.repr.w)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for LinearSrgbAPremultipliedF64 {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        LinearSrgbAF64::fmt_css(color.convert(), f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl ToCss for OklabF64 {
+    fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_css_f64(f, "oklab(", color.repr, 1.0)
+    }
+}
+
+/// Implements [`ToCss`] for every [`Encoded`] encoding in a linear space which has a CSS `color()` equivalent, by
+/// decoding it and re-encoding it with the transfer function CSS specifies for that space.
+macro_rules! impl_encoded_to_css {
+    ($($space:ident => $prefix:literal, $encode:expr;)*) => {
+        $(
+            #[cfg(not(target_arch = "spirv"))]
+            impl<Tf, Repr, Alpha> ToCss for Encoded<crate::linear_spaces::$space, Tf, Repr, Alpha>
+            where
+                Tf: TransferFunction,
+                Repr: EncodedRepr<Alpha>,
+                Alpha: AlphaMode,
+            {
+                fn fmt_css(color: Color<Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let (linear, alpha) = Self::src_transform_raw(color.repr);
+                    fmt_css_f32(f, $prefix, map_signed(linear, $encode), alpha)
+                }
+            }
+        )*
+    };
+}
+
+impl_encoded_to_css! {
+    Srgb => "color(srgb ", srgb_oetf;
+    DisplayP3 => "color(display-p3 ", srgb_oetf;
+    AdobeRgb => "color(a98-rgb ", |x: f32| x.powf(256.0 / 563.0);
+    ProPhotoRgb => "color(prophoto-rgb ", |x: f32| {
+        if x < 1.0 / 512.0 {
+            x * 16.0
+        } else {
+            x.powf(1.0 / 1.8)
+        }
+    };
+    Bt2020 => "color(rec2020 ", |x: f32| {
+        if x < REC2020_BETA {
+            x * 4.5
+        } else {
+            REC2020_ALPHA * x.powf(0.45) - (REC2020_ALPHA - 1.0)
+        }
+    };
+}
+
+// --------- PARSING -----------
+
+#[derive(Clone, Copy)]
+enum Function {
+    Rgb,
+    Hsl,
+    Hwb,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+    Color,
+}
+
+const FUNCTIONS: [(&str, Function); 10] = [
+    ("rgb", Function::Rgb),
+    ("rgba", Function::Rgb),
+    ("hsl", Function::Hsl),
+    ("hsla", Function::Hsl),
+    ("hwb", Function::Hwb),
+    ("lab", Function::Lab),
+    ("lch", Function::Lch),
+    ("oklab", Function::Oklab),
+    ("oklch", Function::Oklch),
+    ("color", Function::Color),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Separator {
+    Space,
+    Comma,
+    Slash,
+}
+
+const MAX_TOKENS: usize = 5;
+
+/// The whitespace, comma or slash separated tokens inside a CSS color function.
+struct Tokens<'a> {
+    tokens: [&'a str; MAX_TOKENS],
+    /// `separators[i]` is the separator that came before `tokens[i]`
+    separators: [Separator; MAX_TOKENS],
+    len: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(body: &'a str) -> Result<Self, CssParseError> {
+        let is_separator = |b: u8| b.is_ascii_whitespace() || b == b',' || b == b'/';
+
+        let mut out = Tokens {
+            tokens: [""; MAX_TOKENS],
+            separators: [Separator::Space; MAX_TOKENS],
+            len: 0,
+        };
+
+        let bytes = body.as_bytes();
+        let mut i = 0;
+        loop {
+            let mut separator = Separator::Space;
+            while i < bytes.len() && is_separator(bytes[i]) {
+                match bytes[i] {
+                    b',' | b'/' if separator != Separator::Space => {
+                        return Err(CssParseError::InvalidSyntax)
+                    }
+                    b',' => separator = Separator::Comma,
+                    b'/' => separator = Separator::Slash,
+                    _ => {}
+                }
+                i += 1;
+            }
+
+            if i == bytes.len() {
+                if separator != Separator::Space {
+                    return Err(CssParseError::InvalidSyntax);
+                }
+                break;
+            }
+
+            if out.len == MAX_TOKENS || (out.len == 0 && separator != Separator::Space) {
+                return Err(CssParseError::InvalidSyntax);
+            }
+
+            let start = i;
+            while i < bytes.len() && !is_separator(bytes[i]) {
+                i += 1;
+            }
+
+            out.tokens[out.len] = &body[start..i];
+            out.separators[out.len] = separator;
+            out.len += 1;
+        }
+
+        Ok(out)
+    }
+
+    /// Splits the tokens starting at `first` into three components and an optional alpha, validating the
+    /// separators between them. Returns whether the legacy comma-separated syntax was used.
+    fn color_args(
+        &self,
+        first: usize,
+        allow_legacy: bool,
+    ) -> Result<([&'a str; 3], Option<&'a str>, bool), CssParseError> {
+        let count = self.len.saturating_sub(first);
+        if count != 3 && count != 4 {
+            return Err(CssParseError::InvalidSyntax);
+        }
+
+        let separators = &self.separators[first + 1..self.len];
+        let legacy = separators[0] == Separator::Comma;
+        let valid = if legacy {
+            allow_legacy && separators.iter().all(|&s| s == Separator::Comma)
+        } else {
+            separators[..2].iter().all(|&s| s == Separator::Space)
+                && separators[2..].iter().all(|&s| s == Separator::Slash)
+        };
+        if !valid {
+            return Err(CssParseError::InvalidSyntax);
+        }
+
+        let t = &self.tokens[first..self.len];
+        let alpha = if count == 4 { Some(t[3]) } else { None };
+        Ok(([t[0], t[1], t[2]], alpha, legacy))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Value {
+    Number(f32),
+    Percentage(f32),
+    /// An angle, in degrees.
+    Angle(f32),
+    None,
+}
+
+impl Value {
+    fn parse(token: &str, index: usize) -> Result<Self, CssParseError> {
+        let err = CssParseError::InvalidComponent { index };
+
+        if token.eq_ignore_ascii_case("none") {
+            return Ok(Value::None);
+        }
+
+        let bytes = token.as_bytes();
+        let digits_from = |mut i: usize| {
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            i
+        };
+
+        // Scan a CSS <number> token: sign, integer part, fraction, exponent
+        let mut end = 0;
+        if end < bytes.len() && (bytes[end] == b'+' || bytes[end] == b'-') {
+            end += 1;
+        }
+        let int_end = digits_from(end);
+        let mut mantissa_digits = int_end - end;
+        end = int_end;
+        if end < bytes.len() && bytes[end] == b'.' {
+            let frac_end = digits_from(end + 1);
+            mantissa_digits += frac_end - (end + 1);
+            end = frac_end;
+        }
+        if mantissa_digits == 0 {
+            return Err(err);
+        }
+        if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+            let mut exp = end + 1;
+            if exp < bytes.len() && (bytes[exp] == b'+' || bytes[exp] == b'-') {
+                exp += 1;
+            }
+            let exp_end = digits_from(exp);
+            if exp_end > exp {
+                end = exp_end;
+            }
+        }
+
+        let number: f32 = token[..end].parse().map_err(|_| err)?;
+        let unit = &token[end..];
+
+        let value = match unit {
+            "" => Value::Number(number),
+            "%" => Value::Percentage(number),
+            _ if unit.eq_ignore_ascii_case("deg") => Value::Angle(number),
+            _ if unit.eq_ignore_ascii_case("rad") => Value::Angle(number.to_degrees()),
+            _ if unit.eq_ignore_ascii_case("grad") => Value::Angle(number * 0.9),
+            _ if unit.eq_ignore_ascii_case("turn") => Value::Angle(number * 360.0),
+            _ => return Err(err),
+        };
+
+        Ok(value)
+    }
+
+    /// Resolves a number or percentage, where `100%` is equal to `percent_ref`.
+    fn number(self, percent_ref: f32, index: usize) -> Result<Option<f32>, CssParseError> {
+        match self {
+            Value::Number(n) => Ok(Some(n)),
+            Value::Percentage(p) => Ok(Some(p / 100.0 * percent_ref)),
+            Value::None => Ok(None),
+            Value::Angle(_) => Err(CssParseError::InvalidComponent { index }),
+        }
+    }
+
+    /// Resolves a hue, which is either a unitless number of degrees or an angle, normalized to `0.0..360.0`.
+    fn hue(self, index: usize) -> Result<Option<f32>, CssParseError> {
+        match self {
+            Value::Number(deg) | Value::Angle(deg) => {
+                let deg = deg % 360.0;
+                Ok(Some(if deg < 0.0 { deg + 360.0 } else { deg }))
+            }
+            Value::None => Ok(None),
+            Value::Percentage(_) => Err(CssParseError::InvalidComponent { index }),
+        }
+    }
+}
+
+fn parse_values(
+    tokens: [&str; 3],
+    alpha: Option<&str>,
+    legacy: bool,
+) -> Result<([Value; 3], Option<f32>), CssParseError> {
+    let mut values = [Value::None; 3];
+    for (i, token) in tokens.iter().enumerate() {
+        values[i] = Value::parse(token, i)?;
+        if legacy && values[i] == Value::None {
+            return Err(CssParseError::InvalidComponent { index: i });
+        }
+    }
+
+    let alpha = match alpha {
+        Some(token) => {
+            let value = Value::parse(token, 3)?;
+            if legacy && value == Value::None {
+                return Err(CssParseError::InvalidComponent { index: 3 });
+            }
+            value.number(1.0, 3)?.map(|a| a.clamp(0.0, 1.0))
+        }
+        None => Some(1.0),
+    };
+
+    Ok((values, alpha))
+}
+
+fn parse_keyword(s: &str) -> Result<DynamicColor, CssParseError> {
+    if s.eq_ignore_ascii_case("transparent") {
+        return Ok(DynamicColor::srgb_u8(0, 0, 0, 0));
+    }
+
+    named::ALL
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(s))
+        .map(|&(_, color)| DynamicColor::srgb_u8(color.r, color.g, color.b, 255))
+        .ok_or(CssParseError::UnknownKeyword)
+}
+
+fn parse_rgb(tokens: &Tokens<'_>) -> Result<DynamicColor, CssParseError> {
+    let (comps, alpha, legacy) = tokens.color_args(0, true)?;
+    let (values, alpha) = parse_values(comps, alpha, legacy)?;
+
+    if legacy {
+        let percentages = matches!(values[0], Value::Percentage(_));
+        for (i, v) in values.iter().enumerate() {
+            if matches!(v, Value::Percentage(_)) != percentages {
+                return Err(CssParseError::InvalidComponent { index: i });
+            }
+        }
+    }
+
+    let mut components = [None; 3];
+    for (i, v) in values.iter().enumerate() {
+        components[i] = v.number(255.0, i)?.map(|c| c.clamp(0.0, 255.0) / 255.0);
+    }
+
+    Ok(DynamicColor {
+        space: CssColorSpace::Srgb,
+        components,
+        alpha,
+    })
+}
+
+fn parse_hsl(tokens: &Tokens<'_>) -> Result<DynamicColor, CssParseError> {
+    let (comps, alpha, legacy) = tokens.color_args(0, true)?;
+    let (values, alpha) = parse_values(comps, alpha, legacy)?;
+
+    if legacy {
+        for (i, v) in values.iter().enumerate().skip(1) {
+            if !matches!(v, Value::Percentage(_)) {
+                return Err(CssParseError::InvalidComponent { index: i });
+            }
+        }
+    }
+
+    Ok(DynamicColor {
+        space: CssColorSpace::Hsl,
+        components: [
+            values[0].hue(0)?,
+            values[1].number(100.0, 1)?.map(|s| s.max(0.0)),
+            values[2].number(100.0, 2)?.map(|l| l.clamp(0.0, 100.0)),
+        ],
+        alpha,
+    })
+}
+
+fn parse_hwb(tokens: &Tokens<'_>) -> Result<DynamicColor, CssParseError> {
+    let (comps, alpha, _) = tokens.color_args(0, false)?;
+    let (values, alpha) = parse_values(comps, alpha, false)?;
+
+    Ok(DynamicColor {
+        space: CssColorSpace::Hwb,
+        components: [
+            values[0].hue(0)?,
+            values[1].number(100.0, 1)?.map(|w| w.clamp(0.0, 100.0)),
+            values[2].number(100.0, 2)?.map(|b| b.clamp(0.0, 100.0)),
+        ],
+        alpha,
+    })
+}
+
+fn parse_lab_like(
+    tokens: &Tokens<'_>,
+    space: CssColorSpace,
+    lightness_ref: f32,
+    ab_ref: f32,
+) -> Result<DynamicColor, CssParseError> {
+    let (comps, alpha, _) = tokens.color_args(0, false)?;
+    let (values, alpha) = parse_values(comps, alpha, false)?;
+
+    Ok(DynamicColor {
+        space,
+        components: [
+            values[0]
+                .number(lightness_ref, 0)?
+                .map(|l| l.clamp(0.0, lightness_ref)),
+            values[1].number(ab_ref, 1)?,
+            values[2].number(ab_ref, 2)?,
+        ],
+        alpha,
+    })
+}
+
+fn parse_lch_like(
+    tokens: &Tokens<'_>,
+    space: CssColorSpace,
+    lightness_ref: f32,
+    chroma_ref: f32,
+) -> Result<DynamicColor, CssParseError> {
+    let (comps, alpha, _) = tokens.color_args(0, false)?;
+    let (values, alpha) = parse_values(comps, alpha, false)?;
+
+    Ok(DynamicColor {
+        space,
+        components: [
+            values[0]
+                .number(lightness_ref, 0)?
+                .map(|l| l.clamp(0.0, lightness_ref)),
+            values[1].number(chroma_ref, 1)?.map(|c| c.max(0.0)),
+            values[2].hue(2)?,
+        ],
+        alpha,
+    })
+}
+
+fn parse_color_function(tokens: &Tokens<'_>) -> Result<DynamicColor, CssParseError> {
+    if tokens.len == 0 {
+        return Err(CssParseError::InvalidSyntax);
+    }
+    let space = CssColorSpace::from_color_function_ident(tokens.tokens[0])
+        .ok_or(CssParseError::UnknownColorSpace)?;
+
+    if tokens.len > 1 && tokens.separators[1] != Separator::Space {
+        return Err(CssParseError::InvalidSyntax);
+    }
+
+    let (comps, alpha, _) = tokens.color_args(1, false)?;
+    let (values, alpha) = parse_values(comps, alpha, false)?;
+
+    Ok(DynamicColor {
+        space,
+        components: [
+            values[0].number(1.0, 0)?,
+            values[1].number(1.0, 1)?,
+            values[2].number(1.0, 2)?,
+        ],
+        alpha,
+    })
+}
+
+// --------- CONVERSION HELPERS -----------
+
+/// The Bradford chromatic adaptation from D50 to D65 used by CSS Color Level 4.
+#[rustfmt::skip]
+const D50_TO_D65: Mat3 = Mat3::from_cols_array(&[
+    0.955_473_4, -0.028_369_71, 0.012_314_015,
+    -0.023_098_455, 1.009_995_4, -0.020_507_65,
+    0.063_259_244, 0.021_041_441, 1.330_365_9,
+]);
+
+/// The linear ProPhoto RGB to CIE XYZ (D50) matrix used by CSS Color Level 4.
+#[rustfmt::skip]
+const PROPHOTO_TO_XYZ_D50: Mat3 = Mat3::from_cols_array(&[
+    0.797_766_6, 0.288_074_83, 0.0,
+    0.135_181_3, 0.711_835_2, 0.0,
+    0.031_347_733, 0.000_089_936_94, 0.825_104_6,
+]);
+
+/// The D50 white point as defined by CSS Color Level 4.
+const D50_WHITE: Vec3 = Vec3::new(0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585);
+
+/// The constants of the BT.2020 transfer function, as used by CSS Color Level 4's `rec2020` space.
+const REC2020_ALPHA: f32 = 1.099_296_8;
+const REC2020_BETA: f32 = 0.018_053_97;
+
+#[inline]
+fn map_signed(v: Vec3, f: impl Fn(f32) -> f32) -> Vec3 {
+    let map = |x: f32| if x < 0.0 { -f(-x) } else { f(x) };
+    Vec3::new(map(v.x), map(v.y), map(v.z))
+}
+
+#[inline]
+fn srgb_oetf(x: f32) -> f32 {
+    if x <= 0.003_130_8 {
+        x * 12.92
+    } else {
+        1.055 * x.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[inline]
+fn srgb_eotf_signed(v: Vec3) -> Vec3 {
+    map_signed(v, |x| {
+        if x <= 0.04045 {
+            x / 12.92
+        } else {
+            ((x + 0.055) / 1.055).powf(2.4)
+        }
+    })
+}
+
+fn hsl_to_srgb(hue: f32, saturation: f32, lightness: f32) -> Vec3 {
+    let f = |n: f32| {
+        let k = (n + hue / 30.0) % 12.0;
+        let a = saturation * lightness.min(1.0 - lightness);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    Vec3::new(f(0.0), f(8.0), f(4.0))
+}
+
+fn hwb_to_srgb(hue: f32, whiteness: f32, blackness: f32) -> Vec3 {
+    if whiteness + blackness >= 1.0 {
+        return Vec3::splat(whiteness / (whiteness + blackness));
+    }
+    hsl_to_srgb(hue, 1.0, 0.5) * (1.0 - whiteness - blackness) + Vec3::splat(whiteness)
+}
+
+fn lab_to_xyz_d50(lab: Vec3) -> Vec3 {
+    const KAPPA: f32 = 24389.0 / 27.0;
+    const EPSILON: f32 = 216.0 / 24389.0;
+
+    let f1 = (lab.x + 16.0) / 116.0;
+    let f0 = lab.y / 500.0 + f1;
+    let f2 = f1 - lab.z / 200.0;
+
+    let inverse_f = |f: f32| {
+        let cubed = f * f * f;
+        if cubed > EPSILON {
+            cubed
+        } else {
+            (116.0 * f - 16.0) / KAPPA
+        }
+    };
+
+    let y = if lab.x > KAPPA * EPSILON {
+        f1 * f1 * f1
+    } else {
+        lab.x / KAPPA
+    };
+
+    Vec3::new(inverse_f(f0), y, inverse_f(f2)) * D50_WHITE
+}
+
+fn xyz_d50_to_lab(xyz: Vec3) -> Vec3 {
+    const KAPPA: f32 = 24389.0 / 27.0;
+    const EPSILON: f32 = 216.0 / 24389.0;
+
+    let f = |x: f32| {
+        if x > EPSILON {
+            x.cbrt()
+        } else {
+            (KAPPA * x + 16.0) / 116.0
+        }
+    };
+    let v = xyz / D50_WHITE;
+    let (f0, f1, f2) = (f(v.x), f(v.y), f(v.z));
+
+    Vec3::new(116.0 * f1 - 16.0, 500.0 * (f0 - f1), 200.0 * (f1 - f2))
+}
+
+/// Converts `[lightness, chroma, hue]` to `[lightness, a, b]`.
+fn polar_to_rectangular(lch: Vec3) -> Vec3 {
+    let (sin, cos) = lch.z.to_radians().sin_cos();
+    Vec3::new(lch.x, lch.y * cos, lch.y * sin)
+}
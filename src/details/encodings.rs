@@ -2,14 +2,25 @@ use crate::component_structs::*;
 use crate::linear_spaces;
 use crate::reprs::*;
 use crate::traits::*;
+use crate::transfer_functions;
 use crate::Color;
 
+use core::marker::PhantomData;
+
+use crate::matrices_f64;
+
+use glam::DMat3;
+use glam::DVec3;
+use glam::DVec4;
 use glam::Vec3;
 use glam::Vec4;
 use glam::Vec4Swizzles;
 use kolor::details::color::WhitePoint;
 use kolor::details::transform;
 
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use num_traits::float::Float;
+
 #[inline(always)]
 fn u8_to_f32(x: u8) -> f32 {
     x as f32 / 255.0
@@ -20,6 +31,54 @@ fn f32_to_u8(x: f32) -> u8 {
     (x.clamp(0.0, 1.0) * 255.0) as u8
 }
 
+/// Divides premultiplied color components by their alpha, leaving fully transparent colors black rather than NaN.
+#[inline(always)]
+fn unpremultiply(premultiplied: Vec3, alpha: f32) -> Vec3 {
+    if alpha > 0.0 {
+        premultiplied / alpha
+    } else {
+        Vec3::ZERO
+    }
+}
+
+/// Composites two colors by way of their premultiplied, linear values, as in the default
+/// [`AlphaOver::porter_duff`].
+pub(crate) fn porter_duff_raw<E: ColorEncoding>(
+    src: Color<E>,
+    dst: Color<E>,
+    op: PorterDuff,
+) -> Color<E> {
+    let premultiplied = |color: Color<E>| {
+        let (linear, alpha) = E::src_transform_raw(color.repr);
+        (linear * alpha).extend(alpha)
+    };
+    let comp = op.apply(premultiplied(src), premultiplied(dst));
+    Color::from_repr(E::dst_transform_raw(
+        unpremultiply(comp.xyz(), comp.w),
+        comp.w,
+    ))
+}
+
+/// The `f64` version of [`unpremultiply`].
+#[inline(always)]
+fn unpremultiply_f64(premultiplied: DVec3, alpha: f64) -> DVec3 {
+    if alpha > 0.0 {
+        premultiplied / alpha
+    } else {
+        DVec3::ZERO
+    }
+}
+
+#[inline(always)]
+fn u16_to_f32(x: u16) -> f32 {
+    x as f32 / 65535.0
+}
+
+#[inline(always)]
+fn f32_to_u16(x: f32) -> u16 {
+    (x.clamp(0.0, 1.0) * 65535.0) as u16
+}
+
 #[doc = include_str!("descriptions/srgb_u8.md")]
 pub struct SrgbU8;
 
@@ -49,13 +108,13 @@ impl ColorEncoding for SrgbU8 {
     fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
         let [x, y, z] = repr;
         let raw_electro = Vec3::new(u8_to_f32(x), u8_to_f32(y), u8_to_f32(z));
-        let optical = transform::sRGB_eotf(raw_electro, WhitePoint::D65);
+        let optical = transfer_functions::Srgb::decode(raw_electro);
         (optical, 1.0)
     }
 
     #[inline]
     fn dst_transform_raw(raw: glam::Vec3, _: f32) -> Self::Repr {
-        let electro = transform::sRGB_oetf(raw, WhitePoint::D65);
+        let electro = transfer_functions::Srgb::encode(raw);
         let repr = [
             f32_to_u8(electro.x),
             f32_to_u8(electro.y),
@@ -74,6 +133,9 @@ impl ConvertFrom<LinearSrgbA> for SrgbU8 {}
 impl ConvertFrom<LinearSrgbAPremultiplied> for SrgbU8 {}
 // TODO: oklab gamut clipping
 impl ConvertFrom<Oklab> for SrgbU8 {}
+impl ConvertFrom<CieXyz> for SrgbU8 {}
+impl ConvertFrom<CieLab> for SrgbU8 {}
+impl ConvertFrom<ICtCpPq> for SrgbU8 {}
 
 #[doc = include_str!("descriptions/srgb_f32.md")]
 pub struct SrgbF32;
@@ -102,13 +164,13 @@ impl ColorEncoding for SrgbF32 {
 
     #[inline]
     fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
-        let optical = transform::sRGB_eotf(repr, WhitePoint::D65);
+        let optical = transfer_functions::Srgb::decode(repr);
         (optical, 1.0)
     }
 
     #[inline]
     fn dst_transform_raw(raw: glam::Vec3, _: f32) -> Self::Repr {
-        let electro = transform::sRGB_oetf(raw, WhitePoint::D65);
+        let electro = transfer_functions::Srgb::encode(raw);
         electro
     }
 }
@@ -122,6 +184,9 @@ impl ConvertFrom<LinearSrgbA> for SrgbF32 {}
 impl ConvertFrom<LinearSrgbAPremultiplied> for SrgbF32 {}
 // TODO: oklab gamut clipping
 impl ConvertFrom<Oklab> for SrgbF32 {}
+impl ConvertFrom<CieXyz> for SrgbF32 {}
+impl ConvertFrom<CieLab> for SrgbF32 {}
+impl ConvertFrom<ICtCpPq> for SrgbF32 {}
 
 #[doc = include_str!("descriptions/srgba_u8.md")]
 pub struct SrgbAU8;
@@ -152,14 +217,14 @@ impl ColorEncoding for SrgbAU8 {
     fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
         let [x, y, z, a] = repr;
         let raw_electro = Vec3::new(u8_to_f32(x), u8_to_f32(y), u8_to_f32(z));
-        let optical = transform::sRGB_eotf(raw_electro, WhitePoint::D65);
+        let optical = transfer_functions::Srgb::decode(raw_electro);
         let a = u8_to_f32(a);
         (optical, a)
     }
 
     #[inline]
     fn dst_transform_raw(raw: glam::Vec3, alpha: f32) -> Self::Repr {
-        let electro = transform::sRGB_oetf(raw, WhitePoint::D65);
+        let electro = transfer_functions::Srgb::encode(raw);
         let repr = [
             f32_to_u8(electro.x),
             f32_to_u8(electro.y),
@@ -179,6 +244,18 @@ impl ConvertFrom<LinearSrgbA> for SrgbAU8 {}
 impl ConvertFrom<LinearSrgbAPremultiplied> for SrgbAU8 {}
 // TODO: oklab gamut clipping
 impl ConvertFrom<Oklab> for SrgbAU8 {}
+impl ConvertFrom<CieXyz> for SrgbAU8 {}
+impl ConvertFrom<CieLab> for SrgbAU8 {}
+impl ConvertFrom<ICtCpPq> for SrgbAU8 {}
+
+impl AlphaOver for SrgbAU8 {
+    fn composite(over: Color<Self>, under: Color<Self>) -> Color<Self> {
+        let over = over.convert::<LinearSrgbAPremultiplied>();
+        let under = under.convert::<LinearSrgbAPremultiplied>();
+        let comp = over.alpha_over(under);
+        comp.convert::<Self>()
+    }
+}
 
 #[doc = include_str!("descriptions/srgba_f32.md")]
 pub struct SrgbAF32;
@@ -207,13 +284,13 @@ impl ColorEncoding for SrgbAF32 {
 
     #[inline]
     fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
-        let optical = transform::sRGB_eotf(repr.xyz(), WhitePoint::D65);
+        let optical = transfer_functions::Srgb::decode(repr.xyz());
         (optical, repr.w)
     }
 
     #[inline]
     fn dst_transform_raw(raw: glam::Vec3, alpha: f32) -> Self::Repr {
-        let electro = transform::sRGB_oetf(raw, WhitePoint::D65);
+        let electro = transfer_functions::Srgb::encode(raw);
         electro.extend(alpha)
     }
 }
@@ -227,6 +304,18 @@ impl ConvertFrom<LinearSrgbA> for SrgbAF32 {}
 impl ConvertFrom<LinearSrgbAPremultiplied> for SrgbAF32 {}
 // TODO: oklab gamut clipping
 impl ConvertFrom<Oklab> for SrgbAF32 {}
+impl ConvertFrom<CieXyz> for SrgbAF32 {}
+impl ConvertFrom<CieLab> for SrgbAF32 {}
+impl ConvertFrom<ICtCpPq> for SrgbAF32 {}
+
+impl AlphaOver for SrgbAF32 {
+    fn composite(over: Color<Self>, under: Color<Self>) -> Color<Self> {
+        let over = over.convert::<LinearSrgbAPremultiplied>();
+        let under = under.convert::<LinearSrgbAPremultiplied>();
+        let comp = over.alpha_over(under);
+        comp.convert::<Self>()
+    }
+}
 
 /// The fully-encoded form of the sRGB color encoding standard, with *premultiplied* alpha component.
 ///
@@ -254,16 +343,16 @@ impl ColorEncoding for SrgbAU8Premultiplied {
     fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
         let [x, y, z, a] = repr;
         let raw_electro = Vec3::new(u8_to_f32(x), u8_to_f32(y), u8_to_f32(z));
-        let optical = transform::sRGB_eotf(raw_electro, WhitePoint::D65);
+        let optical = transfer_functions::Srgb::decode(raw_electro);
         let a = u8_to_f32(a);
-        let separated = optical / a;
+        let separated = unpremultiply(optical, a);
         (separated, a)
     }
 
     #[inline]
     fn dst_transform_raw(raw: glam::Vec3, alpha: f32) -> Self::Repr {
         let premultiplied = raw * alpha;
-        let electro = transform::sRGB_oetf(premultiplied, WhitePoint::D65);
+        let electro = transfer_functions::Srgb::encode(premultiplied);
         let repr = [
             f32_to_u8(electro.x),
             f32_to_u8(electro.y),
@@ -283,6 +372,9 @@ impl ConvertFrom<LinearSrgbA> for SrgbAU8Premultiplied {}
 impl ConvertFrom<LinearSrgbAPremultiplied> for SrgbAU8Premultiplied {}
 // TODO: oklab gamut clipping
 impl ConvertFrom<Oklab> for SrgbAU8Premultiplied {}
+impl ConvertFrom<CieXyz> for SrgbAU8Premultiplied {}
+impl ConvertFrom<CieLab> for SrgbAU8Premultiplied {}
+impl ConvertFrom<ICtCpPq> for SrgbAU8Premultiplied {}
 
 impl AlphaOver for SrgbAU8Premultiplied {
     fn composite(over: Color<Self>, under: Color<Self>) -> Color<Self> {
@@ -302,6 +394,11 @@ impl AlphaOver for SrgbAU8Premultiplied {
 /// component where the alpha component varies independently of the color components, you have [`LinearSrgbA`] values.
 /// If you have four values with an alpha component and the rgb components are modified directly when the alpha component
 /// changes as well, you have [`LinearSrgbAPremultiplied`] values.
+///
+/// This is a [`DisplayReferred`] encoding, so values outside of `0.0..=1.0` are clipped when converting to
+/// encodings like [`SrgbU8`]. Values with unbounded brightness, such as the output of lighting calculations,
+/// should be held in [`SceneLinearSrgb`] instead, which can only reach display encodings through a
+/// [`Tonemapper`][crate::tonemap::Tonemapper] or an explicit clamp.
 pub struct LinearSrgb;
 
 impl Color<LinearSrgb> {
@@ -310,7 +407,7 @@ impl Color<LinearSrgb> {
     /// If you're not sure, you should probably use [`Color::srgb_f32`] instead.
     /// See [the `LinearSrgb` encoding docs][LinearSrgb] for more info.
     #[inline(always)]
-    pub fn linear_srgb(r: f32, g: f32, b: f32) -> Self {
+    pub const fn linear_srgb(r: f32, g: f32, b: f32) -> Self {
         Color::from_repr(Vec3::new(r, g, b))
     }
 }
@@ -344,8 +441,12 @@ impl ConvertFrom<LinearSrgbA> for LinearSrgb {}
 impl ConvertFrom<LinearSrgbAPremultiplied> for LinearSrgb {}
 // TODO: oklab gamut clipping
 impl ConvertFrom<Oklab> for LinearSrgb {}
+impl ConvertFrom<CieXyz> for LinearSrgb {}
+impl ConvertFrom<CieLab> for LinearSrgb {}
+impl ConvertFrom<ICtCpPq> for LinearSrgb {}
 
 impl WorkingEncoding for LinearSrgb {}
+impl LinearLight for LinearSrgb {}
 
 /// The linear form of the sRGB color encoding standard with a separate alpha component.
 ///
@@ -364,7 +465,7 @@ impl Color<LinearSrgbA> {
     /// If you're not sure, you should probably use [`Color::srgba_f32`] instead.
     /// See [the `LinearSrgbA` encoding docs][LinearSrgbA] for more info.
     #[inline(always)]
-    pub fn linear_srgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+    pub const fn linear_srgba(r: f32, g: f32, b: f32, a: f32) -> Self {
         Color::from_repr(Vec4::new(r, g, b, a))
     }
 }
@@ -398,8 +499,12 @@ impl ConvertFrom<LinearSrgb> for LinearSrgbA {}
 impl ConvertFrom<LinearSrgbAPremultiplied> for LinearSrgbA {}
 // TODO: oklab gamut clipping
 impl ConvertFrom<Oklab> for LinearSrgbA {}
+impl ConvertFrom<CieXyz> for LinearSrgbA {}
+impl ConvertFrom<CieLab> for LinearSrgbA {}
+impl ConvertFrom<ICtCpPq> for LinearSrgbA {}
 
 impl WorkingEncoding for LinearSrgbA {}
+impl LinearLight for LinearSrgbA {}
 
 impl AlphaOver for LinearSrgbA {
     fn composite(over: Color<Self>, under: Color<Self>) -> Color<Self> {
@@ -436,7 +541,7 @@ impl Color<LinearSrgbAPremultiplied> {
     ///
     /// If you're not sure, see [the `LinearSrgbA` encoding docs][LinearSrgbA] for more info.
     #[inline(always)]
-    pub fn linear_srgba_premultiplied(r: f32, g: f32, b: f32, a: f32) -> Self {
+    pub const fn linear_srgba_premultiplied(r: f32, g: f32, b: f32, a: f32) -> Self {
         Color::from_repr(Vec4::new(r, g, b, a))
     }
 }
@@ -452,7 +557,7 @@ impl ColorEncoding for LinearSrgbAPremultiplied {
 
     #[inline(always)]
     fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
-        let separated = repr.xyz() / repr.w;
+        let separated = unpremultiply(repr.xyz(), repr.w);
         (separated, repr.w)
     }
 
@@ -472,12 +577,22 @@ impl ConvertFrom<LinearSrgbA> for LinearSrgbAPremultiplied {}
 impl ConvertFrom<LinearSrgb> for LinearSrgbAPremultiplied {}
 // TODO: oklab gamut clipping
 impl ConvertFrom<Oklab> for LinearSrgbAPremultiplied {}
+impl ConvertFrom<CieXyz> for LinearSrgbAPremultiplied {}
+impl ConvertFrom<CieLab> for LinearSrgbAPremultiplied {}
+impl ConvertFrom<ICtCpPq> for LinearSrgbAPremultiplied {}
+
+impl LinearLight for LinearSrgbAPremultiplied {}
 
 impl AlphaOver for LinearSrgbAPremultiplied {
     #[inline]
     fn composite(over: Color<Self>, under: Color<Self>) -> Color<Self> {
         Color::from_repr(over.repr + under.repr * (1.0 - over.repr.w))
     }
+
+    #[inline]
+    fn porter_duff(src: Color<Self>, dst: Color<Self>, op: PorterDuff) -> Color<Self> {
+        Color::from_repr(op.apply(src.repr, dst.repr))
+    }
 }
 
 /// A 32-bit-per-component version of the Oklab perceptually-uniform color space.
@@ -489,7 +604,7 @@ impl Color<Oklab> {
     /// This is fairly rare, it would be more common to specify colors in another color encoding like
     /// [`SrgbU8`] and then convert them to [`Oklab`] to blend them together.
     #[inline(always)]
-    pub fn oklab(l: f32, a: f32, b: f32) -> Self {
+    pub const fn oklab(l: f32, a: f32, b: f32) -> Self {
         Color::from_repr(Vec3::new(l, a, b))
     }
 }
@@ -524,6 +639,1682 @@ impl ConvertFrom<SrgbAU8Premultiplied> for Oklab {}
 impl ConvertFrom<LinearSrgb> for Oklab {}
 impl ConvertFrom<LinearSrgbA> for Oklab {}
 impl ConvertFrom<LinearSrgbAPremultiplied> for Oklab {}
+impl ConvertFrom<CieLab> for Oklab {}
+impl ConvertFrom<ICtCpPq> for Oklab {}
+impl ConvertFrom<CieXyz> for Oklab {}
 
 impl WorkingEncoding for Oklab {}
 impl PerceptualEncoding for Oklab {}
+
+macro_rules! impl_referred {
+    ($referral:ident => $($enc:ident),+) => {
+        $(impl Referred for $enc {
+            type Referral = $referral;
+        })+
+    };
+}
+
+impl_referred!(
+    DisplayReferral => SrgbU8, SrgbF32, SrgbAU8, SrgbAF32, SrgbAU8Premultiplied, LinearSrgb, LinearSrgbA,
+        LinearSrgbAPremultiplied, Oklab
+);
+
+/// The CIE 1976 L\*a\*b\* (CIELAB) color space, relative to a D65 white point, with 32 bits per component.
+///
+/// `L` ranges from `0.0` to `100.0` and `a` and `b` are roughly within `-128.0..=128.0`. CIELAB is the basis of
+/// the classic color difference formulas in [`crate::difference`]; for blending, prefer [`Oklab`].
+pub struct CieLab;
+
+impl Color<CieLab> {
+    /// Create a [`Color`] in the [`CieLab`] color encoding.
+    #[inline(always)]
+    pub const fn cie_lab(l: f32, a: f32, b: f32) -> Self {
+        Color::from_repr(Vec3::new(l, a, b))
+    }
+}
+
+impl ColorEncoding for CieLab {
+    type Repr = F32Repr;
+
+    type ComponentStruct = Lab<f32>;
+
+    type LinearSpace = linear_spaces::CieXYZ;
+
+    const NAME: &'static str = "CieLab";
+
+    #[inline(always)]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        let xyz = transform::CIELAB_to_XYZ(repr, WhitePoint::D65);
+        (xyz, 1.0)
+    }
+
+    #[inline(always)]
+    fn dst_transform_raw(raw: glam::Vec3, _: f32) -> Self::Repr {
+        transform::XYZ_to_CIELAB(raw, WhitePoint::D65)
+    }
+}
+
+impl ConvertFrom<SrgbU8> for CieLab {}
+impl ConvertFrom<SrgbF32> for CieLab {}
+impl ConvertFrom<SrgbAU8> for CieLab {}
+impl ConvertFrom<SrgbAF32> for CieLab {}
+impl ConvertFrom<SrgbAU8Premultiplied> for CieLab {}
+impl ConvertFrom<LinearSrgb> for CieLab {}
+impl ConvertFrom<LinearSrgbA> for CieLab {}
+impl ConvertFrom<LinearSrgbAPremultiplied> for CieLab {}
+impl ConvertFrom<Oklab> for CieLab {}
+impl ConvertFrom<CieXyz> for CieLab {}
+impl ConvertFrom<ICtCpPq> for CieLab {}
+
+impl_referred!(DisplayReferral => CieLab);
+
+/// The luminance in cd/m² which a linear value of `1.0` is displayed at by the [`ICtCpPq`] encoding.
+pub const ICTCP_REFERENCE_WHITE: f32 = 100.0;
+
+/// The ITU-R BT.2100 ICtCp color space with the PQ (SMPTE ST 2084) transfer function, with 32 bits per component.
+///
+/// PQ encodes absolute luminance, so linear values are taken to be relative to a white of
+/// [`ICTCP_REFERENCE_WHITE`] cd/m². ICtCp is designed for high dynamic range and wide gamut content, and
+/// is the basis of the ΔE ITP color difference in [`crate::difference`].
+pub struct ICtCpPq;
+
+impl Color<ICtCpPq> {
+    /// Create a [`Color`] in the [`ICtCpPq`] color encoding.
+    #[inline(always)]
+    pub const fn ictcp_pq(i: f32, ct: f32, cp: f32) -> Self {
+        Color::from_repr(Vec3::new(i, ct, cp))
+    }
+}
+
+impl ColorEncoding for ICtCpPq {
+    type Repr = F32Repr;
+
+    type ComponentStruct = ICtCp<f32>;
+
+    type LinearSpace = linear_spaces::Bt2020;
+
+    const NAME: &'static str = "ICtCpPq";
+
+    #[inline(always)]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        let rgb = transform::ICtCp_PQ_to_RGB(repr, WhitePoint::D65);
+        (rgb / ICTCP_REFERENCE_WHITE, 1.0)
+    }
+
+    #[inline(always)]
+    fn dst_transform_raw(raw: glam::Vec3, _: f32) -> Self::Repr {
+        transform::RGB_to_ICtCp_PQ(raw * ICTCP_REFERENCE_WHITE, WhitePoint::D65)
+    }
+}
+
+impl ConvertFrom<SrgbU8> for ICtCpPq {}
+impl ConvertFrom<SrgbF32> for ICtCpPq {}
+impl ConvertFrom<SrgbAU8> for ICtCpPq {}
+impl ConvertFrom<SrgbAF32> for ICtCpPq {}
+impl ConvertFrom<SrgbAU8Premultiplied> for ICtCpPq {}
+impl ConvertFrom<LinearSrgb> for ICtCpPq {}
+impl ConvertFrom<LinearSrgbA> for ICtCpPq {}
+impl ConvertFrom<LinearSrgbAPremultiplied> for ICtCpPq {}
+impl ConvertFrom<Oklab> for ICtCpPq {}
+impl ConvertFrom<CieXyz> for ICtCpPq {}
+impl ConvertFrom<CieLab> for ICtCpPq {}
+
+impl_referred!(DisplayReferral => ICtCpPq);
+
+/// The CIE 1931 XYZ color space, with 32 bits per component.
+///
+/// `Y` is luminance, relative to a D65 white with a `Y` of `1.0`. XYZ is the space all the built-in linear color
+/// spaces are defined against, and is the natural place to work with chromaticities and color temperatures
+/// (see [`crate::temperature`]).
+pub struct CieXyz;
+
+impl Color<CieXyz> {
+    /// Create a [`Color`] in the [`CieXyz`] color encoding.
+    #[inline(always)]
+    pub const fn cie_xyz(x: f32, y: f32, z: f32) -> Self {
+        Color::from_repr(Vec3::new(x, y, z))
+    }
+
+    /// Create a [`Color`] in the [`CieXyz`] color encoding from `xy` chromaticity coordinates and a luminance `big_y`.
+    #[inline]
+    pub fn from_xy_luminance(x: f32, y: f32, big_y: f32) -> Self {
+        Color::from_repr(Vec3::new(x * big_y / y, big_y, (1.0 - x - y) * big_y / y))
+    }
+
+    /// The `xy` chromaticity coordinates of `self`.
+    #[inline]
+    pub fn xy(self) -> glam::Vec2 {
+        let sum = self.repr.x + self.repr.y + self.repr.z;
+        glam::Vec2::new(self.repr.x / sum, self.repr.y / sum)
+    }
+}
+
+impl ColorEncoding for CieXyz {
+    type Repr = F32Repr;
+
+    type ComponentStruct = Xyz<f32>;
+
+    type LinearSpace = linear_spaces::CieXYZ;
+
+    const NAME: &'static str = "CieXyz";
+
+    #[inline(always)]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        (repr, 1.0)
+    }
+
+    #[inline(always)]
+    fn dst_transform_raw(raw: glam::Vec3, _: f32) -> Self::Repr {
+        raw
+    }
+}
+
+impl ConvertFrom<SrgbU8> for CieXyz {}
+impl ConvertFrom<SrgbF32> for CieXyz {}
+impl ConvertFrom<SrgbAU8> for CieXyz {}
+impl ConvertFrom<SrgbAF32> for CieXyz {}
+impl ConvertFrom<SrgbAU8Premultiplied> for CieXyz {}
+impl ConvertFrom<LinearSrgb> for CieXyz {}
+impl ConvertFrom<LinearSrgbA> for CieXyz {}
+impl ConvertFrom<LinearSrgbAPremultiplied> for CieXyz {}
+impl ConvertFrom<Oklab> for CieXyz {}
+impl ConvertFrom<CieLab> for CieXyz {}
+impl ConvertFrom<ICtCpPq> for CieXyz {}
+
+impl WorkingEncoding for CieXyz {}
+impl LinearLight for CieXyz {}
+impl_referred!(DisplayReferral => CieXyz);
+
+macro_rules! impl_linear_encoding {
+    ($($enc:ty),* => |$repr:ident, $factor:ident| $scale:expr) => {
+        $(impl LinearEncoding for $enc {
+            #[inline(always)]
+            fn scale_light($repr: Self::Repr, $factor: f32) -> Self::Repr {
+                $scale
+            }
+        })*
+    };
+}
+
+macro_rules! impl_rgb_encoding {
+    ($($enc:ty),* => $luma:ident, |$repr:ident| $rgb:expr) => {
+        $(impl RgbEncoding for $enc {
+            #[inline(always)]
+            fn luma_coefficients() -> LumaCoefficients {
+                LumaCoefficients::$luma
+            }
+
+            #[inline]
+            fn encoded_rgb($repr: Self::Repr) -> Vec3 {
+                $rgb
+            }
+        })*
+    };
+}
+
+impl_rgb_encoding!(SrgbU8, SrgbAU8 => Rec709, |repr| Vec3::new(u8_to_f32(repr[0]), u8_to_f32(repr[1]), u8_to_f32(repr[2])));
+impl_rgb_encoding!(SrgbF32, LinearSrgb, SceneLinearSrgb => Rec709, |repr| repr);
+impl_rgb_encoding!(SrgbAF32, LinearSrgbA, SceneLinearSrgbA => Rec709, |repr| repr.xyz());
+impl_rgb_encoding!(SrgbAU8Premultiplied => Rec709, |repr| {
+    transfer_functions::Srgb::encode(Self::src_transform_raw(repr).0)
+});
+impl_rgb_encoding!(LinearSrgbAPremultiplied => Rec709, |repr| Self::src_transform_raw(repr).0);
+
+impl RgbEncoding for AcesCgF32 {
+    #[inline]
+    fn luma_coefficients() -> LumaCoefficients {
+        LumaCoefficients::for_space::<linear_spaces::AcesCg>()
+    }
+
+    #[inline(always)]
+    fn encoded_rgb(repr: Self::Repr) -> Vec3 {
+        repr
+    }
+}
+
+impl_linear_encoding!(SceneLinearSrgb, AcesCgF32 => |repr, factor| repr * factor);
+impl_linear_encoding!(SceneLinearSrgbA => |repr, factor| (repr.xyz() * factor).extend(repr.w));
+
+macro_rules! impl_saturate {
+    ($($enc:ty),* => |$repr:ident| $saturate:expr) => {
+        $(impl Saturate for $enc {
+            #[inline]
+            fn saturate($repr: Self::Repr) -> Self::Repr {
+                $saturate
+            }
+        })*
+    };
+}
+
+// integer encodings can't hold values outside of their valid range
+impl_saturate!(SrgbU8, SrgbAU8, SrgbAU8Premultiplied => |repr| repr);
+impl_saturate!(SrgbF32, LinearSrgb => |repr| repr.clamp(Vec3::ZERO, Vec3::ONE));
+impl_saturate!(SrgbAF32, LinearSrgbA => |repr| repr.clamp(Vec4::ZERO, Vec4::ONE));
+// premultiplied color components can't be brighter than their alpha
+impl_saturate!(LinearSrgbAPremultiplied => |repr| {
+    let alpha = repr.w.clamp(0.0, 1.0);
+    repr.xyz().clamp(Vec3::ZERO, Vec3::splat(alpha)).extend(alpha)
+});
+// only lightness is bounded, chroma depends on the hue and lightness
+impl_saturate!(Oklab, ICtCpPq => |repr| Vec3::new(repr.x.clamp(0.0, 1.0), repr.y, repr.z));
+impl_saturate!(CieLab => |repr| Vec3::new(repr.x.clamp(0.0, 100.0), repr.y, repr.z));
+// X and Z of display-referred colors exceed 1.0 near white, so these are only bounded below
+impl_saturate!(CieXyz, SceneLinearSrgb, AcesCgF32 => |repr| repr.max(Vec3::ZERO));
+impl_saturate!(SceneLinearSrgbA => |repr| repr.xyz().max(Vec3::ZERO).extend(repr.w.clamp(0.0, 1.0)));
+
+macro_rules! impl_display_gamut {
+    ($($enc:ty),* => $min:expr, $max:expr) => {
+        $(impl DisplayGamut for $enc {
+            const LINEAR_MIN: f32 = $min;
+
+            const LINEAR_MAX: f32 = $max;
+        })*
+    };
+}
+
+impl_display_gamut!(
+    SrgbU8, SrgbF32, SrgbAU8, SrgbAF32, SrgbAU8Premultiplied, LinearSrgb, LinearSrgbA, LinearSrgbAPremultiplied
+    => 0.0, 1.0
+);
+// the gamut of XYZ isn't a box, so only negative values are known to be out of it
+impl_display_gamut!(CieXyz, Oklab, CieLab => 0.0, f32::INFINITY);
+// PQ reaches 10000 cd/m²
+impl_display_gamut!(ICtCpPq => 0.0, 10_000.0 / ICTCP_REFERENCE_WHITE);
+
+/// The linear form of the sRGB color encoding standard, holding *scene-referred* values with unbounded brightness.
+///
+/// This has the same primaries and representation as [`LinearSrgb`], but is the encoding to use for values
+/// like the output of lighting calculations, where `1.0` has no special meaning and values far above it are common.
+/// It is a [`SceneReferred`] encoding, so it can't be converted to display encodings like [`SrgbU8`] with
+/// [`convert`][Color::convert], which would clip bright values. Use a [`Tonemapper`][crate::tonemap::Tonemapper]
+/// or [`Color::clamp_to_display`] instead.
+///
+/// ```compile_fail
+/// # use colstodian::Color;
+/// # use colstodian::details::encodings::*;
+/// let hdr = Color::scene_linear_srgb(4.0, 2.0, 1.0);
+/// let clipped = hdr.convert::<SrgbU8>();
+/// ```
+///
+/// Display-referred colors can be converted to [`SceneLinearSrgb`] freely, for example to use an
+/// artist-picked color as the color of a light.
+///
+/// ```
+/// # use colstodian::Color;
+/// # use colstodian::details::encodings::*;
+/// # use colstodian::tonemap::Reinhard;
+/// let light = Color::srgb_u8(255, 200, 150).convert::<SceneLinearSrgb>() * 8.0;
+/// let pixel: Color<SrgbU8> = light.tonemap(&Reinhard);
+/// # let _ = pixel;
+/// ```
+pub struct SceneLinearSrgb;
+
+impl Color<SceneLinearSrgb> {
+    /// Create a [`Color`] in the [`SceneLinearSrgb`] encoding.
+    ///
+    /// See [the `SceneLinearSrgb` encoding docs][SceneLinearSrgb] for more info.
+    #[inline(always)]
+    pub const fn scene_linear_srgb(r: f32, g: f32, b: f32) -> Self {
+        Color::from_repr(Vec3::new(r, g, b))
+    }
+
+    /// Clamps `self` to the `0.0..=1.0` range, giving a display-referred [`LinearSrgb`] color.
+    ///
+    /// This is the simplest explicit way to go from scene-referred to display-referred values, and
+    /// is only appropriate if `self` is already known to be in range. Otherwise, use a
+    /// [`Tonemapper`][crate::tonemap::Tonemapper].
+    #[inline]
+    pub fn clamp_to_display(self) -> Color<LinearSrgb> {
+        Color::from_repr(self.repr.clamp(Vec3::ZERO, Vec3::ONE))
+    }
+}
+
+impl ColorEncoding for SceneLinearSrgb {
+    type Repr = F32Repr;
+
+    type ComponentStruct = Rgb<f32>;
+
+    type LinearSpace = linear_spaces::Srgb;
+
+    const NAME: &'static str = "SceneLinearSrgb";
+
+    #[inline(always)]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        (repr, 1.0)
+    }
+
+    #[inline(always)]
+    fn dst_transform_raw(raw: glam::Vec3, _: f32) -> Self::Repr {
+        raw
+    }
+}
+
+impl ConvertFrom<SrgbU8> for SceneLinearSrgb {}
+impl ConvertFrom<SrgbF32> for SceneLinearSrgb {}
+impl ConvertFrom<SrgbAU8> for SceneLinearSrgb {}
+impl ConvertFrom<SrgbAF32> for SceneLinearSrgb {}
+impl ConvertFrom<SrgbAU8Premultiplied> for SceneLinearSrgb {}
+impl ConvertFrom<LinearSrgb> for SceneLinearSrgb {}
+impl ConvertFrom<LinearSrgbA> for SceneLinearSrgb {}
+impl ConvertFrom<LinearSrgbAPremultiplied> for SceneLinearSrgb {}
+impl ConvertFrom<Oklab> for SceneLinearSrgb {}
+impl ConvertFrom<CieXyz> for SceneLinearSrgb {}
+impl ConvertFrom<CieLab> for SceneLinearSrgb {}
+impl ConvertFrom<ICtCpPq> for SceneLinearSrgb {}
+impl ConvertFrom<SceneLinearSrgbA> for SceneLinearSrgb {}
+
+impl WorkingEncoding for SceneLinearSrgb {}
+impl LinearLight for SceneLinearSrgb {}
+impl_referred!(SceneReferral => SceneLinearSrgb);
+
+/// The linear form of the sRGB color encoding standard with a separate alpha component, holding *scene-referred*
+/// color values with unbounded brightness.
+///
+/// See [`SceneLinearSrgb`] for more info. The alpha component is still a `0.0..=1.0` coverage value.
+pub struct SceneLinearSrgbA;
+
+impl Color<SceneLinearSrgbA> {
+    /// Create a [`Color`] in the [`SceneLinearSrgbA`] encoding.
+    ///
+    /// See [the `SceneLinearSrgb` encoding docs][SceneLinearSrgb] for more info.
+    #[inline(always)]
+    pub const fn scene_linear_srgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Color::from_repr(Vec4::new(r, g, b, a))
+    }
+
+    /// Clamps the color components of `self` to the `0.0..=1.0` range, giving a display-referred
+    /// [`LinearSrgbA`] color.
+    ///
+    /// See [`Color::<SceneLinearSrgb>::clamp_to_display`][Color::clamp_to_display] for more.
+    #[inline]
+    pub fn clamp_to_display(self) -> Color<LinearSrgbA> {
+        Color::from_repr(self.repr.clamp(Vec4::ZERO, Vec4::ONE))
+    }
+}
+
+impl ColorEncoding for SceneLinearSrgbA {
+    type Repr = F32ARepr;
+
+    type ComponentStruct = RgbA<f32>;
+
+    type LinearSpace = linear_spaces::Srgb;
+
+    const NAME: &'static str = "SceneLinearSrgbA";
+
+    #[inline(always)]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        (repr.xyz(), repr.w)
+    }
+
+    #[inline(always)]
+    fn dst_transform_raw(raw: glam::Vec3, alpha: f32) -> Self::Repr {
+        raw.extend(alpha)
+    }
+}
+
+impl ConvertFrom<SrgbU8> for SceneLinearSrgbA {}
+impl ConvertFrom<SrgbF32> for SceneLinearSrgbA {}
+impl ConvertFrom<SrgbAU8> for SceneLinearSrgbA {}
+impl ConvertFrom<SrgbAF32> for SceneLinearSrgbA {}
+impl ConvertFrom<SrgbAU8Premultiplied> for SceneLinearSrgbA {}
+impl ConvertFrom<LinearSrgb> for SceneLinearSrgbA {}
+impl ConvertFrom<LinearSrgbA> for SceneLinearSrgbA {}
+impl ConvertFrom<LinearSrgbAPremultiplied> for SceneLinearSrgbA {}
+impl ConvertFrom<Oklab> for SceneLinearSrgbA {}
+impl ConvertFrom<CieXyz> for SceneLinearSrgbA {}
+impl ConvertFrom<CieLab> for SceneLinearSrgbA {}
+impl ConvertFrom<ICtCpPq> for SceneLinearSrgbA {}
+impl ConvertFrom<SceneLinearSrgb> for SceneLinearSrgbA {}
+
+impl WorkingEncoding for SceneLinearSrgbA {}
+impl LinearLight for SceneLinearSrgbA {}
+impl_referred!(SceneReferral => SceneLinearSrgbA);
+
+/// The ACEScg color encoding, linear values with the [`AcesCg`][linear_spaces::AcesCg] primaries, holding
+/// *scene-referred* values with 32 bits per component.
+///
+/// ACEScg is the common working space of visual effects and color grading pipelines, and the usual destination of
+/// footage in camera log encodings, such as [`ArriLogC4`].
+///
+/// ```
+/// use colstodian::Color;
+/// use colstodian::details::encodings::{AcesCgF32, ArriLogC3};
+/// # use colstodian::*;
+/// # use colstodian::equals_eps::*;
+///
+/// let plate = Color::<ArriLogC3>::from_repr(glam::Vec3::splat(0.391));
+/// let grey = plate.convert::<AcesCgF32>();
+/// assert_eq_eps!(grey.repr, glam::Vec3::splat(0.18), 1e-3);
+/// ```
+pub struct AcesCgF32;
+
+impl Color<AcesCgF32> {
+    /// Create a [`Color`] in the [`AcesCgF32`] encoding.
+    #[inline(always)]
+    pub const fn aces_cg(r: f32, g: f32, b: f32) -> Self {
+        Color::from_repr(Vec3::new(r, g, b))
+    }
+}
+
+impl ColorEncoding for AcesCgF32 {
+    type Repr = F32Repr;
+
+    type ComponentStruct = Rgb<f32>;
+
+    type LinearSpace = linear_spaces::AcesCg;
+
+    const NAME: &'static str = "AcesCgF32";
+
+    #[inline(always)]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        (repr, 1.0)
+    }
+
+    #[inline(always)]
+    fn dst_transform_raw(raw: glam::Vec3, _: f32) -> Self::Repr {
+        raw
+    }
+}
+
+impl ConvertFrom<SrgbU8> for AcesCgF32 {}
+impl ConvertFrom<SrgbF32> for AcesCgF32 {}
+impl ConvertFrom<SrgbAU8> for AcesCgF32 {}
+impl ConvertFrom<SrgbAF32> for AcesCgF32 {}
+impl ConvertFrom<SrgbAU8Premultiplied> for AcesCgF32 {}
+impl ConvertFrom<LinearSrgb> for AcesCgF32 {}
+impl ConvertFrom<LinearSrgbA> for AcesCgF32 {}
+impl ConvertFrom<LinearSrgbAPremultiplied> for AcesCgF32 {}
+impl ConvertFrom<Oklab> for AcesCgF32 {}
+impl ConvertFrom<CieXyz> for AcesCgF32 {}
+impl ConvertFrom<CieLab> for AcesCgF32 {}
+impl ConvertFrom<ICtCpPq> for AcesCgF32 {}
+impl ConvertFrom<SceneLinearSrgb> for AcesCgF32 {}
+impl ConvertFrom<SceneLinearSrgbA> for AcesCgF32 {}
+
+impl ConvertFrom<AcesCgF32> for SceneLinearSrgb {}
+impl ConvertFrom<AcesCgF32> for SceneLinearSrgbA {}
+
+impl AlphaOver for SceneLinearSrgbA {
+    #[inline]
+    fn composite(over: Color<Self>, under: Color<Self>) -> Color<Self> {
+        porter_duff_raw(over, under, PorterDuff::SourceOver)
+    }
+}
+
+impl WorkingEncoding for AcesCgF32 {}
+impl LinearLight for AcesCgF32 {}
+impl_referred!(SceneReferral => AcesCgF32);
+
+/// An encoding built generically from a [`LinearColorSpace`], a [`TransferFunction`], a repr and an [`AlphaMode`].
+///
+/// This covers combinations of these which have no dedicated encoding, without needing any new code. For
+/// example, Display P3 primaries with a gamma of 2.2 and 16 bits per channel, with straight alpha, is
+/// `Encoded<DisplayP3, Gamma22, U16ARepr, SeparateAlpha>`.
+///
+/// The supported reprs are [`U8Repr`], [`U16Repr`] and [`F32Repr`] with [`NoAlpha`], and [`U8ARepr`], [`U16ARepr`]
+/// and [`F32ARepr`] with [`SeparateAlpha`] or [`PremultipliedAlpha`]. Integer components are normalized to
+/// `0.0..=1.0` before being decoded, and alpha is always stored linearly.
+///
+/// The encoding is display-referred or scene-referred according to its transfer function's
+/// [`Referral`][TransferFunction::Referral], and converts to and from the other built-in `f32`
+/// and integer encodings following the same rules as they do.
+///
+/// There is no direct conversion between two different `Encoded` encodings: a generic
+/// `impl ConvertFrom<Encoded<..>> for Encoded<..>` would overlap the identity conversion every encoding has to
+/// itself. Go through [`CieXyz`] (if both are display-referred) or [`SceneLinearSrgb`] instead, which store any color
+/// without loss:
+///
+/// ```
+/// # use colstodian::Color;
+/// # use colstodian::details::encodings::{AdobeRgbU8, CieXyz, Gamma22F32};
+/// # use colstodian::*;
+/// # use colstodian::equals_eps::*;
+/// let adobe: Color<AdobeRgbU8> = Color::srgb_u8(255, 128, 0).convert();
+/// let gamma: Color<Gamma22F32> = adobe.convert::<CieXyz>().convert();
+/// assert_eq_eps!(gamma.convert::<CieXyz>(), adobe.convert::<CieXyz>(), 1e-3);
+/// ```
+///
+/// ```
+/// use colstodian::Color;
+/// use colstodian::details::encodings::{Encoded, LinearSrgb, SeparateAlpha};
+/// use colstodian::details::linear_spaces::DisplayP3;
+/// use colstodian::details::reprs::U16ARepr;
+/// use colstodian::details::transfer_functions::Gamma22;
+/// # use colstodian::*;
+/// # use colstodian::equals_eps::*;
+///
+/// type DisplayP3Gamma22U16A = Encoded<DisplayP3, Gamma22, U16ARepr, SeparateAlpha>;
+///
+/// let color: Color<DisplayP3Gamma22U16A> = Color::srgba_u8(255, 128, 0, 255).convert();
+/// assert_eq!(color.a, u16::MAX);
+/// assert_eq_eps!(color.convert::<LinearSrgb>(), Color::srgb_u8(255, 128, 0).convert::<LinearSrgb>(), 1e-3);
+/// ```
+///
+/// Scene-referred values need tonemapping before they can be shown:
+///
+/// ```compile_fail
+/// # use colstodian::Color;
+/// # use colstodian::details::encodings::{Encoded, SrgbU8};
+/// # use colstodian::details::linear_spaces::AcesCg;
+/// # use colstodian::details::reprs::F32Repr;
+/// # use colstodian::details::transfer_functions::AcesCct;
+/// let graded = Color::<Encoded<AcesCg, AcesCct, F32Repr>>::from_repr(glam::Vec3::splat(0.4));
+/// let display = graded.convert::<SrgbU8>();
+/// ```
+pub struct Encoded<Space, Tf, Repr, Alpha = NoAlpha> {
+    _marker: PhantomData<(Space, Tf, Repr, Alpha)>,
+}
+
+/// The [`AlphaMode`] of [`Encoded`] encodings without an alpha component.
+pub struct NoAlpha;
+
+impl AlphaMode for NoAlpha {
+    #[inline(always)]
+    fn premultiply(linear: Vec3, _: f32) -> Vec3 {
+        linear
+    }
+
+    #[inline(always)]
+    fn separate(linear: Vec3, _: f32) -> Vec3 {
+        linear
+    }
+}
+
+/// The [`AlphaMode`] of [`Encoded`] encodings with an alpha component that is separate from the color components.
+pub struct SeparateAlpha;
+
+impl AlphaMode for SeparateAlpha {
+    #[inline(always)]
+    fn premultiply(linear: Vec3, _: f32) -> Vec3 {
+        linear
+    }
+
+    #[inline(always)]
+    fn separate(linear: Vec3, _: f32) -> Vec3 {
+        linear
+    }
+}
+
+/// The [`AlphaMode`] of [`Encoded`] encodings whose color components are premultiplied by their alpha component.
+///
+/// As with [`SrgbAU8Premultiplied`], the multiplication happens on the linear values, *before* the transfer
+/// function is applied.
+pub struct PremultipliedAlpha;
+
+impl AlphaMode for PremultipliedAlpha {
+    #[inline(always)]
+    fn premultiply(linear: Vec3, alpha: f32) -> Vec3 {
+        linear * alpha
+    }
+
+    #[inline(always)]
+    fn separate(linear: Vec3, alpha: f32) -> Vec3 {
+        unpremultiply(linear, alpha)
+    }
+}
+
+impl EncodedRepr<NoAlpha> for U8Repr {
+    type ComponentStruct = Rgb<u8>;
+
+    #[inline]
+    fn split(self) -> (Vec3, f32) {
+        let [x, y, z] = self;
+        (Vec3::new(u8_to_f32(x), u8_to_f32(y), u8_to_f32(z)), 1.0)
+    }
+
+    #[inline]
+    fn join(components: Vec3, _: f32) -> Self {
+        [
+            f32_to_u8(components.x),
+            f32_to_u8(components.y),
+            f32_to_u8(components.z),
+        ]
+    }
+
+    #[inline(always)]
+    fn saturate<Tf: TransferFunction>(self) -> Self {
+        self
+    }
+}
+
+impl EncodedRepr<NoAlpha> for U16Repr {
+    type ComponentStruct = Rgb<u16>;
+
+    #[inline]
+    fn split(self) -> (Vec3, f32) {
+        let [x, y, z] = self;
+        (Vec3::new(u16_to_f32(x), u16_to_f32(y), u16_to_f32(z)), 1.0)
+    }
+
+    #[inline]
+    fn join(components: Vec3, _: f32) -> Self {
+        [
+            f32_to_u16(components.x),
+            f32_to_u16(components.y),
+            f32_to_u16(components.z),
+        ]
+    }
+
+    #[inline(always)]
+    fn saturate<Tf: TransferFunction>(self) -> Self {
+        self
+    }
+}
+
+impl EncodedRepr<NoAlpha> for F16Repr {
+    type ComponentStruct = Rgb<F16>;
+
+    #[inline]
+    fn split(self) -> (Vec3, f32) {
+        let [x, y, z] = self;
+        (Vec3::new(x.to_f32(), y.to_f32(), z.to_f32()), 1.0)
+    }
+
+    #[inline]
+    fn join(components: Vec3, _: f32) -> Self {
+        [
+            F16::from_f32(components.x),
+            F16::from_f32(components.y),
+            F16::from_f32(components.z),
+        ]
+    }
+}
+
+impl EncodedRepr<NoAlpha> for F32Repr {
+    type ComponentStruct = Rgb<f32>;
+
+    #[inline(always)]
+    fn split(self) -> (Vec3, f32) {
+        (self, 1.0)
+    }
+
+    #[inline(always)]
+    fn join(components: Vec3, _: f32) -> Self {
+        components
+    }
+}
+
+macro_rules! impl_encoded_repr_with_alpha {
+    ($($alpha:ident),+) => {
+        $(
+            impl EncodedRepr<$alpha> for U8ARepr {
+                type ComponentStruct = RgbA<u8>;
+
+                #[inline]
+                fn split(self) -> (Vec3, f32) {
+                    let [x, y, z, a] = self;
+                    (Vec3::new(u8_to_f32(x), u8_to_f32(y), u8_to_f32(z)), u8_to_f32(a))
+                }
+
+                #[inline]
+                fn join(components: Vec3, alpha: f32) -> Self {
+                    [
+                        f32_to_u8(components.x),
+                        f32_to_u8(components.y),
+                        f32_to_u8(components.z),
+                        f32_to_u8(alpha),
+                    ]
+                }
+
+                #[inline(always)]
+                fn saturate<Tf: TransferFunction>(self) -> Self {
+                    self
+                }
+            }
+
+            impl EncodedRepr<$alpha> for U16ARepr {
+                type ComponentStruct = RgbA<u16>;
+
+                #[inline]
+                fn split(self) -> (Vec3, f32) {
+                    let [x, y, z, a] = self;
+                    (Vec3::new(u16_to_f32(x), u16_to_f32(y), u16_to_f32(z)), u16_to_f32(a))
+                }
+
+                #[inline]
+                fn join(components: Vec3, alpha: f32) -> Self {
+                    [
+                        f32_to_u16(components.x),
+                        f32_to_u16(components.y),
+                        f32_to_u16(components.z),
+                        f32_to_u16(alpha),
+                    ]
+                }
+
+                #[inline(always)]
+                fn saturate<Tf: TransferFunction>(self) -> Self {
+                    self
+                }
+            }
+
+            impl EncodedRepr<$alpha> for F16ARepr {
+                type ComponentStruct = RgbA<F16>;
+
+                #[inline]
+                fn split(self) -> (Vec3, f32) {
+                    let [x, y, z, a] = self;
+                    (Vec3::new(x.to_f32(), y.to_f32(), z.to_f32()), a.to_f32())
+                }
+
+                #[inline]
+                fn join(components: Vec3, alpha: f32) -> Self {
+                    [
+                        F16::from_f32(components.x),
+                        F16::from_f32(components.y),
+                        F16::from_f32(components.z),
+                        F16::from_f32(alpha),
+                    ]
+                }
+            }
+
+            impl EncodedRepr<$alpha> for F32ARepr {
+                type ComponentStruct = RgbA<f32>;
+
+                #[inline(always)]
+                fn split(self) -> (Vec3, f32) {
+                    (self.xyz(), self.w)
+                }
+
+                #[inline(always)]
+                fn join(components: Vec3, alpha: f32) -> Self {
+                    components.extend(alpha)
+                }
+            }
+        )+
+    };
+}
+
+impl_encoded_repr_with_alpha!(SeparateAlpha, PremultipliedAlpha);
+
+/// The name of an [`Encoded`] encoding, built at compile time from the names of its space and transfer function.
+struct EncodedName {
+    bytes: [u8; Self::CAPACITY],
+    len: usize,
+}
+
+impl EncodedName {
+    /// Longer names are truncated.
+    const CAPACITY: usize = 64;
+
+    const fn new(parts: &[&str]) -> Self {
+        let mut bytes = [0; Self::CAPACITY];
+        let mut len = 0;
+        let mut i = 0;
+        while i < parts.len() {
+            let part = parts[i].as_bytes();
+            let mut j = 0;
+            while j < part.len() && len < Self::CAPACITY {
+                bytes[len] = part[j];
+                len += 1;
+                j += 1;
+            }
+            i += 1;
+        }
+        Self { bytes, len }
+    }
+
+    const fn as_str(&'static self) -> &'static str {
+        match core::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(name) => name,
+            // only reachable if truncation split a multi-byte character
+            Err(_) => "Encoded",
+        }
+    }
+}
+
+impl<Space, Tf, Repr, Alpha> Encoded<Space, Tf, Repr, Alpha>
+where
+    Space: LinearColorSpace,
+    Tf: TransferFunction,
+{
+    const ENCODED_NAME: EncodedName =
+        EncodedName::new(&["Encoded<", Space::NAME, ", ", Tf::NAME, ">"]);
+}
+
+impl<Space, Tf, Repr, Alpha> ColorEncoding for Encoded<Space, Tf, Repr, Alpha>
+where
+    Space: LinearColorSpace + 'static,
+    Tf: TransferFunction,
+    Repr: EncodedRepr<Alpha>,
+    Alpha: AlphaMode,
+{
+    type Repr = Repr;
+
+    type ComponentStruct = <Repr as EncodedRepr<Alpha>>::ComponentStruct;
+
+    type LinearSpace = Space;
+
+    const NAME: &'static str = Self::ENCODED_NAME.as_str();
+
+    #[inline]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        let (encoded, alpha) = repr.split();
+        let linear = Tf::decode(encoded);
+        (Alpha::separate(linear, alpha), alpha)
+    }
+
+    #[inline]
+    fn dst_transform_raw(raw: glam::Vec3, alpha: f32) -> Self::Repr {
+        let linear = Alpha::premultiply(raw, alpha);
+        Repr::join(Tf::encode(linear), alpha)
+    }
+}
+
+impl<Space, Tf, Repr, Alpha> Referred for Encoded<Space, Tf, Repr, Alpha>
+where
+    Space: LinearColorSpace + 'static,
+    Tf: TransferFunction,
+    Repr: EncodedRepr<Alpha>,
+    Alpha: AlphaMode,
+{
+    type Referral = Tf::Referral;
+}
+
+macro_rules! impl_encoded_conversions {
+    (display: $($display:ident),+; scene: $($scene:ident),+) => {
+        $(
+            impl<Space, Tf, Repr, Alpha> ConvertFrom<$display> for Encoded<Space, Tf, Repr, Alpha>
+            where
+                Space: LinearColorSpace + LinearConvertFromRaw<<$display as ColorEncoding>::LinearSpace> + 'static,
+                Tf: TransferFunction,
+                Repr: EncodedRepr<Alpha>,
+                Alpha: AlphaMode,
+            {
+            }
+
+            impl<Space, Tf, Repr, Alpha> ConvertFrom<Encoded<Space, Tf, Repr, Alpha>> for $display
+            where
+                Space: LinearColorSpace + 'static,
+                <$display as ColorEncoding>::LinearSpace: LinearConvertFromRaw<Space>,
+                Tf: DisplayTransferFunction,
+                Repr: EncodedRepr<Alpha>,
+                Alpha: AlphaMode,
+            {
+            }
+        )+
+        $(
+            impl<Space, Tf, Repr, Alpha> ConvertFrom<$scene> for Encoded<Space, Tf, Repr, Alpha>
+            where
+                Space: LinearColorSpace + LinearConvertFromRaw<<$scene as ColorEncoding>::LinearSpace> + 'static,
+                Tf: SceneTransferFunction,
+                Repr: EncodedRepr<Alpha>,
+                Alpha: AlphaMode,
+            {
+            }
+
+            impl<Space, Tf, Repr, Alpha> ConvertFrom<Encoded<Space, Tf, Repr, Alpha>> for $scene
+            where
+                Space: LinearColorSpace + 'static,
+                <$scene as ColorEncoding>::LinearSpace: LinearConvertFromRaw<Space>,
+                Tf: TransferFunction,
+                Repr: EncodedRepr<Alpha>,
+                Alpha: AlphaMode,
+            {
+            }
+        )+
+    };
+}
+
+impl_encoded_conversions!(
+    display: SrgbU8, SrgbF32, SrgbAU8, SrgbAF32, SrgbAU8Premultiplied, LinearSrgb, LinearSrgbA,
+        LinearSrgbAPremultiplied, Oklab, CieXyz, CieLab, ICtCpPq;
+    scene: SceneLinearSrgb, SceneLinearSrgbA, AcesCgF32
+);
+
+impl<Space, Tf, Repr, Alpha> RgbEncoding for Encoded<Space, Tf, Repr, Alpha>
+where
+    Space: LinearColorSpace + 'static,
+    linear_spaces::CieXYZ: LinearConvertFromRaw<Space>,
+    Tf: TransferFunction,
+    Repr: EncodedRepr<Alpha>,
+    Alpha: AlphaMode,
+{
+    #[inline]
+    fn luma_coefficients() -> LumaCoefficients {
+        LumaCoefficients::for_space::<Space>()
+    }
+
+    #[inline]
+    fn encoded_rgb(repr: Self::Repr) -> Vec3 {
+        Tf::encode(Self::src_transform_raw(repr).0)
+    }
+}
+
+macro_rules! impl_encoded_alpha_over {
+    ($($alpha:ident),+) => {
+        $(
+            impl<Space, Tf, Repr> AlphaOver for Encoded<Space, Tf, Repr, $alpha>
+            where
+                Space: LinearColorSpace + 'static,
+                Tf: TransferFunction,
+                Repr: EncodedRepr<$alpha>,
+            {
+                #[inline]
+                fn composite(over: Color<Self>, under: Color<Self>) -> Color<Self> {
+                    porter_duff_raw(over, under, PorterDuff::SourceOver)
+                }
+            }
+        )+
+    };
+}
+
+impl_encoded_alpha_over!(SeparateAlpha, PremultipliedAlpha);
+
+macro_rules! impl_encoded_linear_light {
+    ($($tf:ident),+) => {
+        $(
+            impl<Space, Repr, Alpha> LinearLight for Encoded<Space, transfer_functions::$tf, Repr, Alpha>
+            where
+                Space: LinearColorSpace + 'static,
+                Repr: EncodedRepr<Alpha>,
+                Alpha: AlphaMode,
+            {
+            }
+        )+
+    };
+}
+
+impl_encoded_linear_light!(Linear, ExtendedLinear);
+
+impl<Space, Tf, Repr, Alpha> DisplayGamut for Encoded<Space, Tf, Repr, Alpha>
+where
+    Space: LinearColorSpace + 'static,
+    Tf: DisplayTransferFunction,
+    Repr: EncodedRepr<Alpha>,
+    Alpha: AlphaMode,
+{
+    const LINEAR_MIN: f32 = Tf::LINEAR_MIN;
+
+    const LINEAR_MAX: f32 = Tf::LINEAR_MAX;
+}
+
+impl<Space, Tf, Repr, Alpha> Saturate for Encoded<Space, Tf, Repr, Alpha>
+where
+    Space: LinearColorSpace + 'static,
+    Tf: TransferFunction,
+    Repr: EncodedRepr<Alpha>,
+    Alpha: AlphaMode,
+{
+    #[inline]
+    fn saturate(repr: Self::Repr) -> Self::Repr {
+        repr.saturate::<Tf>()
+    }
+}
+
+/// The sRGB primaries with the ITU-R BT.1886 EOTF of broadcast monitors and many TVs, encoded into 8 bits per
+/// component.
+///
+/// Use this instead of [`SrgbU8`] to preview colors for a calibrated Rec. 709 display. The black and white
+/// luminance of the display can be configured, see [`transfer_functions::Bt1886`].
+///
+/// ```
+/// use colstodian::Color;
+/// use colstodian::details::encodings::{Bt1886U8, SrgbU8};
+///
+/// let grey = Color::srgb_u8(128, 128, 128);
+/// let ideal: Color<Bt1886U8> = grey.convert();
+/// // a display with a raised black level needs lower code values for the same shade
+/// let lifted: Color<Bt1886U8<100, 100_000>> = grey.convert();
+/// assert!(ideal.r > grey.r && lifted.r < ideal.r);
+/// ```
+pub type Bt1886U8<const BLACK_MILLINITS: u32 = 0, const WHITE_MILLINITS: u32 = 100_000> = Encoded<
+    linear_spaces::Srgb,
+    transfer_functions::Bt1886<BLACK_MILLINITS, WHITE_MILLINITS>,
+    U8Repr,
+>;
+
+/// The 32-bit-per-component version of [`Bt1886U8`].
+pub type Bt1886F32<const BLACK_MILLINITS: u32 = 0, const WHITE_MILLINITS: u32 = 100_000> = Encoded<
+    linear_spaces::Srgb,
+    transfer_functions::Bt1886<BLACK_MILLINITS, WHITE_MILLINITS>,
+    F32Repr,
+>;
+
+/// The sRGB primaries with a pure gamma of 2.2, encoded into 8 bits per component.
+///
+/// Many PC monitors and televisions decode sRGB content with a pure 2.2 gamma rather than the piecewise sRGB
+/// curve, which makes dark shades darker than [`SrgbU8`] intends.
+pub type Gamma22U8 = Encoded<linear_spaces::Srgb, transfer_functions::Gamma22, U8Repr>;
+
+/// The 32-bit-per-component version of [`Gamma22U8`].
+pub type Gamma22F32 = Encoded<linear_spaces::Srgb, transfer_functions::Gamma22, F32Repr>;
+
+/// The sRGB primaries with a pure gamma of 2.4, encoded into 8 bits per component.
+///
+/// This is the same as [`Bt1886U8`] for a display with a black level of zero.
+pub type Gamma24U8 = Encoded<linear_spaces::Srgb, transfer_functions::Gamma24, U8Repr>;
+
+/// The 32-bit-per-component version of [`Gamma24U8`].
+pub type Gamma24F32 = Encoded<linear_spaces::Srgb, transfer_functions::Gamma24, F32Repr>;
+
+/// The Adobe RGB (1998) color encoding standard, with 8 bits per component.
+///
+/// Adobe RGB has a wider gamut than sRGB, mostly extending into greens and cyans, and is common in photography and
+/// print workflows.
+pub type AdobeRgbU8 = Encoded<linear_spaces::AdobeRgb, transfer_functions::AdobeRgb, U8Repr>;
+
+/// The 16-bit-per-component version of [`AdobeRgbU8`].
+pub type AdobeRgbU16 = Encoded<linear_spaces::AdobeRgb, transfer_functions::AdobeRgb, U16Repr>;
+
+/// The 32-bit-per-component version of [`AdobeRgbU8`].
+pub type AdobeRgbF32 = Encoded<linear_spaces::AdobeRgb, transfer_functions::AdobeRgb, F32Repr>;
+
+/// The ProPhoto RGB (ROMM RGB) color encoding standard, with 8 bits per component.
+///
+/// ProPhoto RGB has a very wide gamut and a D50 white point, so converting to and from the D65 encodings
+/// includes a chromatic adaptation. Because its gamut is so wide, 8 bits per component is prone to banding, and
+/// [`ProPhotoRgbU16`] is usually preferred.
+///
+/// ```
+/// use colstodian::Color;
+/// use colstodian::details::encodings::{ProPhotoRgbU16, SrgbU8};
+///
+/// // white is adapted between the D65 and D50 white points, rather than changing color
+/// let white: Color<ProPhotoRgbU16> = Color::srgb_u8(255, 255, 255).convert();
+/// assert!(white.repr.iter().all(|&c| c >= 65530));
+/// ```
+pub type ProPhotoRgbU8 =
+    Encoded<linear_spaces::ProPhotoRgb, transfer_functions::ProPhotoRgb, U8Repr>;
+
+/// The 16-bit-per-component version of [`ProPhotoRgbU8`].
+pub type ProPhotoRgbU16 =
+    Encoded<linear_spaces::ProPhotoRgb, transfer_functions::ProPhotoRgb, U16Repr>;
+
+/// The 32-bit-per-component version of [`ProPhotoRgbU8`].
+pub type ProPhotoRgbF32 =
+    Encoded<linear_spaces::ProPhotoRgb, transfer_functions::ProPhotoRgb, F32Repr>;
+
+/// The sRGB encoding, extended to values below `0.0` and above `1.0` with the mirrored, unclamped
+/// [`ExtendedSrgb`][transfer_functions::ExtendedSrgb] transfer function, with 32 bits per component.
+///
+/// This can represent colors outside of the sRGB gamut and brighter than sRGB white, as used by extended-range
+/// swapchains and UI toolkits. Unlike [`SrgbF32`], whose transfer function is only meaningful within `0.0..=1.0`,
+/// negative values are encoded symmetrically to positive ones, and unlike [`SrgbU8`] nothing is ever clamped.
+///
+/// ```
+/// use colstodian::Color;
+/// use colstodian::details::encodings::{ExtendedSrgbF32, LinearSrgb};
+/// # use colstodian::*;
+/// # use colstodian::equals_eps::*;
+///
+/// let bright = Color::linear_srgb(4.0, -0.01, 0.5);
+/// let extended = bright.convert::<ExtendedSrgbF32>();
+/// assert!(extended.r > 1.0 && extended.g < 0.0);
+/// assert_eq_eps!(extended.convert::<LinearSrgb>(), bright, 1e-5);
+/// ```
+pub type ExtendedSrgbF32 = Encoded<linear_spaces::Srgb, transfer_functions::ExtendedSrgb, F32Repr>;
+
+/// The [`ExtendedSrgbF32`] encoding with a separate alpha component.
+pub type ExtendedSrgbAF32 =
+    Encoded<linear_spaces::Srgb, transfer_functions::ExtendedSrgb, F32ARepr, SeparateAlpha>;
+
+/// The scRGB encoding: linear values with the sRGB/BT.709 primaries, stored as 16-bit half floats, as used by the
+/// Windows HDR compositor.
+///
+/// A value of `1.0` is sRGB white (80 cd/m² in scRGB terms). Values are never clamped, so negative components
+/// represent colors outside of the sRGB gamut and components above `1.0` are brighter than sRGB white, up to the
+/// half-float maximum of `65504.0`.
+///
+/// ```
+/// use colstodian::Color;
+/// use colstodian::details::encodings::ScRgbF16;
+/// use colstodian::details::reprs::F16;
+/// # use colstodian::*;
+///
+/// let hdr: Color<ScRgbF16> = Color::linear_srgb(12.5, 1.0, -0.25).convert();
+/// assert_eq!(hdr.repr, [F16::from_f32(12.5), F16::ONE, F16::from_f32(-0.25)]);
+/// ```
+pub type ScRgbF16 = Encoded<linear_spaces::Srgb, transfer_functions::ExtendedLinear, F16Repr>;
+
+/// The [`ScRgbF16`] encoding with a separate alpha component.
+pub type ScRgbAF16 =
+    Encoded<linear_spaces::Srgb, transfer_functions::ExtendedLinear, F16ARepr, SeparateAlpha>;
+
+/// The [`ScRgbF16`] encoding with an alpha component which its color components are premultiplied by, as expected
+/// by premultiplied-alpha swapchains.
+pub type ScRgbAF16Premultiplied =
+    Encoded<linear_spaces::Srgb, transfer_functions::ExtendedLinear, F16ARepr, PremultipliedAlpha>;
+
+/// The ACEScct encoding, for color grading: the [`AcesCg`][linear_spaces::AcesCg] primaries with the
+/// [`AcesCct`][transfer_functions::AcesCct] log curve, with 32 bits per component.
+pub type AcesCctF32 = Encoded<linear_spaces::AcesCg, transfer_functions::AcesCct, F32Repr>;
+
+/// Footage from ARRI cameras using LogC3 (for an exposure index of 800) and ARRI Wide Gamut 3, with 32 bits per
+/// component.
+///
+/// Like the other camera log encodings, this is scene-referred, and is usually converted to [`AcesCgF32`] for
+/// compositing and grading.
+pub type ArriLogC3 = Encoded<linear_spaces::ArriWideGamut3, transfer_functions::LogC3, F32Repr>;
+
+/// Footage from ARRI cameras using LogC4 and ARRI Wide Gamut 4, with 32 bits per component.
+pub type ArriLogC4 = Encoded<linear_spaces::ArriWideGamut4, transfer_functions::LogC4, F32Repr>;
+
+/// Footage from Sony cameras using S-Log3 and S-Gamut3, with 32 bits per component.
+pub type SonySLog3SGamut3 = Encoded<linear_spaces::SGamut3, transfer_functions::SLog3, F32Repr>;
+
+/// Footage from Sony cameras using S-Log3 and S-Gamut3.Cine, with 32 bits per component.
+pub type SonySLog3SGamut3Cine =
+    Encoded<linear_spaces::SGamut3Cine, transfer_functions::SLog3, F32Repr>;
+
+/// Footage from Canon cameras using Canon Log and Cinema Gamut, with 32 bits per component.
+pub type CanonLogCinemaGamut =
+    Encoded<linear_spaces::CanonCinemaGamut, transfer_functions::CanonLog, F32Repr>;
+
+/// Footage from RED cameras using Log3G10 and REDWideGamutRGB, with 32 bits per component.
+pub type RedLog3G10 = Encoded<linear_spaces::RedWideGamutRgb, transfer_functions::Log3G10, F32Repr>;
+
+/// Converts between two [`ColorEncodingF64`]s entirely in `f64`. Used to implement
+/// [`ConvertFrom::convert_raw`] between the `f64` encodings.
+#[inline]
+fn convert_raw_f64<SrcEnc, DstEnc>(mut src: SrcEnc::Repr) -> DstEnc::Repr
+where
+    SrcEnc: ColorEncodingF64,
+    DstEnc: ColorEncodingF64 + ConvertFrom<SrcEnc>,
+    DstEnc::LinearSpace: LinearConvertFromRaw<SrcEnc::LinearSpace>,
+{
+    <DstEnc as ConvertFrom<SrcEnc>>::map_src(&mut src);
+    let (mut raw, alpha) = SrcEnc::src_transform_raw_f64(src);
+    <DstEnc::LinearSpace as LinearConvertFromRaw<SrcEnc::LinearSpace>>::linear_part_raw_f64(
+        &mut raw,
+    );
+    DstEnc::dst_transform_raw_f64(raw, alpha)
+}
+
+macro_rules! impl_convert_from_f64 {
+    ($dst:ident <= $($src:ident),+) => {
+        $(
+            impl ConvertFrom<$src> for $dst {
+                #[inline]
+                fn convert_raw(src: <$src as ColorEncoding>::Repr) -> Self::Repr {
+                    convert_raw_f64::<$src, Self>(src)
+                }
+            }
+        )+
+    };
+}
+
+/// Changes the precision of a color between an `f32` encoding and its `f64` counterpart. This is
+/// deliberately not a [`ConvertFrom`] impl, so that converting between precisions is always explicit.
+macro_rules! impl_precision_conversions {
+    ($($enc_f32:ident <=> $enc_f64:ident, $widen:ident, $narrow:ident);+ $(;)?) => {
+        $(
+            impl Color<$enc_f32> {
+                #[doc = concat!("Converts `self` to the double precision [`", stringify!($enc_f64), "`] encoding.")]
+                #[inline(always)]
+                pub fn to_f64(self) -> Color<$enc_f64> {
+                    Color::from_repr(self.repr.$widen())
+                }
+            }
+
+            impl Color<$enc_f64> {
+                #[doc = concat!("Converts `self` to the single precision [`", stringify!($enc_f32), "`] encoding.")]
+                ///
+                /// This rounds each component to the nearest `f32`.
+                #[inline(always)]
+                pub fn to_f32(self) -> Color<$enc_f32> {
+                    Color::from_repr(self.repr.$narrow())
+                }
+            }
+        )+
+    };
+}
+
+#[inline]
+fn srgb_eotf_f64(color: DVec3) -> DVec3 {
+    let cutoff = color.cmplt(DVec3::splat(0.04045));
+    let higher = ((color + DVec3::splat(0.055)) / 1.055).powf(2.4);
+    let lower = color / 12.92;
+
+    DVec3::select(cutoff, lower, higher)
+}
+
+#[inline]
+fn srgb_oetf_f64(color: DVec3) -> DVec3 {
+    let cutoff = color.cmplt(DVec3::splat(0.0031308));
+    let higher = DVec3::splat(1.055) * color.powf(1.0 / 2.4) - DVec3::splat(0.055);
+    let lower = color * DVec3::splat(12.92);
+
+    DVec3::select(cutoff, lower, higher)
+}
+
+// Oklab matrices from https://bottosson.github.io/posts/oklab/
+const OKLAB_M_1_ROWS: [[f64; 3]; 3] = [
+    [0.8189330101, 0.3618667424, -0.1288597137],
+    [0.0329845436, 0.9293118715, 0.0361456387],
+    [0.0482003018, 0.2643662691, 0.6338517070],
+];
+const OKLAB_M_2_ROWS: [[f64; 3]; 3] = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+const OKLAB_M_1_F64: DMat3 = matrices_f64::to_dmat3(&OKLAB_M_1_ROWS);
+const OKLAB_M_2_F64: DMat3 = matrices_f64::to_dmat3(&OKLAB_M_2_ROWS);
+const OKLAB_M_1_INV_F64: DMat3 = matrices_f64::to_dmat3(&matrices_f64::inverse(&OKLAB_M_1_ROWS));
+const OKLAB_M_2_INV_F64: DMat3 = matrices_f64::to_dmat3(&matrices_f64::inverse(&OKLAB_M_2_ROWS));
+
+/// The fully-encoded form of the sRGB color encoding standard, with components stored as `f64`s.
+///
+/// This is the double precision version of [`SrgbF32`].
+pub struct SrgbF64;
+
+impl Color<SrgbF64> {
+    /// Create a [`Color`] in the [`SrgbF64`] encoding.
+    ///
+    /// See [the `SrgbF32` encoding docs][SrgbF32] for more info.
+    #[inline(always)]
+    pub const fn srgb_f64(r: f64, g: f64, b: f64) -> Self {
+        Color::from_repr(DVec3::new(r, g, b))
+    }
+}
+
+impl ColorEncoding for SrgbF64 {
+    type Repr = F64Repr;
+
+    type ComponentStruct = Rgb<f64>;
+
+    type LinearSpace = linear_spaces::Srgb;
+
+    const NAME: &'static str = "SrgbF64";
+
+    #[inline]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        let (raw, alpha) = Self::src_transform_raw_f64(repr);
+        (raw.as_vec3(), alpha as f32)
+    }
+
+    #[inline]
+    fn dst_transform_raw(raw: glam::Vec3, alpha: f32) -> Self::Repr {
+        Self::dst_transform_raw_f64(raw.as_dvec3(), alpha as f64)
+    }
+}
+
+impl ColorEncodingF64 for SrgbF64 {
+    #[inline]
+    fn src_transform_raw_f64(repr: Self::Repr) -> (DVec3, f64) {
+        (srgb_eotf_f64(repr), 1.0)
+    }
+
+    #[inline]
+    fn dst_transform_raw_f64(raw: DVec3, _: f64) -> Self::Repr {
+        srgb_oetf_f64(raw)
+    }
+}
+
+impl_convert_from_f64!(
+    SrgbF64 <= SrgbAF64,
+    LinearSrgbF64,
+    LinearSrgbAF64,
+    LinearSrgbAPremultipliedF64,
+    OklabF64
+);
+
+/// The fully-encoded form of the sRGB color encoding standard, with separate alpha component and components
+/// stored as `f64`s.
+///
+/// This is the double precision version of [`SrgbAF32`].
+pub struct SrgbAF64;
+
+impl Color<SrgbAF64> {
+    /// Create a [`Color`] in the [`SrgbAF64`] encoding.
+    ///
+    /// See [the `SrgbAF32` encoding docs][SrgbAF32] for more info.
+    #[inline(always)]
+    pub const fn srgba_f64(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Color::from_repr(DVec4::new(r, g, b, a))
+    }
+}
+
+impl ColorEncoding for SrgbAF64 {
+    type Repr = F64ARepr;
+
+    type ComponentStruct = RgbA<f64>;
+
+    type LinearSpace = linear_spaces::Srgb;
+
+    const NAME: &'static str = "SrgbAF64";
+
+    #[inline]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        let (raw, alpha) = Self::src_transform_raw_f64(repr);
+        (raw.as_vec3(), alpha as f32)
+    }
+
+    #[inline]
+    fn dst_transform_raw(raw: glam::Vec3, alpha: f32) -> Self::Repr {
+        Self::dst_transform_raw_f64(raw.as_dvec3(), alpha as f64)
+    }
+}
+
+impl ColorEncodingF64 for SrgbAF64 {
+    #[inline]
+    fn src_transform_raw_f64(repr: Self::Repr) -> (DVec3, f64) {
+        (srgb_eotf_f64(repr.xyz()), repr.w)
+    }
+
+    #[inline]
+    fn dst_transform_raw_f64(raw: DVec3, alpha: f64) -> Self::Repr {
+        srgb_oetf_f64(raw).extend(alpha)
+    }
+}
+
+impl_convert_from_f64!(
+    SrgbAF64 <= SrgbF64,
+    LinearSrgbF64,
+    LinearSrgbAF64,
+    LinearSrgbAPremultipliedF64,
+    OklabF64
+);
+
+impl AlphaOver for SrgbAF64 {
+    fn composite(over: Color<Self>, under: Color<Self>) -> Color<Self> {
+        let over = over.convert::<LinearSrgbAPremultipliedF64>();
+        let under = under.convert::<LinearSrgbAPremultipliedF64>();
+        let comp = over.alpha_over(under);
+        comp.convert::<Self>()
+    }
+
+    fn porter_duff(src: Color<Self>, dst: Color<Self>, op: PorterDuff) -> Color<Self> {
+        let src = src.convert::<LinearSrgbAPremultipliedF64>();
+        let dst = dst.convert::<LinearSrgbAPremultipliedF64>();
+        let comp = src.porter_duff(dst, op);
+        comp.convert::<Self>()
+    }
+}
+
+/// The linear form of the sRGB color encoding standard, with components stored as `f64`s.
+///
+/// This is the double precision version of [`LinearSrgb`].
+pub struct LinearSrgbF64;
+
+impl Color<LinearSrgbF64> {
+    /// Create a [`Color`] in the [`LinearSrgbF64`] encoding.
+    ///
+    /// See [the `LinearSrgb` encoding docs][LinearSrgb] for more info.
+    #[inline(always)]
+    pub const fn linear_srgb_f64(r: f64, g: f64, b: f64) -> Self {
+        Color::from_repr(DVec3::new(r, g, b))
+    }
+}
+
+impl ColorEncoding for LinearSrgbF64 {
+    type Repr = F64Repr;
+
+    type ComponentStruct = Rgb<f64>;
+
+    type LinearSpace = linear_spaces::Srgb;
+
+    const NAME: &'static str = "LinearSrgbF64";
+
+    #[inline]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        let (raw, alpha) = Self::src_transform_raw_f64(repr);
+        (raw.as_vec3(), alpha as f32)
+    }
+
+    #[inline]
+    fn dst_transform_raw(raw: glam::Vec3, alpha: f32) -> Self::Repr {
+        Self::dst_transform_raw_f64(raw.as_dvec3(), alpha as f64)
+    }
+}
+
+impl ColorEncodingF64 for LinearSrgbF64 {
+    #[inline]
+    fn src_transform_raw_f64(repr: Self::Repr) -> (DVec3, f64) {
+        (repr, 1.0)
+    }
+
+    #[inline]
+    fn dst_transform_raw_f64(raw: DVec3, _: f64) -> Self::Repr {
+        raw
+    }
+}
+
+impl_convert_from_f64!(
+    LinearSrgbF64 <= SrgbF64,
+    SrgbAF64,
+    LinearSrgbAF64,
+    LinearSrgbAPremultipliedF64,
+    OklabF64
+);
+
+impl WorkingEncoding for LinearSrgbF64 {}
+impl LinearLight for LinearSrgbF64 {}
+
+/// The linear form of the sRGB color encoding standard with a separate alpha component, with components
+/// stored as `f64`s.
+///
+/// This is the double precision version of [`LinearSrgbA`].
+pub struct LinearSrgbAF64;
+
+impl Color<LinearSrgbAF64> {
+    /// Create a [`Color`] in the [`LinearSrgbAF64`] encoding.
+    ///
+    /// See [the `LinearSrgbA` encoding docs][LinearSrgbA] for more info.
+    #[inline(always)]
+    pub const fn linear_srgba_f64(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Color::from_repr(DVec4::new(r, g, b, a))
+    }
+}
+
+impl ColorEncoding for LinearSrgbAF64 {
+    type Repr = F64ARepr;
+
+    type ComponentStruct = RgbA<f64>;
+
+    type LinearSpace = linear_spaces::Srgb;
+
+    const NAME: &'static str = "LinearSrgbAF64";
+
+    #[inline]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        let (raw, alpha) = Self::src_transform_raw_f64(repr);
+        (raw.as_vec3(), alpha as f32)
+    }
+
+    #[inline]
+    fn dst_transform_raw(raw: glam::Vec3, alpha: f32) -> Self::Repr {
+        Self::dst_transform_raw_f64(raw.as_dvec3(), alpha as f64)
+    }
+}
+
+impl ColorEncodingF64 for LinearSrgbAF64 {
+    #[inline]
+    fn src_transform_raw_f64(repr: Self::Repr) -> (DVec3, f64) {
+        (repr.xyz(), repr.w)
+    }
+
+    #[inline]
+    fn dst_transform_raw_f64(raw: DVec3, alpha: f64) -> Self::Repr {
+        raw.extend(alpha)
+    }
+}
+
+impl_convert_from_f64!(
+    LinearSrgbAF64 <= SrgbF64,
+    SrgbAF64,
+    LinearSrgbF64,
+    LinearSrgbAPremultipliedF64,
+    OklabF64
+);
+
+impl WorkingEncoding for LinearSrgbAF64 {}
+impl LinearLight for LinearSrgbAF64 {}
+
+impl AlphaOver for LinearSrgbAF64 {
+    fn composite(over: Color<Self>, under: Color<Self>) -> Color<Self> {
+        let over = over.convert::<LinearSrgbAPremultipliedF64>();
+        let under = under.convert::<LinearSrgbAPremultipliedF64>();
+        let comp = over.alpha_over(under);
+        comp.convert::<Self>()
+    }
+
+    fn porter_duff(src: Color<Self>, dst: Color<Self>, op: PorterDuff) -> Color<Self> {
+        let src = src.convert::<LinearSrgbAPremultipliedF64>();
+        let dst = dst.convert::<LinearSrgbAPremultipliedF64>();
+        let comp = src.porter_duff(dst, op);
+        comp.convert::<Self>()
+    }
+}
+
+/// The linear form of the sRGB color encoding standard with a *premultiplied* alpha component, with components
+/// stored as `f64`s.
+///
+/// This is the double precision version of [`LinearSrgbAPremultiplied`].
+pub struct LinearSrgbAPremultipliedF64;
+
+impl Color<LinearSrgbAPremultipliedF64> {
+    /// Create a [`Color`] in the [`LinearSrgbAPremultipliedF64`] encoding.
+    ///
+    /// See [the `LinearSrgbAPremultiplied` encoding docs][LinearSrgbAPremultiplied] for more info.
+    #[inline(always)]
+    pub const fn linear_srgba_premultiplied_f64(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Color::from_repr(DVec4::new(r, g, b, a))
+    }
+}
+
+impl ColorEncoding for LinearSrgbAPremultipliedF64 {
+    type Repr = F64ARepr;
+
+    type ComponentStruct = RgbA<f64>;
+
+    type LinearSpace = linear_spaces::Srgb;
+
+    const NAME: &'static str = "LinearSrgbAPremultipliedF64";
+
+    #[inline]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        let (raw, alpha) = Self::src_transform_raw_f64(repr);
+        (raw.as_vec3(), alpha as f32)
+    }
+
+    #[inline]
+    fn dst_transform_raw(raw: glam::Vec3, alpha: f32) -> Self::Repr {
+        Self::dst_transform_raw_f64(raw.as_dvec3(), alpha as f64)
+    }
+}
+
+impl ColorEncodingF64 for LinearSrgbAPremultipliedF64 {
+    #[inline]
+    fn src_transform_raw_f64(repr: Self::Repr) -> (DVec3, f64) {
+        let separated = unpremultiply_f64(repr.xyz(), repr.w);
+        (separated, repr.w)
+    }
+
+    #[inline]
+    fn dst_transform_raw_f64(raw: DVec3, alpha: f64) -> Self::Repr {
+        let premultiplied = raw * alpha;
+        premultiplied.extend(alpha)
+    }
+}
+
+impl_convert_from_f64!(
+    LinearSrgbAPremultipliedF64 <= SrgbF64,
+    SrgbAF64,
+    LinearSrgbF64,
+    LinearSrgbAF64,
+    OklabF64
+);
+
+impl LinearLight for LinearSrgbAPremultipliedF64 {}
+
+impl AlphaOver for LinearSrgbAPremultipliedF64 {
+    #[inline]
+    fn composite(over: Color<Self>, under: Color<Self>) -> Color<Self> {
+        Color::from_repr(over.repr + under.repr * (1.0 - over.repr.w))
+    }
+
+    #[inline]
+    fn porter_duff(src: Color<Self>, dst: Color<Self>, op: PorterDuff) -> Color<Self> {
+        Color::from_repr(op.apply_f64(src.repr, dst.repr))
+    }
+}
+
+/// A 64-bit-per-component version of the Oklab perceptually-uniform color space.
+///
+/// This is the double precision version of [`Oklab`].
+pub struct OklabF64;
+
+impl Color<OklabF64> {
+    /// Create a [`Color`] in the [`OklabF64`] color encoding.
+    #[inline(always)]
+    pub const fn oklab_f64(l: f64, a: f64, b: f64) -> Self {
+        Color::from_repr(DVec3::new(l, a, b))
+    }
+}
+
+impl ColorEncoding for OklabF64 {
+    type Repr = F64Repr;
+
+    type ComponentStruct = Lab<f64>;
+
+    type LinearSpace = linear_spaces::CieXYZ;
+
+    const NAME: &'static str = "OklabF64";
+
+    #[inline]
+    fn src_transform_raw(repr: Self::Repr) -> (glam::Vec3, f32) {
+        let (raw, alpha) = Self::src_transform_raw_f64(repr);
+        (raw.as_vec3(), alpha as f32)
+    }
+
+    #[inline]
+    fn dst_transform_raw(raw: glam::Vec3, alpha: f32) -> Self::Repr {
+        Self::dst_transform_raw_f64(raw.as_dvec3(), alpha as f64)
+    }
+}
+
+impl ColorEncodingF64 for OklabF64 {
+    #[inline]
+    fn src_transform_raw_f64(repr: Self::Repr) -> (DVec3, f64) {
+        let lms = OKLAB_M_2_INV_F64 * repr;
+        (OKLAB_M_1_INV_F64 * (lms * lms * lms), 1.0)
+    }
+
+    #[inline]
+    fn dst_transform_raw_f64(raw: DVec3, _: f64) -> Self::Repr {
+        let lms = OKLAB_M_1_F64 * raw;
+        OKLAB_M_2_F64 * DVec3::new(lms.x.cbrt(), lms.y.cbrt(), lms.z.cbrt())
+    }
+}
+
+impl_convert_from_f64!(
+    OklabF64 <= SrgbF64,
+    SrgbAF64,
+    LinearSrgbF64,
+    LinearSrgbAF64,
+    LinearSrgbAPremultipliedF64
+);
+
+impl WorkingEncoding for OklabF64 {}
+impl PerceptualEncoding for OklabF64 {}
+
+impl_referred!(
+    DisplayReferral => SrgbF64, SrgbAF64, LinearSrgbF64, LinearSrgbAF64, LinearSrgbAPremultipliedF64, OklabF64
+);
+
+impl_rgb_encoding!(SrgbF64, LinearSrgbF64 => Rec709, |repr| repr.as_vec3());
+impl_rgb_encoding!(SrgbAF64, LinearSrgbAF64 => Rec709, |repr| repr.xyz().as_vec3());
+impl_rgb_encoding!(LinearSrgbAPremultipliedF64 => Rec709, |repr| Self::src_transform_raw(repr).0);
+
+impl_saturate!(SrgbF64, LinearSrgbF64 => |repr| repr.clamp(DVec3::ZERO, DVec3::ONE));
+impl_saturate!(SrgbAF64, LinearSrgbAF64 => |repr| repr.clamp(DVec4::ZERO, DVec4::ONE));
+impl_saturate!(LinearSrgbAPremultipliedF64 => |repr| {
+    let alpha = repr.w.clamp(0.0, 1.0);
+    repr.xyz().clamp(DVec3::ZERO, DVec3::splat(alpha)).extend(alpha)
+});
+impl_saturate!(OklabF64 => |repr| DVec3::new(repr.x.clamp(0.0, 1.0), repr.y, repr.z));
+
+impl_display_gamut!(
+    SrgbF64, SrgbAF64, LinearSrgbF64, LinearSrgbAF64, LinearSrgbAPremultipliedF64 => 0.0, 1.0
+);
+impl_display_gamut!(OklabF64 => 0.0, f32::INFINITY);
+
+impl_precision_conversions!(
+    SrgbF32 <=> SrgbF64, as_dvec3, as_vec3;
+    SrgbAF32 <=> SrgbAF64, as_dvec4, as_vec4;
+    LinearSrgb <=> LinearSrgbF64, as_dvec3, as_vec3;
+    LinearSrgbA <=> LinearSrgbAF64, as_dvec4, as_vec4;
+    LinearSrgbAPremultiplied <=> LinearSrgbAPremultipliedF64, as_dvec4, as_vec4;
+    Oklab <=> OklabF64, as_dvec3, as_vec3;
+);
@@ -0,0 +1,238 @@
+use crate::encodings::{SrgbAU8, SrgbU8};
+use crate::error::HexParseError;
+use crate::Color;
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
+
+#[inline]
+const fn hex_digit_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Parses `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` (with the `#` optional) into `[r, g, b, a]`.
+/// If the string has no alpha digits, alpha is `255`. If `allow_alpha` is false, the
+/// 4 and 8 digit forms are rejected.
+pub(crate) const fn parse_hex(s: &str, allow_alpha: bool) -> Result<[u8; 4], HexParseError> {
+    let bytes = s.as_bytes();
+    let start = if !bytes.is_empty() && bytes[0] == b'#' {
+        1
+    } else {
+        0
+    };
+    let len = bytes.len() - start;
+
+    let short = match len {
+        3 => true,
+        6 => false,
+        4 | 8 if allow_alpha => len == 4,
+        _ => {
+            return Err(HexParseError::InvalidLength {
+                len,
+                alpha: allow_alpha,
+            })
+        }
+    };
+
+    let mut out = [0, 0, 0, 255];
+    let mut i = 0;
+    while i < len {
+        let index = start + i;
+        let value = match hex_digit_value(bytes[index]) {
+            Some(value) => value,
+            None => return Err(HexParseError::InvalidDigit { index }),
+        };
+        if short {
+            out[i] = value * 17;
+        } else if i % 2 == 0 {
+            out[i / 2] = value << 4;
+        } else {
+            out[i / 2] |= value;
+        }
+        i += 1;
+    }
+
+    Ok(out)
+}
+
+/// A fixed-capacity string holding a formatted hex color like `#rrggbb` or `#rrggbbaa`.
+///
+/// Returned by [`Color::to_hex_string`]. It doesn't allocate, so it can be used without `std`.
+/// It dereferences to a `&str`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexString {
+    buf: [u8; 9],
+    len: u8,
+}
+
+impl HexString {
+    const DIGITS: &'static [u8; 16] = b"0123456789abcdef";
+
+    fn new(components: &[u8]) -> Self {
+        let mut buf = [b'#'; 9];
+        for (i, &c) in components.iter().enumerate() {
+            buf[1 + i * 2] = Self::DIGITS[(c >> 4) as usize];
+            buf[2 + i * 2] = Self::DIGITS[(c & 0xf) as usize];
+        }
+        Self {
+            buf,
+            len: 1 + components.len() as u8 * 2,
+        }
+    }
+
+    /// The formatted string, including the leading `#`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: `buf` is only ever filled with ASCII bytes
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len as usize]) }
+    }
+}
+
+impl Deref for HexString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for HexString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for HexString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for HexString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Color<SrgbU8> {
+    /// Parses a hex color string in the `#rgb` or `#rrggbb` form. The leading `#` is optional and
+    /// digits are case-insensitive.
+    ///
+    /// This is also available through the [`FromStr`] and [`TryFrom<&str>`] impls.
+    ///
+    /// ```
+    /// # use colstodian::Color;
+    /// # use colstodian::error::HexParseError;
+    /// assert_eq!(Color::srgb_hex("#6633cc"), Ok(Color::srgb_u8(0x66, 0x33, 0xcc)));
+    /// assert_eq!("63c".parse(), Ok(Color::srgb_u8(0x66, 0x33, 0xcc)));
+    /// assert_eq!(
+    ///     Color::srgb_hex("#6633ccff"),
+    ///     Err(HexParseError::InvalidLength { len: 8, alpha: false }),
+    /// );
+    /// ```
+    pub const fn srgb_hex(s: &str) -> Result<Self, HexParseError> {
+        match parse_hex(s, false) {
+            Ok([r, g, b, _]) => Ok(Color::srgb_u8(r, g, b)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Formats `self` as a `#rrggbb` string without allocating.
+    ///
+    /// The same output is available through the [`LowerHex`][fmt::LowerHex] impl with `{:#x}`.
+    pub fn to_hex_string(self) -> HexString {
+        HexString::new(&self.repr)
+    }
+}
+
+impl Color<SrgbAU8> {
+    /// Parses a hex color string in the `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` form. The leading `#` is
+    /// optional and digits are case-insensitive. If no alpha is given, it is set to `255`.
+    ///
+    /// This is also available through the [`FromStr`] and [`TryFrom<&str>`] impls.
+    ///
+    /// ```
+    /// # use colstodian::Color;
+    /// assert_eq!(Color::srgba_hex("#6633cc80"), Ok(Color::srgba_u8(0x66, 0x33, 0xcc, 0x80)));
+    /// assert_eq!(Color::srgba_hex("63c"), Ok(Color::srgba_u8(0x66, 0x33, 0xcc, 0xff)));
+    /// ```
+    pub const fn srgba_hex(s: &str) -> Result<Self, HexParseError> {
+        match parse_hex(s, true) {
+            Ok([r, g, b, a]) => Ok(Color::srgba_u8(r, g, b, a)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Formats `self` as a `#rrggbbaa` string without allocating.
+    ///
+    /// The same output is available through the [`LowerHex`][fmt::LowerHex] impl with `{:#x}`.
+    pub fn to_hex_string(self) -> HexString {
+        HexString::new(&self.repr)
+    }
+}
+
+macro_rules! impl_hex_traits {
+    ($($enc:ident => $parse:ident),+) => {
+        $(
+            impl FromStr for Color<$enc> {
+                type Err = HexParseError;
+
+                #[inline]
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Self::$parse(s)
+                }
+            }
+
+            impl TryFrom<&str> for Color<$enc> {
+                type Error = HexParseError;
+
+                #[inline]
+                fn try_from(s: &str) -> Result<Self, Self::Error> {
+                    Self::$parse(s)
+                }
+            }
+
+            /// Writes the components as lowercase hex digits. With the alternate flag (`{:#x}`),
+            /// a leading `#` is written as well.
+            #[cfg(not(target_arch = "spirv"))]
+            impl fmt::LowerHex for Color<$enc> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let s = self.to_hex_string();
+                    if f.alternate() {
+                        f.write_str(&s)
+                    } else {
+                        f.write_str(&s[1..])
+                    }
+                }
+            }
+
+            /// Writes the components as uppercase hex digits. With the alternate flag (`{:#X}`),
+            /// a leading `#` is written as well.
+            #[cfg(not(target_arch = "spirv"))]
+            impl fmt::UpperHex for Color<$enc> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if f.alternate() {
+                        f.write_str("#")?;
+                    }
+                    for c in self.repr.iter() {
+                        write!(f, "{:02X}", c)?;
+                    }
+                    Ok(())
+                }
+            }
+        )+
+    };
+}
+
+impl_hex_traits!(SrgbU8 => srgb_hex, SrgbAU8 => srgba_hex);
@@ -1,6 +1,6 @@
 use crate::traits::*;
 
-use glam::Vec3;
+use glam::{DVec3, Vec3};
 
 use kolor::details::color::*;
 
@@ -9,6 +9,9 @@ macro_rules! impl_conversion {
         impl LinearConvertFromRaw<$space> for $dst_space {
             #[inline(always)]
             fn linear_part_raw(_: &mut Vec3) {}
+
+            #[inline(always)]
+            fn linear_part_raw_f64(_: &mut DVec3) {}
         }
     };
     ($space:ident to $dst_space:ident => $mat:ident) => {
@@ -17,6 +20,11 @@ macro_rules! impl_conversion {
             fn linear_part_raw(color: &mut Vec3) {
                 *color = kolor::details::generated_matrices::$mat * *color;
             }
+
+            #[inline(always)]
+            fn linear_part_raw_f64(color: &mut DVec3) {
+                *color = crate::matrices_f64::$mat * *color;
+            }
         }
     };
 }
@@ -29,6 +37,8 @@ impl_conversion!(SPACENAME to Bt2020            => PRIMARIES_WHITEPOINT_TO_BT_20
 impl_conversion!(SPACENAME to AcesCg            => PRIMARIES_WHITEPOINT_TO_AP1_D60);
 impl_conversion!(SPACENAME to Aces2065          => PRIMARIES_WHITEPOINT_TO_AP0_D60);
 impl_conversion!(SPACENAME to DisplayP3         => PRIMARIES_WHITEPOINT_TO_P3_D65);
+impl_conversion!(SPACENAME to AdobeRgb          => PRIMARIES_WHITEPOINT_TO_ADOBE_1998_D65);
+impl_conversion!(SPACENAME to ProPhotoRgb       => PRIMARIES_WHITEPOINT_TO_PRO_PHOTO_D50);
 */
 
 /// A type representing the linear part of the sRGB color space.
@@ -37,6 +47,7 @@ pub struct Srgb;
 impl LinearColorSpace for Srgb {
     const PRIMARIES: RGBPrimaries = RGBPrimaries::BT_709;
     const WHITE_POINT: WhitePoint = WhitePoint::D65;
+    const NAME: &'static str = "Srgb";
 }
 
 impl_conversion!(Srgb to Srgb              => None);
@@ -45,6 +56,8 @@ impl_conversion!(Srgb to Bt2020            => BT_709_D65_TO_BT_2020_D65);
 impl_conversion!(Srgb to AcesCg            => BT_709_D65_TO_AP1_D60);
 impl_conversion!(Srgb to Aces2065          => BT_709_D65_TO_AP0_D60);
 impl_conversion!(Srgb to DisplayP3         => BT_709_D65_TO_P3_D65);
+impl_conversion!(Srgb to AdobeRgb          => BT_709_D65_TO_ADOBE_1998_D65);
+impl_conversion!(Srgb to ProPhotoRgb       => BT_709_D65_TO_PRO_PHOTO_D50);
 
 /// A type representing the reference CIE XYZ 1931 color space.
 pub struct CieXYZ;
@@ -52,6 +65,7 @@ pub struct CieXYZ;
 impl LinearColorSpace for CieXYZ {
     const PRIMARIES: RGBPrimaries = RGBPrimaries::CIE_XYZ;
     const WHITE_POINT: WhitePoint = WhitePoint::D65;
+    const NAME: &'static str = "CieXYZ";
 }
 
 impl_conversion!(CieXYZ to CieXYZ            => None);
@@ -60,6 +74,8 @@ impl_conversion!(CieXYZ to Bt2020            => CIE_XYZ_D65_TO_BT_2020_D65);
 impl_conversion!(CieXYZ to AcesCg            => CIE_XYZ_D65_TO_AP1_D60);
 impl_conversion!(CieXYZ to Aces2065          => CIE_XYZ_D65_TO_AP0_D60);
 impl_conversion!(CieXYZ to DisplayP3         => CIE_XYZ_D65_TO_P3_D65);
+impl_conversion!(CieXYZ to AdobeRgb          => CIE_XYZ_D65_TO_ADOBE_1998_D65);
+impl_conversion!(CieXYZ to ProPhotoRgb       => CIE_XYZ_D65_TO_PRO_PHOTO_D50);
 
 /// A type representing the BT.2020 linear color space.
 pub struct Bt2020;
@@ -67,28 +83,160 @@ pub struct Bt2020;
 impl LinearColorSpace for Bt2020 {
     const PRIMARIES: RGBPrimaries = RGBPrimaries::BT_2020;
     const WHITE_POINT: WhitePoint = WhitePoint::D65;
+    const NAME: &'static str = "Bt2020";
 }
 
+impl_conversion!(Bt2020 to Bt2020            => None);
+impl_conversion!(Bt2020 to Srgb              => BT_2020_D65_TO_BT_709_D65);
+impl_conversion!(Bt2020 to CieXYZ            => BT_2020_D65_TO_CIE_XYZ_D65);
+impl_conversion!(Bt2020 to AcesCg            => BT_2020_D65_TO_AP1_D60);
+impl_conversion!(Bt2020 to Aces2065          => BT_2020_D65_TO_AP0_D60);
+impl_conversion!(Bt2020 to DisplayP3         => BT_2020_D65_TO_P3_D65);
+impl_conversion!(Bt2020 to AdobeRgb          => BT_2020_D65_TO_ADOBE_1998_D65);
+impl_conversion!(Bt2020 to ProPhotoRgb       => BT_2020_D65_TO_PRO_PHOTO_D50);
+
 /// A type representing the linear ACEScg color space.
 pub struct AcesCg;
 
 impl LinearColorSpace for AcesCg {
     const PRIMARIES: RGBPrimaries = RGBPrimaries::AP1;
     const WHITE_POINT: WhitePoint = WhitePoint::D60;
+    const NAME: &'static str = "AcesCg";
 }
 
+impl_conversion!(AcesCg to AcesCg            => None);
+impl_conversion!(AcesCg to Srgb              => AP1_D60_TO_BT_709_D65);
+impl_conversion!(AcesCg to CieXYZ            => AP1_D60_TO_CIE_XYZ_D65);
+impl_conversion!(AcesCg to Bt2020            => AP1_D60_TO_BT_2020_D65);
+impl_conversion!(AcesCg to Aces2065          => AP1_D60_TO_AP0_D60);
+impl_conversion!(AcesCg to DisplayP3         => AP1_D60_TO_P3_D65);
+impl_conversion!(AcesCg to AdobeRgb          => AP1_D60_TO_ADOBE_1998_D65);
+impl_conversion!(AcesCg to ProPhotoRgb       => AP1_D60_TO_PRO_PHOTO_D50);
+
 /// A type representing the linear ACES 2065 (aka ACES archival) color space.
 pub struct Aces2065;
 
 impl LinearColorSpace for Aces2065 {
     const PRIMARIES: RGBPrimaries = RGBPrimaries::AP0;
     const WHITE_POINT: WhitePoint = WhitePoint::D60;
+    const NAME: &'static str = "Aces2065";
 }
 
+impl_conversion!(Aces2065 to Aces2065          => None);
+impl_conversion!(Aces2065 to Srgb              => AP0_D60_TO_BT_709_D65);
+impl_conversion!(Aces2065 to CieXYZ            => AP0_D60_TO_CIE_XYZ_D65);
+impl_conversion!(Aces2065 to Bt2020            => AP0_D60_TO_BT_2020_D65);
+impl_conversion!(Aces2065 to AcesCg            => AP0_D60_TO_AP1_D60);
+impl_conversion!(Aces2065 to DisplayP3         => AP0_D60_TO_P3_D65);
+impl_conversion!(Aces2065 to AdobeRgb          => AP0_D60_TO_ADOBE_1998_D65);
+impl_conversion!(Aces2065 to ProPhotoRgb       => AP0_D60_TO_PRO_PHOTO_D50);
+
 /// A type representing the linear part of the Apple Display P3 color space.
 pub struct DisplayP3;
 
 impl LinearColorSpace for DisplayP3 {
     const PRIMARIES: RGBPrimaries = RGBPrimaries::P3;
     const WHITE_POINT: WhitePoint = WhitePoint::D65;
+    const NAME: &'static str = "DisplayP3";
+}
+
+impl_conversion!(DisplayP3 to DisplayP3         => None);
+impl_conversion!(DisplayP3 to Srgb              => P3_D65_TO_BT_709_D65);
+impl_conversion!(DisplayP3 to CieXYZ            => P3_D65_TO_CIE_XYZ_D65);
+impl_conversion!(DisplayP3 to Bt2020            => P3_D65_TO_BT_2020_D65);
+impl_conversion!(DisplayP3 to AcesCg            => P3_D65_TO_AP1_D60);
+impl_conversion!(DisplayP3 to Aces2065          => P3_D65_TO_AP0_D60);
+impl_conversion!(DisplayP3 to AdobeRgb          => P3_D65_TO_ADOBE_1998_D65);
+impl_conversion!(DisplayP3 to ProPhotoRgb       => P3_D65_TO_PRO_PHOTO_D50);
+
+/// A type representing the linear part of the Adobe RGB (1998) color space.
+pub struct AdobeRgb;
+
+impl LinearColorSpace for AdobeRgb {
+    const PRIMARIES: RGBPrimaries = RGBPrimaries::ADOBE_1998;
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+    const NAME: &'static str = "AdobeRgb";
+}
+
+impl_conversion!(AdobeRgb to AdobeRgb          => None);
+impl_conversion!(AdobeRgb to Srgb              => ADOBE_1998_D65_TO_BT_709_D65);
+impl_conversion!(AdobeRgb to CieXYZ            => ADOBE_1998_D65_TO_CIE_XYZ_D65);
+impl_conversion!(AdobeRgb to Bt2020            => ADOBE_1998_D65_TO_BT_2020_D65);
+impl_conversion!(AdobeRgb to AcesCg            => ADOBE_1998_D65_TO_AP1_D60);
+impl_conversion!(AdobeRgb to Aces2065          => ADOBE_1998_D65_TO_AP0_D60);
+impl_conversion!(AdobeRgb to DisplayP3         => ADOBE_1998_D65_TO_P3_D65);
+impl_conversion!(AdobeRgb to ProPhotoRgb       => ADOBE_1998_D65_TO_PRO_PHOTO_D50);
+
+/// A type representing the linear part of the ProPhoto RGB (aka ROMM RGB) color space, which has a D50 white point.
+///
+/// Its primaries cover almost all colors that surfaces can reflect, and two of them are imaginary (outside the
+/// range of human vision), so many values in it don't represent real colors.
+pub struct ProPhotoRgb;
+
+impl LinearColorSpace for ProPhotoRgb {
+    const PRIMARIES: RGBPrimaries = RGBPrimaries::PRO_PHOTO;
+    const WHITE_POINT: WhitePoint = WhitePoint::D50;
+    const NAME: &'static str = "ProPhotoRgb";
+}
+
+impl_conversion!(ProPhotoRgb to ProPhotoRgb       => None);
+impl_conversion!(ProPhotoRgb to Srgb              => PRO_PHOTO_D50_TO_BT_709_D65);
+impl_conversion!(ProPhotoRgb to CieXYZ            => PRO_PHOTO_D50_TO_CIE_XYZ_D65);
+impl_conversion!(ProPhotoRgb to Bt2020            => PRO_PHOTO_D50_TO_BT_2020_D65);
+impl_conversion!(ProPhotoRgb to AcesCg            => PRO_PHOTO_D50_TO_AP1_D60);
+impl_conversion!(ProPhotoRgb to Aces2065          => PRO_PHOTO_D50_TO_AP0_D60);
+impl_conversion!(ProPhotoRgb to DisplayP3         => PRO_PHOTO_D50_TO_P3_D65);
+impl_conversion!(ProPhotoRgb to AdobeRgb          => PRO_PHOTO_D50_TO_ADOBE_1998_D65);
+
+// The native gamuts of digital cinema cameras. These aren't known to `kolor`, so their matrices are derived at
+// compile time, and they only convert directly to and from the spaces above.
+
+crate::linear_color_space! {
+    /// A type representing ARRI Wide Gamut 3, the native gamut of ARRI cameras using [LogC3][crate::details::transfer_functions::LogC3].
+    pub struct ArriWideGamut3 {
+        primaries: [[0.684, 0.313], [0.221, 0.848], [0.0861, -0.102]],
+        white_point: [0.3127, 0.329],
+    }
+}
+
+crate::linear_color_space! {
+    /// A type representing ARRI Wide Gamut 4, the native gamut of ARRI cameras using [LogC4][crate::details::transfer_functions::LogC4].
+    pub struct ArriWideGamut4 {
+        primaries: [[0.7347, 0.2653], [0.1424, 0.8576], [0.0991, -0.0308]],
+        white_point: [0.3127, 0.329],
+    }
+}
+
+crate::linear_color_space! {
+    /// A type representing Sony's S-Gamut3, the full native gamut of Sony cameras.
+    pub struct SGamut3 {
+        primaries: [[0.73, 0.28], [0.14, 0.855], [0.1, -0.05]],
+        white_point: [0.3127, 0.329],
+    }
+}
+
+crate::linear_color_space! {
+    /// A type representing Sony's S-Gamut3.Cine, a narrower version of [`SGamut3`] which is easier to grade for
+    /// cinema projection.
+    pub struct SGamut3Cine {
+        primaries: [[0.766, 0.275], [0.225, 0.8], [0.089, -0.087]],
+        white_point: [0.3127, 0.329],
+    }
+}
+
+crate::linear_color_space! {
+    /// A type representing Canon Cinema Gamut, the native gamut of Canon cinema cameras.
+    pub struct CanonCinemaGamut {
+        primaries: [[0.74, 0.27], [0.17, 1.14], [0.08, -0.1]],
+        white_point: [0.3127, 0.329],
+    }
+}
+
+crate::linear_color_space! {
+    /// A type representing REDWideGamutRGB, the native gamut of RED cameras using
+    /// [Log3G10][crate::details::transfer_functions::Log3G10].
+    pub struct RedWideGamutRgb {
+        primaries: [[0.780308, 0.304253], [0.121595, 1.493994], [0.095612, -0.084589]],
+        white_point: [0.3127, 0.329],
+    }
 }
@@ -0,0 +1,268 @@
+//! These are derived at compile time from the same primaries, white points and chromatic adaptation
+//! (the "Sharp" cone space) that `kolor` uses to generate its `f32` matrices, so the two agree up to `f32` precision.
+//! The same derivation is used for spaces declared with [`linear_color_space!`][crate::linear_color_space!].
+
+use glam::{DMat3, Mat3, Vec3};
+
+/// A row-major 3x3 matrix, which is easier to work with in `const fn`s than [`DMat3`].
+type Rows = [[f64; 3]; 3];
+
+/// Chromaticity coordinates of a set of RGB primaries, or `None` for CIE XYZ itself.
+type Primaries = Option<[[f64; 2]; 3]>;
+
+const BT_709: Primaries = Some([[0.64, 0.33], [0.30, 0.60], [0.15, 0.06]]);
+const BT_2020: Primaries = Some([[0.708, 0.292], [0.17, 0.797], [0.131, 0.046]]);
+const AP0: Primaries = Some([[0.7347, 0.2653], [0.0, 1.0], [0.0001, -0.0770]]);
+const AP1: Primaries = Some([[0.713, 0.293], [0.165, 0.830], [0.128, 0.044]]);
+const P3: Primaries = Some([[0.680, 0.320], [0.265, 0.690], [0.150, 0.060]]);
+const ADOBE_1998: Primaries = Some([[0.64, 0.33], [0.21, 0.71], [0.15, 0.06]]);
+const PRO_PHOTO: Primaries = Some([
+    [0.734699, 0.265301],
+    [0.159597, 0.840403],
+    [0.036598, 0.000105],
+]);
+const CIE_XYZ: Primaries = None;
+
+const D50: [f64; 3] = [0.96422, 1.0, 0.82521];
+const D60: [f64; 3] = [0.9523, 1.0, 1.00859];
+const D65: [f64; 3] = [0.95047, 1.0, 1.08883];
+
+const SHARP: Rows = [
+    [1.2694, -0.0988, -0.1706],
+    [-0.8364, 1.8006, 0.0357],
+    [0.0297, -0.0315, 1.0018],
+];
+
+const IDENTITY: Rows = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+const fn mul(a: &Rows, b: &Rows) -> Rows {
+    let mut out = [[0.0; 3]; 3];
+    let mut i = 0;
+    while i < 3 {
+        let mut j = 0;
+        while j < 3 {
+            out[i][j] = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+            j += 1;
+        }
+        i += 1;
+    }
+    out
+}
+
+const fn mul_vec(m: &Rows, v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+pub(crate) const fn inverse(m: &Rows) -> Rows {
+    let [[a, b, c], [d, e, f], [g, h, i]] = *m;
+    let co_a = e * i - f * h;
+    let co_b = f * g - d * i;
+    let co_c = d * h - e * g;
+    let inv_det = 1.0 / (a * co_a + b * co_b + c * co_c);
+    [
+        [
+            co_a * inv_det,
+            (c * h - b * i) * inv_det,
+            (b * f - c * e) * inv_det,
+        ],
+        [
+            co_b * inv_det,
+            (a * i - c * g) * inv_det,
+            (c * d - a * f) * inv_det,
+        ],
+        [
+            co_c * inv_det,
+            (b * g - a * h) * inv_det,
+            (a * e - b * d) * inv_det,
+        ],
+    ]
+}
+
+pub(crate) const fn to_dmat3(m: &Rows) -> DMat3 {
+    DMat3::from_cols_array(&[
+        m[0][0], m[1][0], m[2][0], m[0][1], m[1][1], m[2][1], m[0][2], m[1][2], m[2][2],
+    ])
+}
+
+const fn rgb_to_xyz(primaries: Primaries, white: [f64; 3]) -> Rows {
+    let [[xr, yr], [xg, yg], [xb, yb]] = match primaries {
+        Some(primaries) => primaries,
+        None => return IDENTITY,
+    };
+    let base = [
+        [xr / yr, xg / yg, xb / yb],
+        [1.0, 1.0, 1.0],
+        [
+            (1.0 - xr - yr) / yr,
+            (1.0 - xg - yg) / yg,
+            (1.0 - xb - yb) / yb,
+        ],
+    ];
+    let [sr, sg, sb] = mul_vec(&inverse(&base), white);
+    let scale = [[sr, 0.0, 0.0], [0.0, sg, 0.0], [0.0, 0.0, sb]];
+    mul(&base, &scale)
+}
+
+const fn adaptation(src_white: [f64; 3], dst_white: [f64; 3]) -> Rows {
+    let src = mul_vec(&SHARP, src_white);
+    let dst = mul_vec(&SHARP, dst_white);
+    let scale = [
+        [dst[0] / src[0], 0.0, 0.0],
+        [0.0, dst[1] / src[1], 0.0],
+        [0.0, 0.0, dst[2] / src[2]],
+    ];
+    mul(&inverse(&SHARP), &mul(&scale, &SHARP))
+}
+
+/// The chromaticities which define a linear color space, from which its conversion matrices can be derived
+/// with [`conversion_between`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearSpaceDefinition {
+    primaries: Primaries,
+    white: [f64; 3],
+}
+
+impl LinearSpaceDefinition {
+    /// Defines a linear RGB color space from the `xy` chromaticities of its red, green and blue `primaries`
+    /// and its `white_point`.
+    pub const fn from_xy(primaries: [[f64; 2]; 3], white_point: [f64; 2]) -> Self {
+        let [x, y] = white_point;
+        Self {
+            primaries: Some(primaries),
+            white: [x / y, 1.0, (1.0 - x - y) / y],
+        }
+    }
+}
+
+/// The definition of the linear part of sRGB, [`Srgb`][crate::linear_spaces::Srgb].
+pub const BT_709_D65: LinearSpaceDefinition = LinearSpaceDefinition {
+    primaries: BT_709,
+    white: D65,
+};
+/// The definition of [`CieXYZ`][crate::linear_spaces::CieXYZ].
+pub const CIE_XYZ_D65: LinearSpaceDefinition = LinearSpaceDefinition {
+    primaries: CIE_XYZ,
+    white: D65,
+};
+/// The definition of [`Bt2020`][crate::linear_spaces::Bt2020].
+pub const BT_2020_D65: LinearSpaceDefinition = LinearSpaceDefinition {
+    primaries: BT_2020,
+    white: D65,
+};
+/// The definition of [`AcesCg`][crate::linear_spaces::AcesCg].
+pub const AP1_D60: LinearSpaceDefinition = LinearSpaceDefinition {
+    primaries: AP1,
+    white: D60,
+};
+/// The definition of [`Aces2065`][crate::linear_spaces::Aces2065].
+pub const AP0_D60: LinearSpaceDefinition = LinearSpaceDefinition {
+    primaries: AP0,
+    white: D60,
+};
+/// The definition of [`DisplayP3`][crate::linear_spaces::DisplayP3].
+pub const P3_D65: LinearSpaceDefinition = LinearSpaceDefinition {
+    primaries: P3,
+    white: D65,
+};
+/// The definition of [`AdobeRgb`][crate::linear_spaces::AdobeRgb].
+pub const ADOBE_1998_D65: LinearSpaceDefinition = LinearSpaceDefinition {
+    primaries: ADOBE_1998,
+    white: D65,
+};
+/// The definition of [`ProPhotoRgb`][crate::linear_spaces::ProPhotoRgb].
+pub const PRO_PHOTO_D50: LinearSpaceDefinition = LinearSpaceDefinition {
+    primaries: PRO_PHOTO,
+    white: D50,
+};
+
+/// The matrix converting linear values in the space defined by `src` to the space defined by `dst`,
+/// adapting between their white points.
+pub const fn conversion_between(src: &LinearSpaceDefinition, dst: &LinearSpaceDefinition) -> DMat3 {
+    conversion(src.primaries, src.white, dst.primaries, dst.white)
+}
+
+/// Narrows `m` to `f32`, in a `const` context.
+pub const fn to_mat3(m: &DMat3) -> Mat3 {
+    Mat3::from_cols(
+        Vec3::new(m.x_axis.x as f32, m.x_axis.y as f32, m.x_axis.z as f32),
+        Vec3::new(m.y_axis.x as f32, m.y_axis.y as f32, m.y_axis.z as f32),
+        Vec3::new(m.z_axis.x as f32, m.z_axis.y as f32, m.z_axis.z as f32),
+    )
+}
+
+const fn conversion(
+    src: Primaries,
+    src_white: [f64; 3],
+    dst: Primaries,
+    dst_white: [f64; 3],
+) -> DMat3 {
+    let src_to_xyz = rgb_to_xyz(src, src_white);
+    let xyz_to_dst = inverse(&rgb_to_xyz(dst, dst_white));
+    let adapt = adaptation(src_white, dst_white);
+    to_dmat3(&mul(&xyz_to_dst, &mul(&adapt, &src_to_xyz)))
+}
+
+pub const BT_709_D65_TO_CIE_XYZ_D65: DMat3 = conversion(BT_709, D65, CIE_XYZ, D65);
+pub const BT_709_D65_TO_BT_2020_D65: DMat3 = conversion(BT_709, D65, BT_2020, D65);
+pub const BT_709_D65_TO_AP1_D60: DMat3 = conversion(BT_709, D65, AP1, D60);
+pub const BT_709_D65_TO_AP0_D60: DMat3 = conversion(BT_709, D65, AP0, D60);
+pub const BT_709_D65_TO_P3_D65: DMat3 = conversion(BT_709, D65, P3, D65);
+
+pub const CIE_XYZ_D65_TO_BT_709_D65: DMat3 = conversion(CIE_XYZ, D65, BT_709, D65);
+pub const CIE_XYZ_D65_TO_BT_2020_D65: DMat3 = conversion(CIE_XYZ, D65, BT_2020, D65);
+pub const CIE_XYZ_D65_TO_AP1_D60: DMat3 = conversion(CIE_XYZ, D65, AP1, D60);
+pub const CIE_XYZ_D65_TO_AP0_D60: DMat3 = conversion(CIE_XYZ, D65, AP0, D60);
+pub const CIE_XYZ_D65_TO_P3_D65: DMat3 = conversion(CIE_XYZ, D65, P3, D65);
+
+pub const BT_2020_D65_TO_BT_709_D65: DMat3 = conversion(BT_2020, D65, BT_709, D65);
+pub const BT_2020_D65_TO_CIE_XYZ_D65: DMat3 = conversion(BT_2020, D65, CIE_XYZ, D65);
+pub const BT_2020_D65_TO_AP1_D60: DMat3 = conversion(BT_2020, D65, AP1, D60);
+pub const BT_2020_D65_TO_AP0_D60: DMat3 = conversion(BT_2020, D65, AP0, D60);
+pub const BT_2020_D65_TO_P3_D65: DMat3 = conversion(BT_2020, D65, P3, D65);
+
+pub const AP1_D60_TO_BT_709_D65: DMat3 = conversion(AP1, D60, BT_709, D65);
+pub const AP1_D60_TO_CIE_XYZ_D65: DMat3 = conversion(AP1, D60, CIE_XYZ, D65);
+pub const AP1_D60_TO_BT_2020_D65: DMat3 = conversion(AP1, D60, BT_2020, D65);
+pub const AP1_D60_TO_AP0_D60: DMat3 = conversion(AP1, D60, AP0, D60);
+pub const AP1_D60_TO_P3_D65: DMat3 = conversion(AP1, D60, P3, D65);
+pub const AP0_D60_TO_BT_709_D65: DMat3 = conversion(AP0, D60, BT_709, D65);
+pub const AP0_D60_TO_CIE_XYZ_D65: DMat3 = conversion(AP0, D60, CIE_XYZ, D65);
+pub const AP0_D60_TO_BT_2020_D65: DMat3 = conversion(AP0, D60, BT_2020, D65);
+pub const AP0_D60_TO_AP1_D60: DMat3 = conversion(AP0, D60, AP1, D60);
+pub const AP0_D60_TO_P3_D65: DMat3 = conversion(AP0, D60, P3, D65);
+pub const P3_D65_TO_BT_709_D65: DMat3 = conversion(P3, D65, BT_709, D65);
+pub const P3_D65_TO_CIE_XYZ_D65: DMat3 = conversion(P3, D65, CIE_XYZ, D65);
+pub const P3_D65_TO_BT_2020_D65: DMat3 = conversion(P3, D65, BT_2020, D65);
+pub const P3_D65_TO_AP1_D60: DMat3 = conversion(P3, D65, AP1, D60);
+pub const P3_D65_TO_AP0_D60: DMat3 = conversion(P3, D65, AP0, D60);
+
+pub const BT_709_D65_TO_ADOBE_1998_D65: DMat3 = conversion(BT_709, D65, ADOBE_1998, D65);
+pub const BT_709_D65_TO_PRO_PHOTO_D50: DMat3 = conversion(BT_709, D65, PRO_PHOTO, D50);
+pub const CIE_XYZ_D65_TO_ADOBE_1998_D65: DMat3 = conversion(CIE_XYZ, D65, ADOBE_1998, D65);
+pub const CIE_XYZ_D65_TO_PRO_PHOTO_D50: DMat3 = conversion(CIE_XYZ, D65, PRO_PHOTO, D50);
+pub const BT_2020_D65_TO_ADOBE_1998_D65: DMat3 = conversion(BT_2020, D65, ADOBE_1998, D65);
+pub const BT_2020_D65_TO_PRO_PHOTO_D50: DMat3 = conversion(BT_2020, D65, PRO_PHOTO, D50);
+pub const AP1_D60_TO_ADOBE_1998_D65: DMat3 = conversion(AP1, D60, ADOBE_1998, D65);
+pub const AP1_D60_TO_PRO_PHOTO_D50: DMat3 = conversion(AP1, D60, PRO_PHOTO, D50);
+pub const AP0_D60_TO_ADOBE_1998_D65: DMat3 = conversion(AP0, D60, ADOBE_1998, D65);
+pub const AP0_D60_TO_PRO_PHOTO_D50: DMat3 = conversion(AP0, D60, PRO_PHOTO, D50);
+pub const P3_D65_TO_ADOBE_1998_D65: DMat3 = conversion(P3, D65, ADOBE_1998, D65);
+pub const P3_D65_TO_PRO_PHOTO_D50: DMat3 = conversion(P3, D65, PRO_PHOTO, D50);
+pub const ADOBE_1998_D65_TO_BT_709_D65: DMat3 = conversion(ADOBE_1998, D65, BT_709, D65);
+pub const ADOBE_1998_D65_TO_CIE_XYZ_D65: DMat3 = conversion(ADOBE_1998, D65, CIE_XYZ, D65);
+pub const ADOBE_1998_D65_TO_BT_2020_D65: DMat3 = conversion(ADOBE_1998, D65, BT_2020, D65);
+pub const ADOBE_1998_D65_TO_AP1_D60: DMat3 = conversion(ADOBE_1998, D65, AP1, D60);
+pub const ADOBE_1998_D65_TO_AP0_D60: DMat3 = conversion(ADOBE_1998, D65, AP0, D60);
+pub const ADOBE_1998_D65_TO_P3_D65: DMat3 = conversion(ADOBE_1998, D65, P3, D65);
+pub const ADOBE_1998_D65_TO_PRO_PHOTO_D50: DMat3 = conversion(ADOBE_1998, D65, PRO_PHOTO, D50);
+pub const PRO_PHOTO_D50_TO_BT_709_D65: DMat3 = conversion(PRO_PHOTO, D50, BT_709, D65);
+pub const PRO_PHOTO_D50_TO_CIE_XYZ_D65: DMat3 = conversion(PRO_PHOTO, D50, CIE_XYZ, D65);
+pub const PRO_PHOTO_D50_TO_BT_2020_D65: DMat3 = conversion(PRO_PHOTO, D50, BT_2020, D65);
+pub const PRO_PHOTO_D50_TO_AP1_D60: DMat3 = conversion(PRO_PHOTO, D50, AP1, D60);
+pub const PRO_PHOTO_D50_TO_AP0_D60: DMat3 = conversion(PRO_PHOTO, D50, AP0, D60);
+pub const PRO_PHOTO_D50_TO_P3_D65: DMat3 = conversion(PRO_PHOTO, D50, P3, D65);
+pub const PRO_PHOTO_D50_TO_ADOBE_1998_D65: DMat3 = conversion(PRO_PHOTO, D50, ADOBE_1998, D65);
@@ -1,5 +1,8 @@
 use crate::traits::*;
 
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+
 /// Just a `[u8; 3]`. Used for 8-bits-per-channel, three channel encodings.
 pub type U8Repr = [u8; 3];
 
@@ -14,6 +17,135 @@ impl ColorRepr for U8ARepr {
     type Element = u8;
 }
 
+/// Just a `[u16; 3]`. Used for 16-bits-per-channel, three channel encodings.
+pub type U16Repr = [u16; 3];
+
+impl ColorRepr for U16Repr {
+    type Element = u16;
+}
+
+/// Just a `[u16; 4]`. Used for 16-bits-per-channel, four channel encodings.
+pub type U16ARepr = [u16; 4];
+
+impl ColorRepr for U16ARepr {
+    type Element = u16;
+}
+
+/// An IEEE 754 half-precision (16-bit) float, stored as its raw bits.
+///
+/// This is a minimal storage type for half-float encodings such as [`ScRgbF16`][crate::details::encodings::ScRgbF16],
+/// with no arithmetic of its own: convert to and from `f32` with [`F16::to_f32`] and [`F16::from_f32`].
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct F16(pub u16);
+
+impl F16 {
+    pub const ZERO: Self = Self(0x0000);
+    pub const ONE: Self = Self(0x3c00);
+
+    /// Convert an `f32` to the nearest [`F16`], rounding ties to even.
+    ///
+    /// Values too large for a half float become infinity, and NaNs stay NaN.
+    pub fn from_f32(x: f32) -> Self {
+        let bits = x.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exp = ((bits >> 23) & 0xff) as i32;
+        let man = bits & 0x7f_ffff;
+
+        // infinity or NaN, keeping NaNs quiet
+        if exp == 0xff {
+            let nan = if man != 0 {
+                0x0200 | (man >> 13) as u16
+            } else {
+                0
+            };
+            return Self(sign | 0x7c00 | nan);
+        }
+
+        let half_exp = exp - 127 + 15;
+        if half_exp >= 0x1f {
+            return Self(sign | 0x7c00);
+        }
+
+        if half_exp <= 0 {
+            // subnormal or zero
+            let shift = 14 - half_exp;
+            if shift > 24 {
+                return Self(sign);
+            }
+            let man = man | 0x80_0000;
+            let half_man = (man >> shift) as u16;
+            let round_bit = 1 << (shift - 1);
+            let round_up = (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0;
+            return Self(sign | (half_man + round_up as u16));
+        }
+
+        let half = sign | ((half_exp as u16) << 10) | (man >> 13) as u16;
+        let round_bit = 0x1000;
+        let round_up = (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0;
+        // a carry out of the mantissa correctly bumps the exponent
+        Self(half + round_up as u16)
+    }
+
+    /// Convert to an `f32`, which is always exact.
+    pub fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let exp = ((self.0 >> 10) & 0x1f) as u32;
+        let man = (self.0 & 0x3ff) as u32;
+
+        match exp {
+            0 => {
+                let magnitude = man as f32 * (1.0 / 16_777_216.0);
+                f32::from_bits(sign | magnitude.to_bits())
+            }
+            0x1f => f32::from_bits(sign | 0x7f80_0000 | (man << 13)),
+            _ => f32::from_bits(sign | ((exp + 112) << 23) | (man << 13)),
+        }
+    }
+}
+
+impl From<f32> for F16 {
+    #[inline]
+    fn from(x: f32) -> Self {
+        Self::from_f32(x)
+    }
+}
+
+impl From<F16> for f32 {
+    #[inline]
+    fn from(x: F16) -> Self {
+        x.to_f32()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for F16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f32(), f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for F16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+/// Just a `[F16; 3]`. Used for 16-bit half-float, three channel encodings.
+pub type F16Repr = [F16; 3];
+
+impl ColorRepr for F16Repr {
+    type Element = F16;
+}
+
+/// Just a `[F16; 4]`. Used for 16-bit half-float, four channel encodings.
+pub type F16ARepr = [F16; 4];
+
+impl ColorRepr for F16ARepr {
+    type Element = F16;
+}
+
 /// Just a [`glam::Vec3`] (also equivalent in layout to a `[f32; 3]`). Used for 32-bits-per-channel, three channel encodings.
 pub type F32Repr = glam::Vec3;
 
@@ -27,3 +159,17 @@ pub type F32ARepr = glam::Vec4;
 impl ColorRepr for F32ARepr {
     type Element = f32;
 }
+
+/// Just a [`glam::DVec3`] (also equivalent in layout to a `[f64; 3]`). Used for 64-bits-per-channel, three channel encodings.
+pub type F64Repr = glam::DVec3;
+
+impl ColorRepr for F64Repr {
+    type Element = f64;
+}
+
+/// Just a [`glam::DVec4`] (also equivalent in layout to a `[f64; 4]`). Used for 64-bits-per-channel, four channel encodings.
+pub type F64ARepr = glam::DVec4;
+
+impl ColorRepr for F64ARepr {
+    type Element = f64;
+}
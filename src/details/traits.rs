@@ -2,14 +2,16 @@ use core::ops::{Add, Mul, Sub};
 
 use crate::Color;
 
-use glam::Vec3;
+use glam::{DVec3, DVec4, Vec3, Vec4};
 use kolor::details::color::{RGBPrimaries, WhitePoint};
 
 /// A type that implements [`ColorEncoding`] represents a collection of metadata
 /// that together defines how a color's data is stored and what the values of that data
 /// actually mean.
 ///
-/// You can see a list of all the built-in color encodings in [`crate::details::encodings`].
+/// You can see a list of all the built-in color encodings in [`crate::details::encodings`]. Custom encodings,
+/// along with their conversions to and from the built-in ones, can be declared with
+/// [`color_encoding!`][crate::color_encoding!].
 pub trait ColorEncoding: Sized + 'static {
     /// The raw data representation used by this encoding.
     ///
@@ -41,6 +43,20 @@ pub trait ColorEncoding: Sized + 'static {
     fn dst_transform_raw(raw: Vec3, alpha: f32) -> Self::Repr;
 }
 
+/// Implemented by color encodings whose components are stored as `f64`s.
+///
+/// Conversions between two such encodings use these transforms in place of [`ColorEncoding::src_transform_raw`]
+/// and [`ColorEncoding::dst_transform_raw`], so they happen entirely in double precision.
+///
+/// You can see a list of all the built-in `f64` color encodings in [`crate::details::encodings`].
+pub trait ColorEncodingF64: ColorEncoding {
+    /// The `f64` version of [`ColorEncoding::src_transform_raw`].
+    fn src_transform_raw_f64(repr: Self::Repr) -> (DVec3, f64);
+
+    /// The `f64` version of [`ColorEncoding::dst_transform_raw`].
+    fn dst_transform_raw_f64(raw: DVec3, alpha: f64) -> Self::Repr;
+}
+
 /// Implementing this trait for a struct marks that it is safe to pointer cast `Repr` as `Self`.
 ///
 /// # Safety
@@ -63,12 +79,119 @@ pub trait ColorRepr: Sized + Clone + Copy + 'static {
     type Element: Sized + Clone + Copy + 'static;
 }
 
-/// Implemented by color encodings that can do alpha compositing.
+/// Implemented by color encodings with an alpha component, which can do alpha compositing.
+///
+/// Compositing always happens on premultiplied, linear values, whatever form the encoding stores them in.
 pub trait AlphaOver: ColorEncoding {
+    /// Composites `over` over `under`, i.e. [`PorterDuff::SourceOver`].
     fn composite(over: Color<Self>, under: Color<Self>) -> Color<Self>;
+
+    /// Composites `src` with `dst` using the Porter-Duff operator `op`.
+    ///
+    /// [`PorterDuff::SourceOver`] goes through [`composite`][AlphaOver::composite]. By default, the other operators
+    /// are applied to the premultiplied linear values given by the encoding's
+    /// [`src_transform_raw`][ColorEncoding::src_transform_raw], and the result is encoded again with its
+    /// [`dst_transform_raw`][ColorEncoding::dst_transform_raw].
+    #[inline]
+    fn porter_duff(src: Color<Self>, dst: Color<Self>, op: PorterDuff) -> Color<Self> {
+        match op {
+            PorterDuff::SourceOver => Self::composite(src, dst),
+            _ => crate::details::encodings::porter_duff_raw(src, dst, op),
+        }
+    }
+}
+
+/// The Porter-Duff compositing operators, which combine a source color with a destination color that it is drawn
+/// onto, as used by [`AlphaOver`] and [`Color::porter_duff`].
+///
+/// Each operator weights the premultiplied source and destination by a factor depending on the other's alpha,
+/// named here as in the CSS compositing specification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PorterDuff {
+    /// Neither the source nor the destination, leaving full transparency.
+    Clear,
+    /// Only the source.
+    Source,
+    /// Only the destination.
+    Destination,
+    /// The source drawn over the destination. This is the usual kind of alpha blending.
+    SourceOver,
+    /// The destination drawn over the source.
+    DestinationOver,
+    /// The part of the source inside the destination.
+    SourceIn,
+    /// The part of the destination inside the source.
+    DestinationIn,
+    /// The part of the source outside of the destination.
+    SourceOut,
+    /// The part of the destination outside of the source.
+    DestinationOut,
+    /// The part of the source inside the destination, drawn over the destination.
+    SourceAtop,
+    /// The part of the destination inside the source, drawn over the source.
+    DestinationAtop,
+    /// The parts of the source and the destination outside of each other.
+    Xor,
+    /// The sum of the source and the destination, with the resulting alpha clamped to `1.0`. The color components
+    /// aren't clamped, so adding bright colors can produce values brighter than white.
+    Plus,
+}
+
+impl PorterDuff {
+    /// The factors the premultiplied source and destination are multiplied by, given their alphas, in either `f32`
+    /// or `f64`.
+    #[inline]
+    fn factors<T>(self, src_alpha: T, dst_alpha: T) -> (T, T)
+    where
+        T: Copy + From<u8> + core::ops::Sub<Output = T>,
+    {
+        let (zero, one) = (T::from(0), T::from(1));
+        match self {
+            PorterDuff::Clear => (zero, zero),
+            PorterDuff::Source => (one, zero),
+            PorterDuff::Destination => (zero, one),
+            PorterDuff::SourceOver => (one, one - src_alpha),
+            PorterDuff::DestinationOver => (one - dst_alpha, one),
+            PorterDuff::SourceIn => (dst_alpha, zero),
+            PorterDuff::DestinationIn => (zero, src_alpha),
+            PorterDuff::SourceOut => (one - dst_alpha, zero),
+            PorterDuff::DestinationOut => (zero, one - src_alpha),
+            PorterDuff::SourceAtop => (dst_alpha, one - src_alpha),
+            PorterDuff::DestinationAtop => (one - dst_alpha, src_alpha),
+            PorterDuff::Xor => (one - dst_alpha, one - src_alpha),
+            PorterDuff::Plus => (one, one),
+        }
+    }
+
+    /// Composites the premultiplied, linear `src` with `dst`, each with their alpha in `w`.
+    #[inline]
+    pub fn apply(self, src: Vec4, dst: Vec4) -> Vec4 {
+        let (src_factor, dst_factor) = self.factors(src.w, dst.w);
+        let mut out = src * src_factor + dst * dst_factor;
+        if self == PorterDuff::Plus {
+            out.w = out.w.min(1.0);
+        }
+        out
+    }
+
+    /// The `f64` version of [`apply`][PorterDuff::apply].
+    #[inline]
+    pub fn apply_f64(self, src: DVec4, dst: DVec4) -> DVec4 {
+        let (src_factor, dst_factor) = self.factors(src.w, dst.w);
+        let mut out = src * src_factor + dst * dst_factor;
+        if self == PorterDuff::Plus {
+            out.w = out.w.min(1.0);
+        }
+        out
+    }
 }
 
 /// Implemented by color encodings that can perform saturate-style clamping.
+///
+/// The valid range depends on the encoding: display-referred RGB encodings are clamped to `0.0..=1.0`,
+/// scene-referred and XYZ encodings only to non-negative values, and perceptual encodings like
+/// [`Oklab`][crate::details::encodings::Oklab] only in their lightness component. Alpha components are always
+/// clamped to `0.0..=1.0`, and integer encodings are left as they are, since they can't hold out-of-range values.
 pub trait Saturate: ColorEncoding {
     fn saturate(repr: Self::Repr) -> Self::Repr;
 }
@@ -106,15 +229,297 @@ pub trait PerceptualEncoding: ColorEncoding + WorkingEncoding {}
 /// operations on the contained color values directly.
 pub trait WorkingEncoding: ColorEncoding {}
 
+/// Marks a color encoding which stores the values of its [`LinearColorSpace`] directly, with no transfer function
+/// applied, though they may be premultiplied by alpha. This unlocks [`adapt`][Color::adapt].
+pub trait LinearLight: ColorEncoding {}
+
+/// Implemented by color encodings which store red, green and blue components, unlocking
+/// [`luma`][Color::luma] on [`Color`].
+pub trait RgbEncoding: ColorEncoding {
+    /// The luma coefficients matching the primaries of this encoding's [`LinearColorSpace`].
+    fn luma_coefficients() -> LumaCoefficients;
+
+    /// The red, green and blue components of `repr` as they are encoded (i.e. with any transfer function
+    /// still applied and not premultiplied by alpha), normalized to `0.0..=1.0`.
+    fn encoded_rgb(repr: Self::Repr) -> Vec3;
+}
+
+/// Weights used to compute luma, the weighted sum of the *encoded* (usually gamma-compressed) components of
+/// an RGB color, as defined by the video standards for each set of primaries.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LumaCoefficients {
+    /// ITU-R BT.601, used by standard definition video.
+    Rec601,
+    /// ITU-R BT.709, which shares its primaries with sRGB.
+    Rec709,
+    /// ITU-R BT.2020.
+    Rec2020,
+    /// The red, green and blue weights for any other primaries, usually derived with
+    /// [`for_space`][LumaCoefficients::for_space].
+    Custom(Vec3),
+}
+
+impl LumaCoefficients {
+    /// Derives the coefficients for the primaries of `Space` from the `Y` row of its RGB to XYZ matrix, the same
+    /// way the video standards define theirs.
+    #[inline]
+    pub fn for_space<Space>() -> Self
+    where
+        Space: LinearColorSpace,
+        crate::linear_spaces::CieXYZ: LinearConvertFromRaw<Space>,
+    {
+        let luminance = |mut rgb: Vec3| {
+            <crate::linear_spaces::CieXYZ as LinearConvertFromRaw<Space>>::linear_part_raw(
+                &mut rgb,
+            );
+            rgb.y
+        };
+        LumaCoefficients::Custom(Vec3::new(
+            luminance(Vec3::X),
+            luminance(Vec3::Y),
+            luminance(Vec3::Z),
+        ))
+    }
+
+    /// The red, green and blue weights, which sum to `1.0`.
+    #[inline]
+    pub const fn weights(self) -> Vec3 {
+        match self {
+            LumaCoefficients::Rec601 => Vec3::new(0.299, 0.587, 0.114),
+            LumaCoefficients::Rec709 => Vec3::new(0.2126, 0.7152, 0.0722),
+            LumaCoefficients::Rec2020 => Vec3::new(0.2627, 0.678, 0.0593),
+            LumaCoefficients::Custom(weights) => weights,
+        }
+    }
+}
+
+/// Implemented by [`SceneReferred`] [`WorkingEncoding`]s whose color components are linear in light, so that scaling
+/// them scales the amount of light the color represents. This unlocks the exposure and photometric helpers in
+/// [`crate::exposure`].
+///
+/// Display-referred linear encodings like [`LinearSrgb`][crate::details::encodings::LinearSrgb] don't implement it,
+/// since exposing them could produce values brighter than their display can show, which would then be silently
+/// clipped when converted to an encoding like [`SrgbU8`][crate::details::encodings::SrgbU8]. Do lighting in
+/// [`SceneLinearSrgb`][crate::details::encodings::SceneLinearSrgb] instead, and tonemap the result.
+///
+/// ```compile_fail,E0599
+/// # use colstodian::Color;
+/// let lit = Color::linear_srgb(0.5, 0.25, 0.1).expose(2.0);
+/// ```
+pub trait LinearEncoding: WorkingEncoding + LinearLight + SceneReferred {
+    /// Scales the color components of `repr` by `factor`, leaving any alpha component unchanged.
+    fn scale_light(repr: Self::Repr, factor: f32) -> Self::Repr;
+}
+
+/// The kind of values a [`Referred`] color encoding or a [`TransferFunction`] holds: either [`DisplayReferral`] or
+/// [`SceneReferral`].
+pub trait Referral: AcceptsReferral<DisplayReferral> + 'static {}
+
+/// Implemented by a [`Referral`] for each kind of referral whose colors can be converted into it.
+pub trait AcceptsReferral<Src> {}
+
+/// The [`Referral`] of [`DisplayReferred`] encodings.
+pub enum DisplayReferral {}
+
+/// The [`Referral`] of [`SceneReferred`] encodings.
+pub enum SceneReferral {}
+
+impl Referral for DisplayReferral {}
+impl Referral for SceneReferral {}
+
+impl AcceptsReferral<DisplayReferral> for DisplayReferral {}
+impl<Src: Referral> AcceptsReferral<Src> for SceneReferral {}
+
+/// Declares whether a color encoding is [display-referred][DisplayReferred] or [scene-referred][SceneReferred].
+///
+/// Every encoding which takes part in conversions must implement this, as [`ConvertFrom`] can only be implemented
+/// where [`ReferralFrom`] allows it.
+pub trait Referred: ColorEncoding {
+    /// Either [`DisplayReferral`] or [`SceneReferral`].
+    type Referral: Referral;
+}
+
+/// A color encoding which is *scene-referred*: its values are proportional to light in the scene being rendered,
+/// with no upper bound, as produced by lighting calculations or HDR captures.
+///
+/// Scene-referred colors can't be [`convert`][Color::convert]ed to [`DisplayReferred`] encodings, since doing so
+/// would silently clip anything brighter than the display's white; this is enforced by [`ReferralFrom`]. Instead,
+/// reach a display-referred encoding explicitly, through a [`Tonemapper`][crate::tonemap::Tonemapper] or by
+/// clamping (for example with [`Color::clamp_to_display`]). Converting from a display-referred encoding to a
+/// scene-referred one is allowed, as it loses nothing.
+///
+/// This is implemented for every encoding whose [`Referred::Referral`] is [`SceneReferral`].
+pub trait SceneReferred: Referred<Referral = SceneReferral> {}
+
+impl<E: Referred<Referral = SceneReferral>> SceneReferred for E {}
+
+/// A color encoding which is *display-referred*: its values are relative to the white of the display it will be
+/// shown on, and only the `0.0..=1.0` range is meaningful.
+///
+/// See [`SceneReferred`] for how the two kinds of encodings interact. This is implemented for every encoding whose
+/// [`Referred::Referral`] is [`DisplayReferral`].
+pub trait DisplayReferred: Referred<Referral = DisplayReferral> {}
+
+impl<E: Referred<Referral = DisplayReferral>> DisplayReferred for E {}
+
+/// Implemented when the referrals of `Self` and `SrcEnc` allow converting colors from `SrcEnc` to `Self`: any
+/// [`Referred`] encoding can be converted to a [`SceneReferred`] one, but only [`DisplayReferred`] encodings can be
+/// converted to [`DisplayReferred`] ones.
+///
+/// This is required by [`ConvertFrom`], so a conversion which would clip scene-referred values can't be declared.
+///
+/// ```compile_fail,E0277
+/// use colstodian::color_encoding;
+/// use colstodian::details::encodings::SceneLinearSrgb;
+/// use colstodian::details::linear_spaces::Srgb;
+/// use colstodian::details::reprs::F32Repr;
+/// use colstodian::details::traits::ConvertFrom;
+///
+/// color_encoding! {
+///     struct ClampedSrgb {
+///         repr: F32Repr,
+///         components: struct ClampedSrgbComponents { r, g, b },
+///         linear_space: Srgb,
+///         referred: display,
+///         src_transform: |repr| (repr, 1.0),
+///         dst_transform: |raw, _| raw.clamp(glam::Vec3::ZERO, glam::Vec3::ONE),
+///     }
+/// }
+///
+/// // a display-referred encoding can't be converted to from a scene-referred one
+/// impl ConvertFrom<SceneLinearSrgb> for ClampedSrgb {}
+/// ```
+pub trait ReferralFrom<SrcEnc> {}
+
+impl<Dst, Src> ReferralFrom<Src> for Dst
+where
+    Dst: Referred,
+    Src: Referred,
+    Dst::Referral: AcceptsReferral<Src::Referral>,
+{
+}
+
+/// A transfer function, which maps between linear light values and the non-linear values actually stored by an
+/// encoding, usually in order to spend more precision on darker values, where the eye is more sensitive.
+///
+/// Transfer functions operate on each of the three color components independently. Alpha is never passed
+/// through them. Along with a [`LinearColorSpace`], a repr and an [`AlphaMode`], a transfer function makes up an
+/// [`Encoded`][crate::details::encodings::Encoded] color encoding.
+///
+/// You can see all the built-in transfer functions in [`crate::details::transfer_functions`].
+pub trait TransferFunction: 'static {
+    /// Used in `Debug` implementations.
+    const NAME: &'static str;
+
+    /// Whether the linear values of this transfer function are display- or scene-referred, which decides the
+    /// [`Referred::Referral`] of the [`Encoded`][crate::details::encodings::Encoded] encodings using it.
+    type Referral: Referral;
+
+    /// Converts encoded values to linear values (for display-referred transfer functions, this is the EOTF).
+    fn decode(encoded: Vec3) -> Vec3;
+
+    /// Converts linear values to encoded values. This is the inverse of [`decode`][TransferFunction::decode].
+    fn encode(linear: Vec3) -> Vec3;
+
+    /// Clamps encoded values to the range this transfer function considers valid, for
+    /// [`Saturate`] on [`Encoded`][crate::details::encodings::Encoded] encodings.
+    ///
+    /// By default this is `0.0..=1.0`, which suits display-referred transfer functions. Scene-referred ones
+    /// should instead only clamp values which decode to negative linear values.
+    #[inline]
+    fn saturate(encoded: Vec3) -> Vec3 {
+        encoded.clamp(Vec3::ZERO, Vec3::ONE)
+    }
+
+    /// The smallest linear value a display-referred transfer function can encode, which bounds the
+    /// [`DisplayGamut`] of the [`Encoded`][crate::details::encodings::Encoded] encodings using it. By default this is
+    /// `0.0`.
+    const LINEAR_MIN: f32 = 0.0;
+
+    /// The largest linear value a display-referred transfer function can encode. By default this is `1.0`.
+    const LINEAR_MAX: f32 = 1.0;
+}
+
+/// Implemented by [`DisplayReferred`] encodings whose gamut is known, as the range of values each component may take
+/// in their [`LinearColorSpace`]. This unlocks [`Color::is_in_gamut`] and [`Color::gamut_map`].
+///
+/// RGB encodings have a range of `0.0..=1.0`, unless they are extended-range like
+/// [`ScRgbF16`][crate::details::encodings::ScRgbF16] or meant for HDR like [`Pq`][crate::details::transfer_functions::Pq].
+/// Encodings based on CIE XYZ, like [`CieXyz`][crate::details::encodings::CieXyz] and
+/// [`Oklab`][crate::details::encodings::Oklab], only require their linear values to be non-negative.
+pub trait DisplayGamut: DisplayReferred {
+    /// The smallest value a linear component may take, which may be `f32::NEG_INFINITY`.
+    const LINEAR_MIN: f32;
+
+    /// The largest value a linear component may take, which may be `f32::INFINITY`.
+    const LINEAR_MAX: f32;
+}
+
+/// A [`TransferFunction`] whose linear values are display-referred, making the
+/// [`Encoded`][crate::details::encodings::Encoded] encodings using it [`DisplayReferred`].
+///
+/// This is implemented for every transfer function whose [`TransferFunction::Referral`] is [`DisplayReferral`].
+pub trait DisplayTransferFunction: TransferFunction<Referral = DisplayReferral> {}
+
+impl<Tf: TransferFunction<Referral = DisplayReferral>> DisplayTransferFunction for Tf {}
+
+/// A [`TransferFunction`] whose linear values are scene-referred, making the
+/// [`Encoded`][crate::details::encodings::Encoded] encodings using it [`SceneReferred`].
+///
+/// This is implemented for every transfer function whose [`TransferFunction::Referral`] is [`SceneReferral`].
+pub trait SceneTransferFunction: TransferFunction<Referral = SceneReferral> {}
+
+impl<Tf: TransferFunction<Referral = SceneReferral>> SceneTransferFunction for Tf {}
+
+/// Selects how an [`Encoded`][crate::details::encodings::Encoded] color encoding stores alpha, if at all.
+///
+/// The built-in alpha modes are [`NoAlpha`][crate::details::encodings::NoAlpha],
+/// [`SeparateAlpha`][crate::details::encodings::SeparateAlpha] and
+/// [`PremultipliedAlpha`][crate::details::encodings::PremultipliedAlpha].
+pub trait AlphaMode: 'static {
+    /// Converts linear color components with separate alpha to the form they are stored in.
+    fn premultiply(linear: Vec3, alpha: f32) -> Vec3;
+
+    /// The inverse of [`premultiply`][AlphaMode::premultiply].
+    fn separate(linear: Vec3, alpha: f32) -> Vec3;
+}
+
+/// Implemented by the [`ColorRepr`]s an [`Encoded`][crate::details::encodings::Encoded] color encoding can be
+/// stored in with a given [`AlphaMode`].
+pub trait EncodedRepr<Alpha: AlphaMode>: ColorRepr {
+    /// The component struct used by encodings stored in this repr.
+    type ComponentStruct: ComponentStructFor<Self>;
+
+    /// Splits `self` into its three color components and its alpha component (`1.0` if there is none), with
+    /// integer components normalized to `0.0..=1.0`.
+    fn split(self) -> (Vec3, f32);
+
+    /// The inverse of [`split`][EncodedRepr::split].
+    fn join(components: Vec3, alpha: f32) -> Self;
+
+    /// Clamps `self` to the range of values valid for transfer function `Tf`, and its alpha component (if any) to
+    /// `0.0..=1.0`. Integer reprs can't hold values outside of `0.0..=1.0`, so for them this does nothing.
+    #[inline]
+    fn saturate<Tf: TransferFunction>(self) -> Self {
+        let (components, alpha) = self.split();
+        Self::join(Tf::saturate(components), alpha.clamp(0.0, 1.0))
+    }
+}
+
 /// A type that implements [`LinearColorSpace`] represents a color space which can be defined by a *linear transformation only*
 /// (i.e. a 3x3 matrix multiplication) from the CIE XYZ color space.
 ///
 /// A linear color space is defined by the combination of a set of [Primaries][RGBPrimaries] and a [White Point][WhitePoint].
 ///
-/// You can see all the built-in linear spaces in [`crate::details::linear_spaces`]
+/// You can see all the built-in linear spaces in [`crate::details::linear_spaces`]. Spaces with other chromaticities
+/// can be declared with [`linear_color_space!`][crate::linear_color_space!].
 pub trait LinearColorSpace {
     const PRIMARIES: RGBPrimaries;
     const WHITE_POINT: WhitePoint;
+
+    /// Used in the `Debug` and `Display` implementations of [`Encoded`][crate::details::encodings::Encoded]
+    /// encodings.
+    const NAME: &'static str = "Custom";
 }
 
 /// A trait that marks `Self` as being a color encoding which is able to be directly converted from `SrcEnc`,
@@ -126,10 +531,13 @@ pub trait LinearColorSpace {
 ///
 /// If this trait is not implemented for a pair of encodings, then a direct conversion without input or choice from the user
 /// is not possible, and a conversion between the encodings will need to be performed manually or in more than one step.
+///
+/// It can only be implemented where [`ReferralFrom`] allows it, so a [`SceneReferred`] encoding can never be converted
+/// directly to a [`DisplayReferred`] one.
 pub trait ConvertFrom<SrcEnc>
 where
     SrcEnc: ColorEncoding,
-    Self: ColorEncoding,
+    Self: ColorEncoding + ReferralFrom<SrcEnc>,
     Self::LinearSpace: LinearConvertFromRaw<SrcEnc::LinearSpace>,
 {
     /// If required or desired, perform a mapping of some kind to the input
@@ -137,17 +545,46 @@ where
     /// gamut mapping if the src encoding has a larger size of representable colors than the dst encoding.
     #[inline(always)]
     fn map_src(_src: &mut SrcEnc::Repr) {}
+
+    /// Performs the full conversion from `SrcEnc::Repr` to `Self::Repr`.
+    ///
+    /// The default implementation applies [`map_src`][ConvertFrom::map_src], then `SrcEnc`'s source transform,
+    /// the linear part and `Self`'s destination transform in `f32`. Conversions between two
+    /// [`ColorEncodingF64`]s override this to stay in `f64`.
+    #[inline]
+    fn convert_raw(mut src: SrcEnc::Repr) -> Self::Repr {
+        Self::map_src(&mut src);
+        let (mut raw, alpha) = SrcEnc::src_transform_raw(src);
+        <Self::LinearSpace as LinearConvertFromRaw<SrcEnc::LinearSpace>>::linear_part_raw(&mut raw);
+        Self::dst_transform_raw(raw, alpha)
+    }
 }
 
 impl<E> ConvertFrom<E> for E
 where
-    E: ColorEncoding,
+    E: ColorEncoding + ReferralFrom<E>,
     E::LinearSpace: LinearConvertFromRaw<E::LinearSpace>,
 {
+    #[inline(always)]
+    fn convert_raw(src: E::Repr) -> E::Repr {
+        src
+    }
 }
 
 /// Performs the raw conversion from the [`LinearColorSpace`] represented by `SrcSpc` to
 /// the [`LinearColorSpace`] represented by `Self`.
 pub trait LinearConvertFromRaw<SrcSpace: LinearColorSpace>: LinearColorSpace {
     fn linear_part_raw(raw: &mut Vec3);
+
+    /// The `f64` version of [`linear_part_raw`][LinearConvertFromRaw::linear_part_raw], used when converting
+    /// between [`ColorEncodingF64`]s.
+    ///
+    /// The default implementation goes through `linear_part_raw` in `f32`, so it should be overridden
+    /// to keep full precision.
+    #[inline(always)]
+    fn linear_part_raw_f64(raw: &mut DVec3) {
+        let mut narrow = raw.as_vec3();
+        Self::linear_part_raw(&mut narrow);
+        *raw = narrow.as_dvec3();
+    }
 }
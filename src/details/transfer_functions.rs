@@ -0,0 +1,693 @@
+use crate::traits::*;
+
+use glam::Vec3;
+use kolor::details::color::WhitePoint;
+use kolor::details::transform;
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use num_traits::float::Float;
+
+#[inline(always)]
+fn per_channel(color: Vec3, f: impl Fn(f32) -> f32) -> Vec3 {
+    Vec3::new(f(color.x), f(color.y), f(color.z))
+}
+
+/// The identity transfer function, for encodings which store linear values directly.
+pub struct Linear;
+
+impl TransferFunction for Linear {
+    const NAME: &'static str = "Linear";
+
+    type Referral = DisplayReferral;
+
+    #[inline(always)]
+    fn decode(encoded: Vec3) -> Vec3 {
+        encoded
+    }
+
+    #[inline(always)]
+    fn encode(linear: Vec3) -> Vec3 {
+        linear
+    }
+}
+
+/// The identity transfer function, for extended-range encodings such as
+/// [`ScRgbF16`][crate::details::encodings::ScRgbF16] whose linear values aren't limited to `0.0..=1.0`.
+///
+/// This differs from [`Linear`] only in that [`saturate`][TransferFunction::saturate] leaves values untouched.
+pub struct ExtendedLinear;
+
+impl TransferFunction for ExtendedLinear {
+    const NAME: &'static str = "ExtendedLinear";
+
+    const LINEAR_MIN: f32 = f32::NEG_INFINITY;
+
+    const LINEAR_MAX: f32 = f32::INFINITY;
+
+    type Referral = DisplayReferral;
+
+    #[inline(always)]
+    fn decode(encoded: Vec3) -> Vec3 {
+        encoded
+    }
+
+    #[inline(always)]
+    fn encode(linear: Vec3) -> Vec3 {
+        linear
+    }
+
+    #[inline(always)]
+    fn saturate(encoded: Vec3) -> Vec3 {
+        encoded
+    }
+}
+
+/// The piecewise sRGB transfer function, as used by the sRGB encodings in [`crate::details::encodings`].
+pub struct Srgb;
+
+impl TransferFunction for Srgb {
+    const NAME: &'static str = "Srgb";
+
+    type Referral = DisplayReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        transform::sRGB_eotf(encoded, WhitePoint::D65)
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        transform::sRGB_oetf(linear, WhitePoint::D65)
+    }
+}
+
+/// The sRGB transfer function extended to all real numbers, as used by extended-range sRGB encodings.
+///
+/// Negative values are mirrored around zero (`encode(-x) == -encode(x)`), and values above `1.0` follow the curve
+/// rather than being clamped, so colors outside of the sRGB gamut and brighter than its white survive a round trip.
+pub struct ExtendedSrgb;
+
+impl ExtendedSrgb {
+    #[inline]
+    fn decode_channel(encoded: f32) -> f32 {
+        let x = encoded.abs();
+        let linear = if x <= 0.04045 {
+            x / 12.92
+        } else {
+            ((x + 0.055) / 1.055).powf(2.4)
+        };
+        linear.copysign(encoded)
+    }
+
+    #[inline]
+    fn encode_channel(linear: f32) -> f32 {
+        let x = linear.abs();
+        let encoded = if x <= 0.0031308 {
+            x * 12.92
+        } else {
+            1.055 * x.powf(1.0 / 2.4) - 0.055
+        };
+        encoded.copysign(linear)
+    }
+}
+
+impl TransferFunction for ExtendedSrgb {
+    const NAME: &'static str = "ExtendedSrgb";
+
+    const LINEAR_MIN: f32 = f32::NEG_INFINITY;
+
+    const LINEAR_MAX: f32 = f32::INFINITY;
+
+    type Referral = DisplayReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        per_channel(encoded, Self::decode_channel)
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        per_channel(linear, Self::encode_channel)
+    }
+
+    #[inline(always)]
+    fn saturate(encoded: Vec3) -> Vec3 {
+        encoded
+    }
+}
+
+/// A pure power-law gamma of 2.2, as commonly used by PC displays which approximate sRGB.
+///
+/// Negative values are clamped to `0.0`.
+pub struct Gamma22;
+
+impl TransferFunction for Gamma22 {
+    const NAME: &'static str = "Gamma22";
+
+    type Referral = DisplayReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        encoded.max(Vec3::ZERO).powf(2.2)
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        linear.max(Vec3::ZERO).powf(1.0 / 2.2)
+    }
+}
+
+/// A pure power-law gamma of 2.4.
+///
+/// Negative values are clamped to `0.0`.
+pub struct Gamma24;
+
+impl TransferFunction for Gamma24 {
+    const NAME: &'static str = "Gamma24";
+
+    type Referral = DisplayReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        encoded.max(Vec3::ZERO).powf(2.4)
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        linear.max(Vec3::ZERO).powf(1.0 / 2.4)
+    }
+}
+
+/// The ITU-R BT.1886 reference EOTF of broadcast (Rec. 709) displays, for a display with a black luminance of
+/// `BLACK_MILLINITS` and a white luminance of `WHITE_MILLINITS`, both in thousandths of a cd/m².
+///
+/// Linear values are relative to the display's white, so encoded `0.0` decodes to the display's black level
+/// (`BLACK_MILLINITS / WHITE_MILLINITS`), and linear values darker than that encode to below `0.0`. With the
+/// default black level of zero, this is a pure power-law gamma of 2.4. Negative values are clamped to `0.0`.
+///
+/// ```
+/// use colstodian::details::traits::TransferFunction;
+/// use colstodian::details::transfer_functions::Bt1886;
+/// # use glam::Vec3;
+///
+/// // a monitor with a 0.1 cd/m² black and a 100 cd/m² white
+/// type Monitor = Bt1886<100, 100_000>;
+///
+/// assert!(Monitor::decode(Vec3::ZERO).abs_diff_eq(Vec3::splat(0.001), 1e-7));
+/// assert!(Monitor::decode(Vec3::ONE).abs_diff_eq(Vec3::ONE, 1e-6));
+/// ```
+///
+/// The black level must be below the white level (which so can't be zero), or using the transfer function fails
+/// to compile:
+///
+/// ```compile_fail,E0080
+/// # use colstodian::details::traits::TransferFunction;
+/// # use colstodian::details::transfer_functions::Bt1886;
+/// # use glam::Vec3;
+/// let inverted = Bt1886::<100_000, 100>::decode(Vec3::splat(0.5));
+/// ```
+pub struct Bt1886<const BLACK_MILLINITS: u32 = 0, const WHITE_MILLINITS: u32 = 100_000>;
+
+impl<const BLACK_MILLINITS: u32, const WHITE_MILLINITS: u32>
+    Bt1886<BLACK_MILLINITS, WHITE_MILLINITS>
+{
+    const GAMMA: f32 = 2.4;
+
+    /// The gain `a` and black lift `b` of the EOTF, normalized to a white luminance of `1.0`.
+    #[inline]
+    fn gain_and_lift() -> (f32, f32) {
+        const {
+            assert!(
+                BLACK_MILLINITS < WHITE_MILLINITS,
+                "the black level of `Bt1886` must be below its white level"
+            )
+        };
+        let black = (BLACK_MILLINITS as f32 / WHITE_MILLINITS as f32).powf(1.0 / Self::GAMMA);
+        let gain = (1.0 - black).powf(Self::GAMMA);
+        let lift = black / (1.0 - black);
+        (gain, lift)
+    }
+}
+
+impl<const BLACK_MILLINITS: u32, const WHITE_MILLINITS: u32> TransferFunction
+    for Bt1886<BLACK_MILLINITS, WHITE_MILLINITS>
+{
+    const NAME: &'static str = "Bt1886";
+
+    type Referral = DisplayReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        let (gain, lift) = Self::gain_and_lift();
+        gain * (encoded.max(Vec3::ZERO) + lift).powf(Self::GAMMA)
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        let (gain, lift) = Self::gain_and_lift();
+        (linear.max(Vec3::ZERO) / gain).powf(1.0 / Self::GAMMA) - lift
+    }
+}
+
+/// The transfer function of Adobe RGB (1998), a pure power-law gamma of `563 / 256` (about 2.2).
+///
+/// Negative values are clamped to `0.0`.
+pub struct AdobeRgb;
+
+impl AdobeRgb {
+    const GAMMA: f32 = 563.0 / 256.0;
+}
+
+impl TransferFunction for AdobeRgb {
+    const NAME: &'static str = "AdobeRgb";
+
+    type Referral = DisplayReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        encoded.max(Vec3::ZERO).powf(Self::GAMMA)
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        linear.max(Vec3::ZERO).powf(1.0 / Self::GAMMA)
+    }
+}
+
+/// The transfer function of ProPhoto RGB (ROMM RGB), a gamma of 1.8 with a short linear segment near black.
+pub struct ProPhotoRgb;
+
+impl TransferFunction for ProPhotoRgb {
+    const NAME: &'static str = "ProPhotoRgb";
+
+    type Referral = DisplayReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        per_channel(encoded, |e| {
+            if e < 16.0 / 512.0 {
+                e / 16.0
+            } else {
+                e.powf(1.8)
+            }
+        })
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        per_channel(linear, |l| {
+            if l < 1.0 / 512.0 {
+                l * 16.0
+            } else {
+                l.powf(1.0 / 1.8)
+            }
+        })
+    }
+}
+
+/// The luminance in cd/m² which a linear value of `1.0` represents for the [`Pq`] transfer function.
+pub const PQ_REFERENCE_WHITE: f32 = 100.0;
+
+/// The SMPTE ST 2084 "Perceptual Quantizer" transfer function used for HDR by ITU-R BT.2100.
+///
+/// PQ encodes absolute luminance up to 10000 cd/m², so linear values are taken to be relative to a white of
+/// [`PQ_REFERENCE_WHITE`] cd/m². Negative values are clamped to `0.0`.
+pub struct Pq;
+
+impl TransferFunction for Pq {
+    const NAME: &'static str = "Pq";
+
+    // PQ reaches 10000 cd/m²
+    const LINEAR_MAX: f32 = 10_000.0 / PQ_REFERENCE_WHITE;
+
+    type Referral = DisplayReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        transform::ST_2084_PQ_eotf(encoded.max(Vec3::ZERO), WhitePoint::D65) / PQ_REFERENCE_WHITE
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        transform::ST_2084_PQ_eotf_inverse(
+            linear.max(Vec3::ZERO) * PQ_REFERENCE_WHITE,
+            WhitePoint::D65,
+        )
+    }
+}
+
+const HLG_A: f32 = 0.17883277;
+const HLG_B: f32 = 0.28466892;
+const HLG_C: f32 = 0.5599107;
+
+/// The Hybrid Log-Gamma transfer function used for HDR by ITU-R BT.2100.
+///
+/// HLG is scene-referred: linear values are relative scene light, where `1.0` is the brightest value that can be
+/// encoded. Negative values are clamped to `0.0`.
+pub struct Hlg;
+
+impl TransferFunction for Hlg {
+    const NAME: &'static str = "Hlg";
+
+    type Referral = SceneReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        per_channel(encoded, |e| {
+            let e = e.max(0.0);
+            if e <= 0.5 {
+                e * e / 3.0
+            } else {
+                (((e - HLG_C) / HLG_A).exp() + HLG_B) / 12.0
+            }
+        })
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        per_channel(linear, |l| {
+            let l = l.max(0.0);
+            if l <= 1.0 / 12.0 {
+                (3.0 * l).sqrt()
+            } else {
+                HLG_A * (12.0 * l - HLG_B).ln() + HLG_C
+            }
+        })
+    }
+
+    #[inline]
+    fn saturate(encoded: Vec3) -> Vec3 {
+        encoded.max(Self::encode(Vec3::ZERO))
+    }
+}
+
+const ACES_CCT_LINEAR_BREAK: f32 = 0.0078125;
+const ACES_CCT_ENCODED_BREAK: f32 = 0.15525114;
+const ACES_CCT_SLOPE: f32 = 10.540237;
+const ACES_CCT_OFFSET: f32 = 0.07290553;
+const ACES_CCT_MAX: f32 = 65504.0;
+
+/// The ACEScct logarithmic transfer function, used with the [`AcesCg`][crate::details::linear_spaces::AcesCg]
+/// primaries for color grading.
+///
+/// Unlike ACEScc, it has a linear toe near black, which gives a response more like traditional film log
+/// encodings. Decoded values are limited to the maximum of a half float, `65504.0`.
+pub struct AcesCct;
+
+impl TransferFunction for AcesCct {
+    const NAME: &'static str = "AcesCct";
+
+    type Referral = SceneReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        per_channel(encoded, |e| {
+            if e <= ACES_CCT_ENCODED_BREAK {
+                (e - ACES_CCT_OFFSET) / ACES_CCT_SLOPE
+            } else {
+                (e * 17.52 - 9.72).exp2().min(ACES_CCT_MAX)
+            }
+        })
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        per_channel(linear, |l| {
+            if l <= ACES_CCT_LINEAR_BREAK {
+                ACES_CCT_SLOPE * l + ACES_CCT_OFFSET
+            } else {
+                (l.log2() + 9.72) / 17.52
+            }
+        })
+    }
+
+    #[inline]
+    fn saturate(encoded: Vec3) -> Vec3 {
+        encoded.max(Self::encode(Vec3::ZERO))
+    }
+}
+
+/// The linear value which the [`Log`] transfer function centers its range of stops on.
+pub const LOG_MIDDLE_GREY: f32 = 0.18;
+
+/// A pure logarithmic transfer function, which maps the range from `MIN_STOPS` to `MAX_STOPS` stops
+/// around [`LOG_MIDDLE_GREY`] evenly onto `0.0..=1.0`. This is commonly used as a shaper for sampling scene-referred
+/// values from a lookup table.
+///
+/// The default range of `-10` to `+6` stops covers values from about `0.0002` to `11.5`. Values darker than
+/// the bottom of the range are encoded as `0.0`.
+pub struct Log<const MIN_STOPS: i32 = -10, const MAX_STOPS: i32 = 6>;
+
+impl<const MIN_STOPS: i32, const MAX_STOPS: i32> TransferFunction for Log<MIN_STOPS, MAX_STOPS> {
+    const NAME: &'static str = "Log";
+
+    type Referral = SceneReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        let range = (MAX_STOPS - MIN_STOPS) as f32;
+        per_channel(encoded, |e| {
+            LOG_MIDDLE_GREY * (e * range + MIN_STOPS as f32).exp2()
+        })
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        let range = (MAX_STOPS - MIN_STOPS) as f32;
+        let darkest = LOG_MIDDLE_GREY * (MIN_STOPS as f32).exp2();
+        per_channel(linear, |l| {
+            ((l.max(darkest) / LOG_MIDDLE_GREY).log2() - MIN_STOPS as f32) / range
+        })
+    }
+
+    #[inline]
+    fn saturate(encoded: Vec3) -> Vec3 {
+        encoded.max(Self::encode(Vec3::ZERO))
+    }
+}
+
+const LOGC3_CUT: f32 = 0.010591;
+const LOGC3_A: f32 = 5.555556;
+const LOGC3_B: f32 = 0.052272;
+const LOGC3_C: f32 = 0.24719;
+const LOGC3_D: f32 = 0.385537;
+const LOGC3_E: f32 = 5.367655;
+const LOGC3_F: f32 = 0.092809;
+
+/// ARRI's LogC3 camera log curve, for an exposure index of 800, used with
+/// [`ArriWideGamut3`][crate::details::linear_spaces::ArriWideGamut3].
+///
+/// Like the other camera log curves, linear values are scene-referred, with `0.18` being middle grey.
+pub struct LogC3;
+
+impl TransferFunction for LogC3 {
+    const NAME: &'static str = "LogC3";
+
+    type Referral = SceneReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        per_channel(encoded, |e| {
+            if e > LOGC3_E * LOGC3_CUT + LOGC3_F {
+                (10f32.powf((e - LOGC3_D) / LOGC3_C) - LOGC3_B) / LOGC3_A
+            } else {
+                (e - LOGC3_F) / LOGC3_E
+            }
+        })
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        per_channel(linear, |l| {
+            if l > LOGC3_CUT {
+                LOGC3_C * (LOGC3_A * l + LOGC3_B).log10() + LOGC3_D
+            } else {
+                LOGC3_E * l + LOGC3_F
+            }
+        })
+    }
+
+    #[inline]
+    fn saturate(encoded: Vec3) -> Vec3 {
+        encoded.max(Self::encode(Vec3::ZERO))
+    }
+}
+
+const LOGC4_A: f32 = (262144.0 - 16.0) / 117.45;
+const LOGC4_B: f32 = (1023.0 - 95.0) / 1023.0;
+const LOGC4_C: f32 = 95.0 / 1023.0;
+const LOGC4_S: f32 = 0.11359721;
+const LOGC4_T: f32 = -0.018056996;
+
+/// ARRI's LogC4 camera log curve, used with [`ArriWideGamut4`][crate::details::linear_spaces::ArriWideGamut4].
+pub struct LogC4;
+
+impl TransferFunction for LogC4 {
+    const NAME: &'static str = "LogC4";
+
+    type Referral = SceneReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        per_channel(encoded, |e| {
+            if e >= 0.0 {
+                ((14.0 * (e - LOGC4_C) / LOGC4_B + 6.0).exp2() - 64.0) / LOGC4_A
+            } else {
+                e * LOGC4_S + LOGC4_T
+            }
+        })
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        per_channel(linear, |l| {
+            if l >= LOGC4_T {
+                ((LOGC4_A * l + 64.0).log2() - 6.0) / 14.0 * LOGC4_B + LOGC4_C
+            } else {
+                (l - LOGC4_T) / LOGC4_S
+            }
+        })
+    }
+
+    #[inline]
+    fn saturate(encoded: Vec3) -> Vec3 {
+        encoded.max(Self::encode(Vec3::ZERO))
+    }
+}
+
+const SLOG3_CUT: f32 = 0.01125;
+const SLOG3_CUT_CODE: f32 = 171.2103;
+
+/// Sony's S-Log3 camera log curve, used with [`SGamut3`][crate::details::linear_spaces::SGamut3] and
+/// [`SGamut3Cine`][crate::details::linear_spaces::SGamut3Cine].
+pub struct SLog3;
+
+impl TransferFunction for SLog3 {
+    const NAME: &'static str = "SLog3";
+
+    type Referral = SceneReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        per_channel(encoded, |e| {
+            let code = e * 1023.0;
+            if code >= SLOG3_CUT_CODE {
+                10f32.powf((code - 420.0) / 261.5) * 0.19 - 0.01
+            } else {
+                (code - 95.0) * SLOG3_CUT / (SLOG3_CUT_CODE - 95.0)
+            }
+        })
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        per_channel(linear, |l| {
+            let code = if l >= SLOG3_CUT {
+                420.0 + ((l + 0.01) / 0.19).log10() * 261.5
+            } else {
+                l * (SLOG3_CUT_CODE - 95.0) / SLOG3_CUT + 95.0
+            };
+            code / 1023.0
+        })
+    }
+
+    #[inline]
+    fn saturate(encoded: Vec3) -> Vec3 {
+        encoded.max(Self::encode(Vec3::ZERO))
+    }
+}
+
+const CANON_LOG_SCALE: f32 = 0.529136;
+const CANON_LOG_OFFSET: f32 = 0.0730597;
+const CANON_LOG_SLOPE: f32 = 10.1596;
+
+/// Canon's original Canon Log camera log curve, used with
+/// [`CanonCinemaGamut`][crate::details::linear_spaces::CanonCinemaGamut].
+///
+/// Encoded values are 10-bit code values normalized to `0.0..=1.0`, so the curve's output (which is specified in
+/// the legal `64..=940` range) is mapped accordingly, as with the other camera log curves.
+pub struct CanonLog;
+
+impl CanonLog {
+    /// Canon Log is specified in terms of scene reflectance, which is `0.9` for linear `1.0`.
+    const REFLECTANCE: f32 = 0.9;
+}
+
+impl TransferFunction for CanonLog {
+    const NAME: &'static str = "CanonLog";
+
+    type Referral = SceneReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        per_channel(encoded, |e| {
+            let clog = (e * 1023.0 - 64.0) / 876.0;
+            let reflectance = if clog >= CANON_LOG_OFFSET {
+                (10f32.powf((clog - CANON_LOG_OFFSET) / CANON_LOG_SCALE) - 1.0) / CANON_LOG_SLOPE
+            } else {
+                -(10f32.powf((CANON_LOG_OFFSET - clog) / CANON_LOG_SCALE) - 1.0) / CANON_LOG_SLOPE
+            };
+            reflectance * Self::REFLECTANCE
+        })
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        per_channel(linear, |l| {
+            let reflectance = l / Self::REFLECTANCE;
+            let clog = if reflectance >= 0.0 {
+                CANON_LOG_SCALE * (CANON_LOG_SLOPE * reflectance + 1.0).log10() + CANON_LOG_OFFSET
+            } else {
+                -CANON_LOG_SCALE * (1.0 - CANON_LOG_SLOPE * reflectance).log10() + CANON_LOG_OFFSET
+            };
+            (clog * 876.0 + 64.0) / 1023.0
+        })
+    }
+
+    #[inline]
+    fn saturate(encoded: Vec3) -> Vec3 {
+        encoded.max(Self::encode(Vec3::ZERO))
+    }
+}
+
+const LOG3G10_A: f32 = 0.224282;
+const LOG3G10_B: f32 = 155.97533;
+const LOG3G10_C: f32 = 0.01;
+const LOG3G10_G: f32 = 15.1927;
+
+/// RED's Log3G10 camera log curve, used with [`RedWideGamutRgb`][crate::details::linear_spaces::RedWideGamutRgb].
+pub struct Log3G10;
+
+impl TransferFunction for Log3G10 {
+    const NAME: &'static str = "Log3G10";
+
+    type Referral = SceneReferral;
+
+    #[inline]
+    fn decode(encoded: Vec3) -> Vec3 {
+        per_channel(encoded, |e| {
+            if e < 0.0 {
+                e / LOG3G10_G - LOG3G10_C
+            } else {
+                (10f32.powf(e / LOG3G10_A) - 1.0) / LOG3G10_B - LOG3G10_C
+            }
+        })
+    }
+
+    #[inline]
+    fn encode(linear: Vec3) -> Vec3 {
+        per_channel(linear, |l| {
+            let l = l + LOG3G10_C;
+            if l < 0.0 {
+                l * LOG3G10_G
+            } else {
+                LOG3G10_A * (l * LOG3G10_B + 1.0).log10()
+            }
+        })
+    }
+
+    #[inline]
+    fn saturate(encoded: Vec3) -> Vec3 {
+        encoded.max(Self::encode(Vec3::ZERO))
+    }
+}
@@ -0,0 +1,153 @@
+//! Color difference (ΔE) metrics, which estimate how different two colors look to a human observer.
+//!
+//! Each metric accepts colors in any encoding that can be converted to the color space it is defined in:
+//! [`CieLab`] for the CIE formulas, [`Oklab`] for [`delta_e_ok`] and [`ICtCpPq`] for [`delta_e_itp`]. As a
+//! rough guide, a ΔE of `1.0` in the CIE formulas or ΔE ITP is around the smallest difference that is noticeable.
+//! ΔEOK is on a different scale, where a difference of about `0.02` is noticeable.
+//!
+//! ```
+//! use colstodian::{Color, difference::*};
+//!
+//! let before = Color::srgb_u8(200, 120, 40);
+//! let after = Color::srgb_u8(201, 120, 40);
+//!
+//! assert!(delta_e_2000(before, after) < 1.0);
+//! assert!(delta_e_2000(before, Color::srgb_u8(180, 120, 40)) > 2.0);
+//! ```
+
+use crate::encodings::{CieLab, ICtCpPq, Oklab};
+use crate::{Color, ColorInto};
+
+use core::f64::consts::PI;
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use num_traits::float::Float;
+
+/// The CIE 1976 color difference, the Euclidean distance between two colors in CIELAB.
+///
+/// This is simple and symmetric, but overstates differences in saturated colors. Prefer [`delta_e_2000`].
+#[inline]
+pub fn delta_e_76(a: impl ColorInto<Color<CieLab>>, b: impl ColorInto<Color<CieLab>>) -> f32 {
+    a.color_into().repr.distance(b.color_into().repr)
+}
+
+/// The CIE 1994 color difference between a `reference` color and a `sample`, with the weights for graphic arts
+/// (`kL = 1`, `K1 = 0.045`, `K2 = 0.015`).
+///
+/// Unlike the other metrics, this is not symmetric: the chroma of `reference` is used to weight the
+/// chroma and hue differences.
+pub fn delta_e_94(
+    reference: impl ColorInto<Color<CieLab>>,
+    sample: impl ColorInto<Color<CieLab>>,
+) -> f32 {
+    let reference = reference.color_into().repr.as_dvec3();
+    let sample = sample.color_into().repr.as_dvec3();
+
+    let c1 = reference.y.hypot(reference.z);
+    let c2 = sample.y.hypot(sample.z);
+    let delta_l = reference.x - sample.x;
+    let delta_c = c1 - c2;
+    let delta_a = reference.y - sample.y;
+    let delta_b = reference.z - sample.z;
+    let delta_h_squared = (delta_a * delta_a + delta_b * delta_b - delta_c * delta_c).max(0.0);
+
+    let s_c = 1.0 + 0.045 * c1;
+    let s_h = 1.0 + 0.015 * c1;
+
+    let c_term = delta_c / s_c;
+    (delta_l * delta_l + c_term * c_term + delta_h_squared / (s_h * s_h)).sqrt() as f32
+}
+
+/// The CIEDE2000 color difference, with the parametric weights `kL`, `kC` and `kH` all set to `1.0`.
+///
+/// This is the most accurate of the CIELAB-based formulas, and the usual choice for checking whether two colors
+/// match. It is computed in double precision internally.
+pub fn delta_e_2000(a: impl ColorInto<Color<CieLab>>, b: impl ColorInto<Color<CieLab>>) -> f32 {
+    let [l1, a1, b1] = a.color_into().repr.as_dvec3().to_array();
+    let [l2, a2, b2] = b.color_into().repr.as_dvec3().to_array();
+
+    let c_bar = (a1.hypot(b1) + a2.hypot(b2)) * 0.5;
+    let c_bar_7 = c_bar.powi(7);
+    let g = 0.5 * (1.0 - (c_bar_7 / (c_bar_7 + 25f64.powi(7))).sqrt());
+
+    let a1_prime = a1 * (1.0 + g);
+    let a2_prime = a2 * (1.0 + g);
+    let c1_prime = a1_prime.hypot(b1);
+    let c2_prime = a2_prime.hypot(b2);
+    let h1_prime = hue_degrees(b1, a1_prime);
+    let h2_prime = hue_degrees(b2, a2_prime);
+
+    let delta_l_prime = l2 - l1;
+    let delta_c_prime = c2_prime - c1_prime;
+    let chroma_product = c1_prime * c2_prime;
+    let delta_h_prime = if chroma_product == 0.0 {
+        0.0
+    } else {
+        let delta = h2_prime - h1_prime;
+        if delta > 180.0 {
+            delta - 360.0
+        } else if delta < -180.0 {
+            delta + 360.0
+        } else {
+            delta
+        }
+    };
+    let delta_big_h_prime = 2.0 * chroma_product.sqrt() * (delta_h_prime.to_radians() * 0.5).sin();
+
+    let l_bar_prime = (l1 + l2) * 0.5;
+    let c_bar_prime = (c1_prime + c2_prime) * 0.5;
+    let h_bar_prime = if chroma_product == 0.0 {
+        h1_prime + h2_prime
+    } else if (h1_prime - h2_prime).abs() <= 180.0 {
+        (h1_prime + h2_prime) * 0.5
+    } else if h1_prime + h2_prime < 360.0 {
+        (h1_prime + h2_prime + 360.0) * 0.5
+    } else {
+        (h1_prime + h2_prime - 360.0) * 0.5
+    };
+
+    let t = 1.0 - 0.17 * (h_bar_prime - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar_prime).to_radians().cos()
+        + 0.32 * (3.0 * h_bar_prime + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar_prime - 63.0).to_radians().cos();
+    let delta_theta = 30.0 * (-((h_bar_prime - 275.0) / 25.0).powi(2)).exp();
+    let c_bar_prime_7 = c_bar_prime.powi(7);
+    let r_c = 2.0 * (c_bar_prime_7 / (c_bar_prime_7 + 25f64.powi(7))).sqrt();
+    let l_offset = (l_bar_prime - 50.0).powi(2);
+    let s_l = 1.0 + 0.015 * l_offset / (20.0 + l_offset).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar_prime;
+    let s_h = 1.0 + 0.015 * c_bar_prime * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+    let l_term = delta_l_prime / s_l;
+    let c_term = delta_c_prime / s_c;
+    let h_term = delta_big_h_prime / s_h;
+    (l_term * l_term + c_term * c_term + h_term * h_term + r_t * c_term * h_term).sqrt() as f32
+}
+
+/// The hue angle of `(a, b)` in degrees, within `0.0..360.0`.
+#[inline]
+fn hue_degrees(b: f64, a: f64) -> f64 {
+    if a == 0.0 && b == 0.0 {
+        return 0.0;
+    }
+    let hue = b.atan2(a);
+    let hue = if hue < 0.0 { hue + 2.0 * PI } else { hue };
+    hue.to_degrees()
+}
+
+/// The Euclidean distance between two colors in Oklab, as used by CSS Color Level 4 for gamut mapping.
+#[inline]
+pub fn delta_e_ok(a: impl ColorInto<Color<Oklab>>, b: impl ColorInto<Color<Oklab>>) -> f32 {
+    a.color_into().repr.distance(b.color_into().repr)
+}
+
+/// The ΔE ITP color difference from ITU-R BT.2124, computed in ICtCp with the PQ transfer function.
+///
+/// This is designed for high dynamic range and wide color gamut content. See [`ICtCpPq`] for the absolute
+/// luminance the linear values are taken to represent.
+#[inline]
+pub fn delta_e_itp(a: impl ColorInto<Color<ICtCpPq>>, b: impl ColorInto<Color<ICtCpPq>>) -> f32 {
+    let delta = a.color_into().repr - b.color_into().repr;
+    720.0 * (delta.x * delta.x + 0.25 * delta.y * delta.y + delta.z * delta.z).sqrt()
+}
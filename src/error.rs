@@ -0,0 +1,100 @@
+//! Error types returned by fallible operations in this crate.
+
+use core::fmt;
+
+/// An error returned when parsing a hex color string fails.
+///
+/// See [`Color::srgba_hex`][crate::Color::srgba_hex] for the accepted formats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HexParseError {
+    /// The string (not counting a leading `#`) did not have a number of digits
+    /// that is valid for the target encoding.
+    InvalidLength {
+        /// The number of bytes found after the optional leading `#`.
+        len: usize,
+        /// Whether the target encoding accepts the `#rgba` and `#rrggbbaa` forms with alpha, and so 4 and 8
+        /// digits as well as 3 and 6.
+        alpha: bool,
+    },
+    /// The string contained a byte which is not an ASCII hex digit.
+    InvalidDigit {
+        /// The byte offset of the invalid digit in the original string.
+        index: usize,
+    },
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for HexParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { len, alpha } => {
+                let expected = if *alpha { "3, 4, 6 or 8" } else { "3 or 6" };
+                write!(
+                    f,
+                    "invalid hex color length: expected {} digits, found {}",
+                    expected, len
+                )
+            }
+            Self::InvalidDigit { index } => {
+                write!(f, "invalid hex digit at byte offset {}", index)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HexParseError {}
+
+/// An error returned when parsing a CSS color string fails.
+///
+/// See [`DynamicColor::parse_css`][crate::details::css::DynamicColor::parse_css] for the accepted syntax.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CssParseError {
+    /// A hex color (a string starting with `#`) was invalid.
+    Hex(HexParseError),
+    /// The string was a bare keyword which is not a known named color.
+    UnknownKeyword,
+    /// The string contained an unknown color function.
+    UnknownFunction,
+    /// A `color()` function referenced an unknown or unsupported color space.
+    UnknownColorSpace,
+    /// The string was not well-formed, for example because of a missing closing parenthesis, the
+    /// wrong number of components, or misplaced separators.
+    InvalidSyntax,
+    /// A component was not a valid number, percentage, angle or `none` for its position.
+    InvalidComponent {
+        /// The index of the component, where alpha has index `3`.
+        index: usize,
+    },
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for CssParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hex(e) => write!(f, "{}", e),
+            Self::UnknownKeyword => f.write_str("unknown color keyword"),
+            Self::UnknownFunction => f.write_str("unknown color function"),
+            Self::UnknownColorSpace => f.write_str("unknown color space in `color()` function"),
+            Self::InvalidSyntax => f.write_str("invalid color syntax"),
+            Self::InvalidComponent { index } => write!(f, "invalid value for component {}", index),
+        }
+    }
+}
+
+impl From<HexParseError> for CssParseError {
+    #[inline]
+    fn from(e: HexParseError) -> Self {
+        Self::Hex(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CssParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Hex(e) => Some(e),
+            _ => None,
+        }
+    }
+}
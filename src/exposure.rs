@@ -0,0 +1,117 @@
+//! Exposure values and photometric unit conversions, for lighting code working in a [`LinearEncoding`].
+//!
+//! Exposure values here are *EV100*, the exposure value at ISO 100, as used by physically based cameras.
+//! The camera helpers follow the conventions of the "Moving Frostbite to PBR" course notes: a reflected-light
+//! meter calibration constant of `12.5`, an incident-light meter constant of `250`, and saturation-based
+//! sensitivity for converting an EV100 into an exposure scale.
+//!
+//! ```
+//! use colstodian::{Color, exposure};
+//! # use colstodian::equals_eps::*;
+//! # use colstodian::*;
+//!
+//! // "Sunny 16": f/16, 1/100s, ISO 100
+//! let ev100 = exposure::ev100(16.0, 1.0 / 100.0, 100.0);
+//! assert_eq_eps!(ev100, 14.64386, 1e-4);
+//!
+//! // a surface lit by the sun, with radiance given in cd/m²
+//! let lit = Color::scene_linear_srgb(12000.0, 10000.0, 8000.0);
+//! let exposed = lit * exposure::exposure_from_ev100(ev100);
+//! assert!(exposed.luminance_nits(1.0) < 1.0);
+//!
+//! // or, one stop brighter
+//! let brighter = exposed.expose(1.0);
+//! assert_eq_eps!(brighter.luminance_nits(1.0), exposed.luminance_nits(1.0) * 2.0, 1e-5);
+//! ```
+
+use crate::linear_spaces;
+use crate::traits::{ColorEncoding, LinearConvertFromRaw, LinearEncoding};
+use crate::Color;
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use num_traits::float::Float;
+
+/// The luminous efficacy of monochromatic 555nm light, in lumens per watt. This is the factor between radiometric
+/// and photometric units.
+pub const LUMINOUS_EFFICACY: f32 = 683.0;
+
+/// The calibration constant of a reflected-light meter, `K`.
+pub const REFLECTED_METER_CALIBRATION: f32 = 12.5;
+
+/// The calibration constant of an incident-light meter, `C`.
+pub const INCIDENT_METER_CALIBRATION: f32 = 250.0;
+
+/// The EV100 of a camera with the given `aperture` (f-number), `shutter_time` (in seconds) and `iso` sensitivity.
+#[inline]
+pub fn ev100(aperture: f32, shutter_time: f32, iso: f32) -> f32 {
+    (aperture * aperture / shutter_time * 100.0 / iso).log2()
+}
+
+/// The EV100 at which a reflected-light meter reads a scene of average luminance `nits` (in cd/m²) as correctly exposed.
+#[inline]
+pub fn ev100_from_luminance(nits: f32) -> f32 {
+    (nits * 100.0 / REFLECTED_METER_CALIBRATION).log2()
+}
+
+/// The average scene luminance (in cd/m²) which is correctly exposed at `ev100`. The inverse of [`ev100_from_luminance`].
+#[inline]
+pub fn luminance_from_ev100(ev100: f32) -> f32 {
+    REFLECTED_METER_CALIBRATION / 100.0 * ev100.exp2()
+}
+
+/// The EV100 at which an incident-light meter reads an illuminance of `lux` as correctly exposed.
+#[inline]
+pub fn ev100_from_illuminance(lux: f32) -> f32 {
+    (lux * 100.0 / INCIDENT_METER_CALIBRATION).log2()
+}
+
+/// The illuminance (in lux) which is correctly exposed at `ev100`. The inverse of [`ev100_from_illuminance`].
+#[inline]
+pub fn illuminance_from_ev100(ev100: f32) -> f32 {
+    INCIDENT_METER_CALIBRATION / 100.0 * ev100.exp2()
+}
+
+/// The factor to multiply a scene-referred color in cd/m² by to expose it at `ev100`, such that the brightest
+/// luminance the camera can capture without saturating maps to `1.0`.
+#[inline]
+pub fn exposure_from_ev100(ev100: f32) -> f32 {
+    // saturation-based sensitivity: 78 / (S * q) * N² / t, with S = 100 and lens attenuation q = 0.65
+    1.0 / (1.2 * ev100.exp2())
+}
+
+/// Converts a radiometric quantity (in watts, or any unit derived from them) to the photometric
+/// equivalent (in lumens, or the corresponding derived unit), assuming light at the peak of human
+/// sensitivity (555nm).
+#[inline]
+pub fn radiometric_to_photometric(radiometric: f32) -> f32 {
+    radiometric * LUMINOUS_EFFICACY
+}
+
+/// The inverse of [`radiometric_to_photometric`].
+#[inline]
+pub fn photometric_to_radiometric(photometric: f32) -> f32 {
+    photometric / LUMINOUS_EFFICACY
+}
+
+impl<E: LinearEncoding> Color<E> {
+    /// Scales `self` by `ev` stops, i.e. by `2^ev`. Positive values brighten and negative values darken.
+    /// Alpha is left unchanged.
+    #[inline]
+    pub fn expose(self, ev: f32) -> Self {
+        Color::from_repr(E::scale_light(self.repr, ev.exp2()))
+    }
+}
+
+impl<E: ColorEncoding> Color<E> {
+    /// The luminance of `self` in cd/m², where a value of `1.0` in its encoding is `reference_white` cd/m².
+    ///
+    /// For example, display-referred colors are commonly given a reference white of 80 or 100 cd/m², while
+    /// scene-referred colors that are already in cd/m² use a reference white of `1.0`.
+    #[inline]
+    pub fn luminance_nits(self, reference_white: f32) -> f32
+    where
+        linear_spaces::CieXYZ: LinearConvertFromRaw<E::LinearSpace>,
+    {
+        self.luminance() * reference_white
+    }
+}
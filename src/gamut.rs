@@ -0,0 +1,256 @@
+//! Checking whether colors fit in the gamut of a color encoding, and mapping them into it when they don't.
+//!
+//! The gamut of a [`DisplayGamut`] encoding is the set of colors whose linear components in its
+//! [`LinearColorSpace`][crate::traits::LinearColorSpace] are all within its range, which is `0.0..=1.0` for most RGB
+//! encodings. A color from a wider space, or
+//! a scene-referred color brighter than the display's white, may fall outside of it. [`Color::convert`] would then
+//! produce out-of-range values, which are usually clipped when stored. [`Color::gamut_map`] instead brings the color
+//! into the gamut with a choice of [`GamutMapping`] method.
+//!
+//! ```
+//! use colstodian::{Color, gamut::GamutMapping};
+//! use colstodian::details::encodings::{Encoded, SrgbF32, SrgbU8};
+//! use colstodian::details::{linear_spaces, reprs::F32Repr, transfer_functions};
+//!
+//! type DisplayP3 = Encoded<linear_spaces::DisplayP3, transfer_functions::Srgb, F32Repr>;
+//!
+//! // a saturated green from a design tool working in Display P3
+//! let green = Color::<DisplayP3>::from_repr(glam::Vec3::new(0.1, 0.9, 0.2));
+//! assert!(!green.is_in_gamut::<SrgbU8>());
+//!
+//! let mapped = green.gamut_map::<SrgbF32>(GamutMapping::OklchChroma);
+//! assert!(mapped.is_in_gamut::<SrgbU8>());
+//! ```
+
+use crate::encodings::Oklab;
+use crate::linear_spaces::CieXYZ;
+use crate::traits::{ColorEncoding, DisplayGamut, LinearConvertFromRaw};
+use crate::Color;
+
+use glam::Vec3;
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use num_traits::float::Float;
+
+/// How far linear components may stray outside of the range of a [`DisplayGamut`] while still being considered in
+/// gamut, to allow for rounding errors in conversions between spaces.
+pub const GAMUT_TOLERANCE: f32 = 1e-5;
+
+/// A method of mapping colors into the gamut of a color encoding.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GamutMapping {
+    /// Clamp each linear component to the range of the gamut. This is the cheapest method, but it can shift the hue
+    /// and lightness of out-of-gamut colors noticeably.
+    Clip,
+    /// The gamut mapping algorithm of CSS Color Level 4: reduce the chroma of the color in Oklch, keeping its
+    /// lightness and hue, until clipping the result changes it by less than a just-noticeable difference.
+    ///
+    /// Colors at least as light as the gamut's white map to it, and colors at least as dark as black map to black.
+    OklchChroma,
+    /// Softly compress the distance of each component from the achromatic axis, as the ACES reference gamut
+    /// compression does, then clip any remaining overshoot.
+    ///
+    /// The distance of a component is how far below the largest component it is, relative to that largest
+    /// component, so it is `1.0` at the gamut boundary and above `1.0` outside of it. Distances below `threshold`
+    /// are left alone, and those above are compressed so that a distance of `limit` lands on the boundary, with
+    /// `power` controlling the curve of the compression. Each is given per red, green and blue component.
+    ///
+    /// Unlike the other methods, this also moves colors which are inside the gamut but near its boundary, keeping
+    /// gradients into out-of-gamut colors smooth. Gamuts without a lower bound, like those of extended-range
+    /// encodings, have no boundary to compress towards, so their colors are only clipped. See
+    /// [`GamutMapping::ACES_REFERENCE`].
+    SoftCompress {
+        threshold: Vec3,
+        limit: Vec3,
+        power: f32,
+    },
+}
+
+impl GamutMapping {
+    /// The parameters of the ACES 1.3 reference gamut compression, which were chosen to bring the gamuts of common
+    /// digital cinema cameras into ACEScg.
+    pub const ACES_REFERENCE: Self = Self::SoftCompress {
+        threshold: Vec3::new(0.815, 0.803, 0.88),
+        limit: Vec3::new(1.147, 1.264, 1.312),
+        power: 1.2,
+    };
+
+    /// Maps `linear` into the gamut of `Dst`, in its linear color space.
+    fn map<Dst>(self, linear: Vec3) -> Vec3
+    where
+        Dst: DisplayGamut,
+        Dst::LinearSpace: LinearConvertFromRaw<CieXYZ>,
+        CieXYZ: LinearConvertFromRaw<Dst::LinearSpace>,
+    {
+        match self {
+            GamutMapping::Clip => clip::<Dst>(linear),
+            GamutMapping::OklchChroma => oklch_chroma::<Dst>(linear),
+            GamutMapping::SoftCompress {
+                threshold,
+                limit,
+                power,
+            } if Dst::LINEAR_MIN.is_finite() => {
+                clip::<Dst>(soft_compress(linear, threshold, limit, power))
+            }
+            GamutMapping::SoftCompress { .. } => clip::<Dst>(linear),
+        }
+    }
+}
+
+#[inline]
+fn clip<Dst: DisplayGamut>(linear: Vec3) -> Vec3 {
+    linear.clamp(Vec3::splat(Dst::LINEAR_MIN), Vec3::splat(Dst::LINEAR_MAX))
+}
+
+#[inline]
+fn in_gamut<Dst: DisplayGamut>(linear: Vec3) -> bool {
+    linear
+        .cmpge(Vec3::splat(Dst::LINEAR_MIN - GAMUT_TOLERANCE))
+        .all()
+        && linear
+            .cmple(Vec3::splat(Dst::LINEAR_MAX + GAMUT_TOLERANCE))
+            .all()
+}
+
+fn to_oklab<Space>(mut linear: Vec3) -> Vec3
+where
+    CieXYZ: LinearConvertFromRaw<Space>,
+    Space: LinearConvertFromRaw<CieXYZ>,
+{
+    <CieXYZ as LinearConvertFromRaw<Space>>::linear_part_raw(&mut linear);
+    Oklab::dst_transform_raw(linear, 1.0)
+}
+
+fn from_oklab<Space>(oklab: Vec3) -> Vec3
+where
+    CieXYZ: LinearConvertFromRaw<Space>,
+    Space: LinearConvertFromRaw<CieXYZ>,
+{
+    let (mut linear, _) = Oklab::src_transform_raw(oklab);
+    <Space as LinearConvertFromRaw<CieXYZ>>::linear_part_raw(&mut linear);
+    linear
+}
+
+/// The CSS Color Level 4 gamut mapping algorithm, with a binary search over Oklch chroma.
+fn oklch_chroma<Dst>(linear: Vec3) -> Vec3
+where
+    Dst: DisplayGamut,
+    CieXYZ: LinearConvertFromRaw<Dst::LinearSpace>,
+    Dst::LinearSpace: LinearConvertFromRaw<CieXYZ>,
+{
+    // the just-noticeable difference in Oklab, and the precision of the search
+    const JND: f32 = 0.02;
+    const EPSILON: f32 = 0.0001;
+
+    let origin = to_oklab::<Dst::LinearSpace>(linear);
+    if in_gamut::<Dst>(linear) {
+        return linear;
+    }
+    let white = Vec3::splat(Dst::LINEAR_MAX);
+    if Dst::LINEAR_MAX.is_finite() && origin.x >= to_oklab::<Dst::LinearSpace>(white).x {
+        return white;
+    }
+    if origin.x <= 0.0 {
+        return clip::<Dst>(Vec3::ZERO);
+    }
+
+    let with_chroma = |chroma: f32| {
+        let current = origin.truncate().length();
+        let scale = if current > 0.0 { chroma / current } else { 0.0 };
+        Vec3::new(origin.x, origin.y * scale, origin.z * scale)
+    };
+    let delta_e_ok =
+        |oklab: Vec3, clipped: Vec3| (to_oklab::<Dst::LinearSpace>(clipped) - oklab).length();
+
+    let mut clipped = clip::<Dst>(linear);
+    if delta_e_ok(origin, clipped) < JND {
+        return clipped;
+    }
+
+    let mut min = 0.0;
+    let mut max = origin.truncate().length();
+    let mut min_in_gamut = true;
+    while max - min > EPSILON {
+        let chroma = (min + max) * 0.5;
+        let current = with_chroma(chroma);
+        let current_linear = from_oklab::<Dst::LinearSpace>(current);
+        if min_in_gamut && in_gamut::<Dst>(current_linear) {
+            min = chroma;
+            continue;
+        }
+        clipped = clip::<Dst>(current_linear);
+        let e = delta_e_ok(current, clipped);
+        if e < JND {
+            if JND - e < EPSILON {
+                return clipped;
+            }
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+    clipped
+}
+
+/// The ACES reference gamut compression, generalized to any per-component threshold, limit and power.
+fn soft_compress(linear: Vec3, threshold: Vec3, limit: Vec3, power: f32) -> Vec3 {
+    let achromatic = linear.max_element();
+    if achromatic <= 0.0 {
+        return linear;
+    }
+
+    // the scale which makes a distance of `limit` compress to exactly `1.0`
+    let scale = (limit - threshold)
+        / (((Vec3::ONE - threshold) / (limit - threshold)).powf(-power) - Vec3::ONE)
+            .powf(1.0 / power);
+
+    let distance = (Vec3::splat(achromatic) - linear) / achromatic;
+    let compress = |d: f32, t: f32, s: f32| {
+        if d < t {
+            d
+        } else {
+            let x = (d - t) / s;
+            t + s * x / (1.0 + x.powf(power)).powf(1.0 / power)
+        }
+    };
+    let compressed = Vec3::new(
+        compress(distance.x, threshold.x, scale.x),
+        compress(distance.y, threshold.y, scale.y),
+        compress(distance.z, threshold.z, scale.z),
+    );
+    Vec3::splat(achromatic) - compressed * achromatic
+}
+
+impl<E: ColorEncoding> Color<E> {
+    /// Whether `self` is within the gamut of the encoding `Dst`, i.e. whether its linear components in `Dst`'s linear
+    /// color space are all within the range of its [`DisplayGamut`], up to [`GAMUT_TOLERANCE`].
+    ///
+    /// Any alpha component is ignored.
+    pub fn is_in_gamut<Dst>(self) -> bool
+    where
+        Dst: DisplayGamut,
+        Dst::LinearSpace: LinearConvertFromRaw<E::LinearSpace>,
+    {
+        let (mut linear, _) = E::src_transform_raw(self.repr);
+        <Dst::LinearSpace as LinearConvertFromRaw<E::LinearSpace>>::linear_part_raw(&mut linear);
+        in_gamut::<Dst>(linear)
+    }
+
+    /// Converts `self` to the [`DisplayGamut`] encoding `Dst`, mapping it into `Dst`'s gamut with `method` if it
+    /// is outside of it.
+    ///
+    /// Unlike [`convert`][Color::convert], this is also possible from scene-referred encodings, since bringing the
+    /// color into the display's range is the point. Any alpha component is left unchanged.
+    pub fn gamut_map<Dst>(self, method: GamutMapping) -> Color<Dst>
+    where
+        Dst: DisplayGamut,
+        Dst::LinearSpace: LinearConvertFromRaw<E::LinearSpace> + LinearConvertFromRaw<CieXYZ>,
+        CieXYZ: LinearConvertFromRaw<Dst::LinearSpace>,
+    {
+        let (mut linear, alpha) = E::src_transform_raw(self.repr);
+        <Dst::LinearSpace as LinearConvertFromRaw<E::LinearSpace>>::linear_part_raw(&mut linear);
+        let mapped = method.map::<Dst>(linear);
+        Color::from_repr(Dst::dst_transform_raw(mapped, alpha))
+    }
+}
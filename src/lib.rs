@@ -37,6 +37,11 @@
 //! You can convert a color from a working encoding to [`SrgbU8`] for output again with the [`.convert::<E>()`][Color::convert]
 //! method.
 //!
+//! [`LinearSrgb`] holds display-referred values, where `1.0` is the white of the display. For values with unbounded
+//! brightness, such as the result of lighting calculations, use the scene-referred [`SceneLinearSrgb`][basic_encodings::SceneLinearSrgb] instead. Scene-referred
+//! colors can't be [`convert`][Color::convert]ed to display encodings like [`SrgbU8`], which would clip them; map them
+//! explicitly with a [`Tonemapper`][tonemap::Tonemapper] or [`Color::clamp_to_display`].
+//!
 //! ### Example
 //!
 //! Here we construct two colors in different ways, convert them both to [`LinearSrgb`] to work with them, and then convert the result
@@ -91,19 +96,51 @@ pub mod details {
     /// Contains the [`Color`][color::Color] type and helper functions.
     pub mod color;
 
+    /// Hex string parsing and formatting for 8-bit sRGB [`Color`][color::Color]s.
+    pub mod hex;
+
+    /// Parsing and serialization of CSS Color Level 4 color syntax.
+    pub mod css;
+
+    /// Conversions between [`ColorEncoding`][traits::ColorEncoding]s which can be evaluated in `const` contexts.
+    pub mod const_convert;
+
     /// Types representing different [`LinearColorSpace`][traits::LinearColorSpace]s.
     #[rustfmt::skip]
     pub mod linear_spaces;
 
+    /// `f64` conversion matrices between [`LinearColorSpace`][traits::LinearColorSpace]s, and the machinery to derive them.
+    pub mod matrices_f64;
+
+    /// Types representing different [`TransferFunction`][traits::TransferFunction]s.
+    pub mod transfer_functions;
+
     /// The traits which form the backbone of this crate.
     pub mod traits;
 
     /// The underlying data representations ([`ColorRepr`][traits::ColorRepr]s) used by different [`ColorEncoding`][traits::ColorEncoding]s.
     pub mod reprs;
+
+    pub(crate) mod const_math;
 }
 
 pub(crate) use details::*;
 
+pub mod adaptation;
+pub mod contrast;
+pub mod difference;
+pub mod error;
+pub mod exposure;
+pub mod gamut;
+
+pub mod named;
+pub mod temperature;
+
+pub mod tonemap;
+
+#[doc(hidden)]
+pub mod macros;
+
 /// Contains a basic set of [`ColorEncoding`]s to get most people going.
 ///
 /// These are all re-exported from inside the [`details::encodings`]
@@ -113,6 +150,8 @@ pub mod basic_encodings {
     #[doc(inline)]
     pub use crate::details::encodings::LinearSrgbA;
     #[doc(inline)]
+    pub use crate::details::encodings::SceneLinearSrgb;
+    #[doc(inline)]
     pub use crate::details::encodings::SrgbAU8;
     #[doc(inline)]
     pub use crate::details::encodings::SrgbU8;
@@ -121,6 +160,9 @@ pub mod basic_encodings {
 #[doc(inline)]
 pub use color::Color;
 
+#[doc(inline)]
+pub use css::DynamicColor;
+
 #[doc(inline)]
 pub use traits::ColorEncoding;
 
@@ -183,9 +225,6 @@ pub mod equals_eps {
     use reprs::*;
     use traits::*;
 
-    #[cfg(feature = "libm")]
-    use num_traits::float::Float;
-
     /// Check whether `self` and `other` are equal within a margin of `eps`.
     pub trait EqualsEps<T> {
         fn eq_eps(self, other: Self, eps: T) -> bool;
@@ -197,6 +236,12 @@ pub mod equals_eps {
         }
     }
 
+    impl EqualsEps<f64> for f64 {
+        fn eq_eps(self, other: f64, eps: f64) -> bool {
+            (self - other).abs() <= eps
+        }
+    }
+
     impl EqualsEps<u8> for u8 {
         fn eq_eps(self, other: u8, eps: u8) -> bool {
             (self as i32 - other as i32).unsigned_abs() as u8 <= eps
@@ -228,6 +273,12 @@ pub mod equals_eps {
         }
     }
 
+    impl EqualsEps<f32> for glam::Vec2 {
+        fn eq_eps(self, other: glam::Vec2, eps: f32) -> bool {
+            self[0].eq_eps(other[0], eps) && self[1].eq_eps(other[1], eps)
+        }
+    }
+
     impl EqualsEps<f32> for F32ARepr {
         fn eq_eps(self, other: F32ARepr, eps: f32) -> bool {
             self[0].eq_eps(other[0], eps)
@@ -237,6 +288,23 @@ pub mod equals_eps {
         }
     }
 
+    impl EqualsEps<f64> for F64Repr {
+        fn eq_eps(self, other: F64Repr, eps: f64) -> bool {
+            self[0].eq_eps(other[0], eps)
+                && self[1].eq_eps(other[1], eps)
+                && self[2].eq_eps(other[2], eps)
+        }
+    }
+
+    impl EqualsEps<f64> for F64ARepr {
+        fn eq_eps(self, other: F64ARepr, eps: f64) -> bool {
+            self[0].eq_eps(other[0], eps)
+                && self[1].eq_eps(other[1], eps)
+                && self[2].eq_eps(other[2], eps)
+                && self[3].eq_eps(other[3], eps)
+        }
+    }
+
     impl<E: ColorEncoding> EqualsEps<<E::Repr as ColorRepr>::Element> for Color<E>
     where
         E::Repr: EqualsEps<<E::Repr as ColorRepr>::Element>,
@@ -276,6 +344,39 @@ mod tests {
     use equals_eps::*;
     use glam::Vec3;
 
+    /// Fixed-capacity [`core::fmt::Write`] sink, so formatting can be tested without `alloc`.
+    struct FmtBuf {
+        buf: [u8; 128],
+        len: usize,
+    }
+
+    impl FmtBuf {
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.buf[..self.len]).unwrap()
+        }
+    }
+
+    impl core::fmt::Write for FmtBuf {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            self.buf
+                .get_mut(self.len..end)
+                .ok_or(core::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    /// Like `format!`, but writes into a [`FmtBuf`].
+    macro_rules! fmt_buf {
+        ($($arg:tt)*) => {{
+            let mut buf = FmtBuf { buf: [0; 128], len: 0 };
+            core::fmt::Write::write_fmt(&mut buf, format_args!($($arg)*)).unwrap();
+            buf
+        }};
+    }
+
     #[test]
     fn basic() {
         let grey_f32 = Color::srgb_f32(0.5, 0.5, 0.5);
@@ -329,6 +430,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::assign_op_pattern)]
     fn working_space_math() {
         let col = Color::linear_srgb(1.0, 1.0, 1.0);
 
@@ -351,6 +453,1864 @@ mod tests {
         assert_eq_eps!(col2, Color::linear_srgb(5.0, 5.0, 5.0), 0.00001);
     }
 
+    #[test]
+    fn packed_u32() {
+        const PALETTE: [Color<SrgbAU8>; 2] = [
+            Color::from_u32_rgba(0x6633ccff),
+            Color::from_u32_argb(0x806633cc),
+        ];
+
+        assert_eq!(PALETTE[0], Color::srgba_u8(0x66, 0x33, 0xcc, 0xff));
+        assert_eq!(PALETTE[1], Color::srgba_u8(0x66, 0x33, 0xcc, 0x80));
+        assert_eq!(PALETTE[0].to_u32_rgba(), 0x6633ccff);
+        assert_eq!(PALETTE[1].to_u32_argb(), 0x806633cc);
+        assert_eq!(PALETTE[1].to_u32_rgba(), 0x6633cc80);
+
+        let premul = Color::<SrgbAU8Premultiplied>::from_u32_argb(0x80331966);
+        assert_eq!(premul.repr, [0x33, 0x19, 0x66, 0x80]);
+        assert_eq!(premul.to_u32_argb(), 0x80331966);
+
+        let rgb = Color::<SrgbU8>::from_u32_rgb(0xff6633cc);
+        assert_eq!(rgb, Color::srgb_u8(0x66, 0x33, 0xcc));
+        assert_eq!(rgb.to_u32_rgb(), 0x6633cc);
+    }
+
+    #[test]
+    fn hex_strings() {
+        use core::str::FromStr;
+        use error::HexParseError;
+
+        let rgb = Color::srgb_u8(0x66, 0x33, 0xcc);
+        assert_eq!(Color::<SrgbU8>::from_str("#6633CC"), Ok(rgb));
+        assert_eq!(Color::<SrgbU8>::try_from("63c"), Ok(rgb));
+        assert_eq!(
+            Color::<SrgbU8>::from_str("#63cf"),
+            Err(HexParseError::InvalidLength {
+                len: 4,
+                alpha: false
+            })
+        );
+        assert_eq!(
+            Color::<SrgbU8>::from_str("#66x3cc"),
+            Err(HexParseError::InvalidDigit { index: 3 })
+        );
+        assert_eq!(
+            Color::<SrgbU8>::from_str(""),
+            Err(HexParseError::InvalidLength {
+                len: 0,
+                alpha: false
+            })
+        );
+
+        let rgba = Color::srgba_u8(0x66, 0x33, 0xcc, 0x88);
+        assert_eq!("#6633cc88".parse::<Color<SrgbAU8>>(), Ok(rgba));
+        assert_eq!("63c8".parse::<Color<SrgbAU8>>(), Ok(rgba));
+        assert_eq!(
+            "6633cc".parse::<Color<SrgbAU8>>(),
+            Ok(Color::srgba_u8(0x66, 0x33, 0xcc, 0xff))
+        );
+
+        // the message lists the lengths the target encoding accepts
+        let error = Color::<SrgbU8>::from_str("#63cf").unwrap_err();
+        assert_eq!(
+            fmt_buf!("{}", error).as_str(),
+            "invalid hex color length: expected 3 or 6 digits, found 4"
+        );
+        let error = "#63cf1".parse::<Color<SrgbAU8>>().unwrap_err();
+        assert_eq!(
+            fmt_buf!("{}", error).as_str(),
+            "invalid hex color length: expected 3, 4, 6 or 8 digits, found 5"
+        );
+
+        assert_eq!(&*rgb.to_hex_string(), "#6633cc");
+        assert_eq!(&*rgba.to_hex_string(), "#6633cc88");
+        assert_eq!(fmt_buf!("{:x}", rgb).as_str(), "6633cc");
+        assert_eq!(fmt_buf!("{:#x}", rgba).as_str(), "#6633cc88");
+        assert_eq!(fmt_buf!("{:#X}", rgb).as_str(), "#6633CC");
+    }
+
+    #[test]
+    fn css_parsing() {
+        use error::CssParseError;
+
+        let srgb = |s: &str| Color::<SrgbAU8>::from_css(s).unwrap();
+
+        assert_eq_eps!(srgb("rgb(255 0 0)"), Color::srgba_u8(255, 0, 0, 255), 1);
+        assert_eq_eps!(
+            srgb("RGBA(255, 0, 0, 0.5)"),
+            Color::srgba_u8(255, 0, 0, 127),
+            1
+        );
+        assert_eq_eps!(
+            srgb("rgba(100%, 0%, 0%, 50%)"),
+            srgb("rgb(100% 0% 0% / 0.5)"),
+            0
+        );
+        assert_eq_eps!(srgb("rgb(300 -20 0)"), Color::srgba_u8(255, 0, 0, 255), 1);
+        assert_eq_eps!(
+            srgb("hsl(120, 100%, 50%)"),
+            Color::srgba_u8(0, 255, 0, 255),
+            1
+        );
+        assert_eq_eps!(
+            srgb("hsl(0.5turn 100 50)"),
+            Color::srgba_u8(0, 255, 255, 255),
+            1
+        );
+        assert_eq_eps!(srgb("hwb(0 0% 0%)"), Color::srgba_u8(255, 0, 0, 255), 1);
+        assert_eq_eps!(srgb("hwb(0 60% 60%)"), srgb("rgb(50% 50% 50%)"), 0);
+        assert_eq_eps!(srgb("#f008"), Color::srgba_u8(255, 0, 0, 136), 1);
+        assert_eq_eps!(
+            srgb("CornflowerBlue"),
+            Color::srgba_u8(100, 149, 237, 255),
+            1
+        );
+        assert_eq_eps!(srgb("transparent"), Color::srgba_u8(0, 0, 0, 0), 1);
+
+        assert_eq_eps!(
+            srgb("lab(54.29% 80.82 69.91)"),
+            Color::srgba_u8(255, 0, 0, 255),
+            1
+        );
+        assert_eq_eps!(
+            srgb("lch(54.29 106.84 40.85deg)"),
+            Color::srgba_u8(255, 0, 0, 255),
+            1
+        );
+        assert_eq_eps!(
+            srgb("oklab(0.628 0.2249 0.1258)"),
+            Color::srgba_u8(255, 0, 0, 255),
+            1
+        );
+        assert_eq_eps!(
+            srgb("oklch(62.8% 0.2577 0.5101rad)"),
+            Color::srgba_u8(255, 0, 0, 255),
+            1
+        );
+        assert_eq_eps!(
+            srgb("color(xyz 0.4124 0.2126 0.0193)"),
+            Color::srgba_u8(255, 0, 0, 255),
+            1
+        );
+        assert_eq_eps!(
+            srgb("color(xyz-d50 0.4361 0.2225 0.0139)"),
+            Color::srgba_u8(255, 0, 0, 255),
+            1
+        );
+        assert_eq_eps!(
+            srgb("color(a98-rgb 0.8587 0 0)"),
+            Color::srgba_u8(255, 0, 0, 255),
+            1
+        );
+        assert_eq_eps!(
+            srgb("color(prophoto-rgb 0.7022 0.2757 0.1036)"),
+            Color::srgba_u8(255, 0, 0, 255),
+            1
+        );
+        assert_eq_eps!(
+            srgb("color(rec2020 0.7919 0.2310 0.0739)"),
+            Color::srgba_u8(255, 0, 0, 255),
+            1
+        );
+
+        let p3 = Color::<LinearSrgb>::from_css("color(display-p3 1 0 0)").unwrap();
+        assert_eq_eps!(p3, Color::linear_srgb(1.2249, -0.0421, -0.0196), 0.001);
+
+        let none = DynamicColor::parse_css("rgb(none 255 0 / none)").unwrap();
+        assert_eq!(none.components[0], None);
+        assert_eq!(none.alpha, None);
+        assert_eq_eps!(none.convert::<SrgbAU8>(), Color::srgba_u8(0, 255, 0, 0), 1);
+        assert_eq!(fmt_buf!("{}", none).as_str(), "rgb(none 255 0 / none)");
+
+        let err = |s: &str| DynamicColor::parse_css(s).unwrap_err();
+        assert_eq!(err("rgb(1 2)"), CssParseError::InvalidSyntax);
+        assert_eq!(err("rgb(1, 2 3)"), CssParseError::InvalidSyntax);
+        assert_eq!(err("rgb(1 2 3 /)"), CssParseError::InvalidSyntax);
+        assert_eq!(err("rgb(1 2 3 4)"), CssParseError::InvalidSyntax);
+        assert_eq!(err("lab(1, 2, 3)"), CssParseError::InvalidSyntax);
+        assert_eq!(
+            err("rgb(255, 0%, 0)"),
+            CssParseError::InvalidComponent { index: 1 }
+        );
+        assert_eq!(
+            err("rgb(255, none, 0)"),
+            CssParseError::InvalidComponent { index: 1 }
+        );
+        assert_eq!(
+            err("hsl(10 20deg 30%)"),
+            CssParseError::InvalidComponent { index: 1 }
+        );
+        assert_eq!(
+            err("rgb(1px 2 3)"),
+            CssParseError::InvalidComponent { index: 0 }
+        );
+        assert_eq!(
+            err("rgb(inf 2 3)"),
+            CssParseError::InvalidComponent { index: 0 }
+        );
+        assert_eq!(err("foo(1 2 3)"), CssParseError::UnknownFunction);
+        assert_eq!(err("color(foo 1 2 3)"), CssParseError::UnknownColorSpace);
+        assert_eq!(err("notacolor"), CssParseError::UnknownKeyword);
+        assert!(matches!(err("#12"), CssParseError::Hex(_)));
+    }
+
+    #[test]
+    fn css_serialization() {
+        let roundtrip = |s: &str| fmt_buf!("{}", DynamicColor::parse_css(s).unwrap());
+
+        assert_eq!(
+            roundtrip("hsl(270deg 50% 40% / 25%)").as_str(),
+            "hsl(270 50% 40% / 0.25)"
+        );
+        assert_eq!(
+            roundtrip("oklch(0.7 0.1 none)").as_str(),
+            "oklch(0.7 0.1 none)"
+        );
+        assert_eq!(
+            roundtrip("color(xyz 0.5 0.25 1)").as_str(),
+            "color(xyz-d65 0.5 0.25 1)"
+        );
+
+        assert_eq!(
+            fmt_buf!("{}", Color::srgb_u8(1, 2, 3).to_css()).as_str(),
+            "rgb(1 2 3)"
+        );
+        assert_eq!(
+            fmt_buf!("{}", Color::srgba_u8(1, 2, 3, 51).to_css()).as_str(),
+            "rgb(1 2 3 / 0.2)"
+        );
+        assert_eq!(
+            fmt_buf!("{}", Color::srgba_f32(0.5, 0.25, 1.0, 0.5).to_css()).as_str(),
+            "color(srgb 0.5 0.25 1 / 0.5)"
+        );
+        assert_eq!(
+            fmt_buf!("{}", Color::oklab(0.5, 0.125, -0.25).to_css()).as_str(),
+            "oklab(0.5 0.125 -0.25)"
+        );
+
+        let col = Color::srgba_u8(102, 51, 153, 128);
+        let parsed = Color::<SrgbAU8>::from_css(fmt_buf!("{}", col.to_css()).as_str()).unwrap();
+        assert_eq_eps!(parsed, col, 1);
+
+        assert_eq!(
+            fmt_buf!("{}", Color::cie_xyz(0.5, 0.25, 1.0).to_css()).as_str(),
+            "color(xyz-d65 0.5 0.25 1)"
+        );
+        assert_eq!(
+            fmt_buf!(
+                "{}",
+                Color::<SrgbAF64>::from_repr(glam::DVec4::new(0.5, 0.25, 1.0, 0.5)).to_css()
+            )
+            .as_str(),
+            "color(srgb 0.5 0.25 1 / 0.5)"
+        );
+
+        let srgb = Color::srgb_u8(102, 51, 153);
+        let lab: Color<CieLab> = srgb.convert();
+        let written = fmt_buf!("{}", lab.to_css());
+        assert!(written.as_str().starts_with("lab("));
+        assert_eq_eps!(
+            Color::<CieLab>::from_css(written.as_str()).unwrap(),
+            lab,
+            0.01
+        );
+
+        type DisplayP3F32 = Encoded<
+            linear_spaces::DisplayP3,
+            transfer_functions::Linear,
+            reprs::F32ARepr,
+            SeparateAlpha,
+        >;
+        let p3: Color<DisplayP3F32> = Color::srgba_u8(102, 51, 153, 128).convert();
+        let written = fmt_buf!("{}", p3.to_css());
+        assert!(written.as_str().starts_with("color(display-p3 "));
+        assert_eq_eps!(
+            Color::<DisplayP3F32>::from_css(written.as_str()).unwrap(),
+            p3,
+            1e-4
+        );
+
+        type Rec2020F32 =
+            Encoded<linear_spaces::Bt2020, transfer_functions::Gamma24, reprs::F32Repr>;
+        let rec2020: Color<Rec2020F32> = srgb.convert();
+        let written = fmt_buf!("{}", rec2020.to_css());
+        assert!(written.as_str().starts_with("color(rec2020 "));
+        assert_eq_eps!(
+            Color::<Rec2020F32>::from_css(written.as_str()).unwrap(),
+            rec2020,
+            1e-4
+        );
+
+        let bt1886: Color<Bt1886F32> = srgb.convert();
+        let written = fmt_buf!("{}", bt1886.to_css());
+        assert!(written.as_str().starts_with("color(srgb "));
+        assert_eq_eps!(
+            Color::<Bt1886F32>::from_css(written.as_str()).unwrap(),
+            bt1886,
+            1e-4
+        );
+
+        // ProPhoto RGB uses the same transfer function as CSS, so its components are written unchanged
+        let prophoto: Color<ProPhotoRgbF32> = srgb.convert();
+        let parsed = DynamicColor::parse_css(fmt_buf!("{}", prophoto.to_css()).as_str()).unwrap();
+        assert_eq!(parsed.space, css::CssColorSpace::ProphotoRgb);
+        assert_eq_eps!(
+            Vec3::from(parsed.components.map(Option::unwrap)),
+            prophoto.repr,
+            1e-5
+        );
+    }
+
+    #[test]
+    fn named_colors() {
+        assert_eq!(named::CORNFLOWER_BLUE, Color::srgb_u8(100, 149, 237));
+        assert_eq!(
+            named::from_name("cornflowerblue"),
+            Some(named::CORNFLOWER_BLUE)
+        );
+        assert_eq!(
+            named::from_name("Cornflower Blue"),
+            Some(named::CORNFLOWER_BLUE)
+        );
+        assert_eq!(
+            named::from_name("CORNFLOWER_BLUE"),
+            Some(named::CORNFLOWER_BLUE)
+        );
+        assert_eq!(named::from_name("cornflower"), None);
+        assert_eq!(named::from_name(""), None);
+
+        for &(name, color) in named::ALL.iter() {
+            assert_eq!(named::from_name(name), Some(color));
+            assert_eq!(named::nearest(color).1, color);
+        }
+
+        assert_eq!(named::nearest(Color::srgb_u8(128, 128, 128)).0, "gray");
+        assert_eq!(
+            named::nearest(Color::srgb_u8(100, 50, 150)).0,
+            "rebeccapurple"
+        );
+        assert_eq!(named::nearest(Color::linear_srgb(0.0, 0.0, 0.9)).0, "blue");
+    }
+
+    #[test]
+    fn color_macro() {
+        const ACCENT: Color<SrgbU8> = color!(srgb_u8 "#6633cc");
+        assert_eq!(ACCENT, Color::srgb_u8(0x66, 0x33, 0xcc));
+        assert_eq!(color!(srgb_u8 "63c"), ACCENT);
+        assert_eq!(color!(srgb_u8 102, 51, 204), ACCENT);
+        assert_eq!(color!(srgb_u8 102 51 204), ACCENT);
+        assert_eq!(
+            color!(srgba_u8 "#6633cc80"),
+            Color::srgba_u8(0x66, 0x33, 0xcc, 0x80)
+        );
+        assert_eq!(
+            color!(srgba_u8 102, 51, 204, 128u8),
+            Color::srgba_u8(0x66, 0x33, 0xcc, 0x80)
+        );
+
+        assert_eq!(
+            color!(srgb_f32 0.4, 0.2, 0.6),
+            Color::srgb_f32(0.4, 0.2, 0.6)
+        );
+        assert_eq!(
+            color!(srgba_f32 0.4 0.2 0.6 1),
+            Color::srgba_f32(0.4, 0.2, 0.6, 1.0)
+        );
+        assert_eq!(
+            color!(linear_srgb 0.2, 0.3, 0.4,),
+            Color::linear_srgb(0.2, 0.3, 0.4)
+        );
+        assert_eq!(
+            color!(linear_srgba 2e-1, 0.3f32, 4E-1, 0.5),
+            Color::linear_srgba(0.2, 0.3, 0.4, 0.5)
+        );
+        assert_eq!(color!(oklab 0.7, -0.05, 0.1), Color::oklab(0.7, -0.05, 0.1));
+
+        let expected = Color::oklab(0.7, 0.0, -0.1);
+        assert_eq_eps!(color!(oklch 0.7 0.1 270deg), expected, 0.00001);
+        assert_eq_eps!(color!(oklch 0.7 0.1 -90), expected, 0.00001);
+        assert_eq_eps!(color!(oklch 0.7, 0.1, 300grad), expected, 0.00001);
+        assert_eq_eps!(color!(oklch 0.7 0.1 0.75turn), expected, 0.00001);
+        assert_eq_eps!(color!(oklch 0.7 0.1 4.71238898rad), expected, 0.00001);
+    }
+
+    #[test]
+    fn const_conversions() {
+        const BG: Color<LinearSrgb> = Color::srgb_u8(102, 51, 153).const_convert();
+        assert_eq_eps!(BG, Color::srgb_u8(102, 51, 153).convert(), 0.00001);
+
+        macro_rules! check {
+            ($src:expr, $eps_u8:expr, $eps_f32:expr) => {{
+                let src = $src;
+                assert_eq_eps!(
+                    src.const_convert::<SrgbU8>(),
+                    src.convert::<SrgbU8>(),
+                    $eps_u8
+                );
+                assert_eq_eps!(
+                    src.const_convert::<SrgbAU8>(),
+                    src.convert::<SrgbAU8>(),
+                    $eps_u8
+                );
+                assert_eq_eps!(
+                    src.const_convert::<SrgbAU8Premultiplied>(),
+                    src.convert::<SrgbAU8Premultiplied>(),
+                    $eps_u8
+                );
+                assert_eq_eps!(
+                    src.const_convert::<SrgbF32>(),
+                    src.convert::<SrgbF32>(),
+                    $eps_f32
+                );
+                assert_eq_eps!(
+                    src.const_convert::<SrgbAF32>(),
+                    src.convert::<SrgbAF32>(),
+                    $eps_f32
+                );
+                assert_eq_eps!(
+                    src.const_convert::<LinearSrgb>(),
+                    src.convert::<LinearSrgb>(),
+                    $eps_f32
+                );
+                assert_eq_eps!(
+                    src.const_convert::<LinearSrgbA>(),
+                    src.convert::<LinearSrgbA>(),
+                    $eps_f32
+                );
+                assert_eq_eps!(
+                    src.const_convert::<LinearSrgbAPremultiplied>(),
+                    src.convert::<LinearSrgbAPremultiplied>(),
+                    $eps_f32
+                );
+                assert_eq_eps!(
+                    src.const_convert::<Oklab>(),
+                    src.convert::<Oklab>(),
+                    $eps_f32
+                );
+            }};
+        }
+
+        check!(Color::srgb_u8(105, 220, 58), 1, 0.0001);
+        check!(Color::srgba_u8(10, 20, 100, 128), 1, 0.0001);
+        check!(
+            Color::srgba_u8(10, 20, 100, 255).convert::<SrgbAU8Premultiplied>(),
+            1,
+            0.001
+        );
+        check!(Color::srgb_f32(0.0, 0.5, 1.0), 1, 0.0001);
+        check!(Color::srgba_f32(0.2, 0.01, 0.9, 0.25), 1, 0.0001);
+        check!(Color::linear_srgb(0.0, 0.002, 0.7), 1, 0.0001);
+        check!(Color::linear_srgba(0.3, 0.2, 0.1, 0.75), 1, 0.0001);
+        check!(
+            Color::linear_srgba_premultiplied(0.1, 0.2, 0.3, 0.5),
+            1,
+            0.0001
+        );
+        check!(Color::oklab(0.6, 0.1, -0.1), 1, 0.0001);
+
+        // fully transparent premultiplied colors decode to black rather than NaN
+        check!(
+            Color::linear_srgba_premultiplied(0.0, 0.0, 0.0, 0.0),
+            0,
+            0.0001
+        );
+        check!(
+            Color::<SrgbAU8Premultiplied>::from_repr([0, 0, 0, 0]),
+            0,
+            0.0001
+        );
+    }
+
+    #[test]
+    fn f64_encodings() {
+        use glam::DMat3;
+
+        let col = Color::srgba_f64(0.4, 0.2, 0.6, 0.5);
+        let oklab = col.convert::<OklabF64>();
+        let back = oklab.convert::<SrgbAF64>();
+        assert_eq_eps!(back, Color::srgba_f64(0.4, 0.2, 0.6, 1.0), 1e-12);
+        assert_eq_eps!(
+            oklab.convert::<SrgbF64>(),
+            Color::srgb_f64(0.4, 0.2, 0.6),
+            1e-12
+        );
+        assert_eq_eps!(
+            col.convert::<LinearSrgbAPremultipliedF64>()
+                .convert::<LinearSrgbAF64>()
+                .convert::<SrgbAF64>(),
+            col,
+            1e-12
+        );
+
+        // f64 conversions agree with the f32 ones
+        let col_f32 = Color::srgba_f32(0.4, 0.2, 0.6, 0.5);
+        assert_eq!(col_f32.to_f64().to_f32(), col_f32);
+        assert_eq_eps!(
+            col.convert::<LinearSrgbAF64>().to_f32(),
+            col_f32.convert::<LinearSrgbA>(),
+            0.00001
+        );
+        assert_eq_eps!(oklab.to_f32(), col_f32.convert::<Oklab>(), 0.0001);
+
+        let f32_mat = kolor::details::generated_matrices::BT_709_D65_TO_AP1_D60;
+        let f64_mat = matrices_f64::BT_709_D65_TO_AP1_D60;
+        assert!(f64_mat.abs_diff_eq(f32_mat.as_dmat3(), 1e-6));
+        let round_trip =
+            matrices_f64::CIE_XYZ_D65_TO_BT_709_D65 * matrices_f64::BT_709_D65_TO_CIE_XYZ_D65;
+        assert!(round_trip.abs_diff_eq(DMat3::IDENTITY, 1e-12));
+    }
+
+    #[test]
+    fn luminance_and_luma() {
+        use details::{linear_spaces, traits::LumaCoefficients};
+
+        assert_eq_eps!(Color::srgb_u8(255, 255, 255).luminance(), 1.0, 1e-4);
+        assert_eq_eps!(Color::linear_srgb(1.0, 0.0, 0.0).luminance(), 0.2126, 1e-4);
+        assert_eq_eps!(Color::linear_srgb(0.0, 1.0, 0.0).luminance(), 0.7152, 1e-4);
+        assert_eq_eps!(Color::linear_srgb(0.0, 0.0, 1.0).luminance(), 0.0722, 1e-4);
+        // middle grey in sRGB is ~18% luminance
+        assert_eq_eps!(Color::srgb_u8(118, 118, 118).luminance(), 0.18, 2e-3);
+        assert_eq_eps!(
+            Color::srgb_u8(50, 100, 200).convert::<Oklab>().luminance(),
+            Color::srgb_u8(50, 100, 200).luminance(),
+            1e-4
+        );
+
+        let color = Color::srgb_u8(255, 0, 0);
+        assert_eq_eps!(color.luma(), 0.2126, 1e-6);
+        assert_eq_eps!(color.luma_with(LumaCoefficients::Rec601), 0.299, 1e-6);
+        assert_eq_eps!(color.luma_with(LumaCoefficients::Rec2020), 0.2627, 1e-6);
+        for coefficients in [
+            LumaCoefficients::Rec601,
+            LumaCoefficients::Rec709,
+            LumaCoefficients::Rec2020,
+        ] {
+            assert_eq_eps!(coefficients.weights().dot(Vec3::ONE), 1.0, 1e-6);
+        }
+
+        // luma is computed on encoded values, so differs from luminance for mid tones
+        let grey = Color::srgb_u8(118, 118, 118);
+        assert_eq_eps!(grey.luma(), 118.0 / 255.0, 1e-6);
+        let premultiplied = Color::srgba_u8(118, 118, 118, 128).convert::<SrgbAU8Premultiplied>();
+        assert_eq_eps!(premultiplied.luma(), grey.luma(), 1e-2);
+        assert_eq_eps!(Color::srgb_f64(0.5, 0.5, 0.5).luma(), 0.5, 1e-6);
+
+        // coefficients derived from the XYZ matrix reproduce the standard ones
+        assert_eq_eps!(
+            LumaCoefficients::for_space::<linear_spaces::Srgb>().weights(),
+            LumaCoefficients::Rec709.weights(),
+            1e-3
+        );
+        assert_eq_eps!(
+            LumaCoefficients::for_space::<linear_spaces::Bt2020>().weights(),
+            LumaCoefficients::Rec2020.weights(),
+            1e-3
+        );
+        assert_eq_eps!(
+            Color::<Gamma22F32>::from_repr(Vec3::new(1.0, 0.0, 0.0)).luma(),
+            0.2126,
+            1e-3
+        );
+
+        // scene-referred and log encodings weigh by their own primaries
+        let aces = Color::<AcesCgF32>::from_repr(Vec3::ONE);
+        assert_eq_eps!(aces.luma(), 1.0, 1e-3);
+        // a linear encoding's luma is its luminance
+        let green = Color::<AcesCgF32>::from_repr(Vec3::Y);
+        assert_eq_eps!(green.luma(), green.luminance(), 1e-4);
+        let plate = Color::<ArriLogC3>::from_repr(Vec3::splat(0.391));
+        assert_eq_eps!(plate.luma(), 0.391, 1e-3);
+    }
+
+    #[test]
+    fn contrast() {
+        use contrast::*;
+
+        let black = Color::srgb_u8(0, 0, 0);
+        let white = Color::srgb_u8(255, 255, 255);
+        assert_eq_eps!(contrast_ratio_wcag21(black, white), 21.0, 1e-3);
+        assert_eq_eps!(contrast_ratio_wcag21(white, black), 21.0, 1e-3);
+        assert_eq_eps!(contrast_ratio_wcag21(white, white), 1.0, 1e-6);
+        assert_eq_eps!(
+            contrast_ratio_wcag21(Color::srgb_u8(0x76, 0x76, 0x76), white),
+            4.54,
+            0.01
+        );
+
+        // reference values from the APCA-W3 test suite
+        let apca = |text: u32, background: u32| {
+            apca_contrast(
+                Color::<SrgbU8>::from_u32_rgb(text),
+                Color::<SrgbU8>::from_u32_rgb(background),
+            )
+        };
+        assert_eq_eps!(apca(0x888888, 0xffffff), 63.0565, 1e-3);
+        assert_eq_eps!(apca(0xffffff, 0x888888), -68.5415, 1e-3);
+        assert_eq_eps!(apca(0x000000, 0xaaaaaa), 58.1463, 1e-3);
+        assert_eq_eps!(apca(0xaaaaaa, 0x000000), -56.2411, 1e-3);
+        assert_eq_eps!(apca(0x112233, 0xddeeff), 91.6683, 1e-3);
+        assert_eq_eps!(apca(0xddeeff, 0x112233), -93.0677, 1e-3);
+        assert_eq!(apca(0x777777, 0x777777), 0.0);
+
+        let text = Color::srgb_u8(120, 160, 220);
+        let background = Color::srgb_u8(250, 250, 245);
+        let adjusted = adjust_for_contrast(text, background, ContrastTarget::Wcag21(4.5)).unwrap();
+        assert!(contrast_ratio_wcag21(adjusted, background) >= 4.5);
+        assert!(adjusted.l < text.convert::<Oklab>().l);
+        assert_eq_eps!(adjusted.a, text.convert::<Oklab>().a, 1e-6);
+
+        let dark_background = Color::srgb_u8(20, 20, 30);
+        let adjusted =
+            adjust_for_contrast(text, dark_background, ContrastTarget::Apca(75.0)).unwrap();
+        assert!(apca_contrast(adjusted, dark_background).abs() >= 75.0);
+        assert!(adjusted.l > text.convert::<Oklab>().l);
+
+        // already passing colors are left alone, and impossible targets give up
+        let passing = adjust_for_contrast(black, white, ContrastTarget::Wcag21(7.0)).unwrap();
+        assert_eq!(passing, black.convert::<Oklab>());
+        let grey = Color::srgb_u8(128, 128, 128);
+        assert!(adjust_for_contrast(grey, grey, ContrastTarget::Wcag21(8.0)).is_none());
+    }
+
+    #[test]
+    fn color_difference() {
+        use difference::*;
+
+        // the CIEDE2000 test data from Sharma, Wu and Dalal (2005)
+        #[rustfmt::skip]
+        let sharma = [
+        (50.0000, 2.6772, -79.7751, 50.0000, 0.0000, -82.7485, 2.0425),
+        (50.0000, 3.1571, -77.2803, 50.0000, 0.0000, -82.7485, 2.8615),
+        (50.0000, 2.8361, -74.0200, 50.0000, 0.0000, -82.7485, 3.4412),
+        (50.0000, -1.3802, -84.2814, 50.0000, 0.0000, -82.7485, 1.0000),
+        (50.0000, -1.1848, -84.8006, 50.0000, 0.0000, -82.7485, 1.0000),
+        (50.0000, -0.9009, -85.5211, 50.0000, 0.0000, -82.7485, 1.0000),
+        (50.0000, 0.0000, 0.0000, 50.0000, -1.0000, 2.0000, 2.3669),
+        (50.0000, -1.0000, 2.0000, 50.0000, 0.0000, 0.0000, 2.3669),
+        (50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0009, 7.1792),
+        (50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0010, 7.1792),
+        (50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0011, 7.2195),
+        (50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0012, 7.2195),
+        (50.0000, -0.0010, 2.4900, 50.0000, 0.0009, -2.4900, 4.8045),
+        (50.0000, -0.0010, 2.4900, 50.0000, 0.0010, -2.4900, 4.8045),
+        (50.0000, -0.0010, 2.4900, 50.0000, 0.0011, -2.4900, 4.7461),
+        (50.0000, 2.5000, 0.0000, 50.0000, 0.0000, -2.5000, 4.3065),
+        (50.0000, 2.5000, 0.0000, 73.0000, 25.0000, -18.0000, 27.1492),
+        (50.0000, 2.5000, 0.0000, 61.0000, -5.0000, 29.0000, 22.8977),
+        (50.0000, 2.5000, 0.0000, 56.0000, -27.0000, -3.0000, 31.9030),
+        (50.0000, 2.5000, 0.0000, 58.0000, 24.0000, 15.0000, 19.4535),
+        (50.0000, 2.5000, 0.0000, 50.0000, 3.1736, 0.5854, 1.0000),
+        (50.0000, 2.5000, 0.0000, 50.0000, 3.2972, 0.0000, 1.0000),
+        (50.0000, 2.5000, 0.0000, 50.0000, 1.8634, 0.5757, 1.0000),
+        (50.0000, 2.5000, 0.0000, 50.0000, 3.2592, 0.3350, 1.0000),
+        (60.2574, -34.0099, 36.2677, 60.4626, -34.1751, 39.4387, 1.2644),
+        (63.0109, -31.0961, -5.8663, 62.8187, -29.7946, -4.0864, 1.2630),
+        (61.2901, 3.7196, -5.3901, 61.4292, 2.2480, -4.9620, 1.8731),
+        (35.0831, -44.1164, 3.7933, 35.0232, -40.0716, 1.5901, 1.8645),
+        (22.7233, 20.0904, -46.6940, 23.0331, 14.9730, -42.5619, 2.0373),
+        (36.4612, 47.8580, 18.3852, 36.2715, 50.5065, 21.2231, 1.4146),
+        (90.8027, -2.0831, 1.4410, 91.1528, -1.6435, 0.0447, 1.4441),
+        (90.9257, -0.5406, -0.9208, 88.6381, -0.8985, -0.7239, 1.5381),
+        (6.7747, -0.2908, -2.4247, 5.8714, -0.0985, -2.2286, 0.6377),
+        (2.0776, 0.0795, -1.1350, 0.9033, -0.0636, -0.5514, 0.9082),
+        ];
+        for (l1, a1, b1, l2, a2, b2, expected) in sharma {
+            let lab1 = Color::cie_lab(l1, a1, b1);
+            let lab2 = Color::cie_lab(l2, a2, b2);
+            assert_eq_eps!(delta_e_2000(lab1, lab2), expected, 1e-4);
+            assert_eq_eps!(delta_e_2000(lab2, lab1), expected, 1e-4);
+        }
+
+        let reference = Color::cie_lab(50.0, 2.6772, -79.7751);
+        let sample = Color::cie_lab(50.0, 0.0, -82.7485);
+        assert_eq_eps!(delta_e_76(reference, sample), 4.0011, 1e-4);
+        assert_eq_eps!(delta_e_94(reference, sample), 1.3950, 1e-4);
+        let reference = Color::cie_lab(50.0, 2.5, 0.0);
+        let sample = Color::cie_lab(73.0, 25.0, -18.0);
+        assert_eq_eps!(delta_e_94(reference, sample), 34.6892, 1e-3);
+        assert_eq_eps!(delta_e_94(sample, reference), 26.1398, 1e-3);
+
+        // any encoding convertible to the metric's space is accepted
+        let a = Color::srgb_u8(200, 120, 40);
+        let b = Color::linear_srgb(0.5, 0.2, 0.05);
+        assert_eq_eps!(
+            delta_e_76(a, b),
+            delta_e_76(a.convert::<CieLab>(), b.convert::<CieLab>()),
+            1e-6
+        );
+        assert_eq_eps!(
+            delta_e_ok(a, b),
+            a.convert::<Oklab>()
+                .repr
+                .distance(b.convert::<Oklab>().repr),
+            1e-6
+        );
+        assert_eq_eps!(
+            Color::srgb_u8(255, 255, 255).convert::<CieLab>().l,
+            100.0,
+            1e-2
+        );
+
+        for metric in [delta_e_76, delta_e_2000] {
+            assert_eq!(metric(a, a), 0.0);
+        }
+        assert_eq!(delta_e_ok(a, a), 0.0);
+        assert_eq!(delta_e_itp(a, a), 0.0);
+        assert_eq_eps!(delta_e_itp(a, b), delta_e_itp(b, a), 1e-6);
+        // one 8-bit step in a mid grey is around the threshold of visibility
+        let itp = delta_e_itp(Color::srgb_u8(128, 128, 128), Color::srgb_u8(129, 129, 129));
+        assert!(itp > 0.3 && itp < 3.0, "{}", itp);
+
+        let round_trip = a.convert::<ICtCpPq>().convert::<SrgbU8>();
+        assert_eq_eps!(round_trip, a, 1);
+    }
+
+    #[test]
+    fn color_temperature() {
+        use temperature::*;
+
+        // standard illuminants D65 and A
+        let d65 = Color::<CieXyz>::from_xy_luminance(0.31271, 0.32902, 1.0);
+        let (cct, duv) = cct_duv(d65);
+        assert_eq_eps!(cct, 6504.0, 3.0);
+        assert_eq_eps!(duv, 0.0032, 2e-4);
+        let (cct, duv) = cct_duv(Color::<CieXyz>::from_xy_luminance(0.44757, 0.40745, 1.0));
+        assert_eq_eps!(cct, 2856.0, 3.0);
+        assert_eq_eps!(duv, 0.0, 2e-4);
+
+        assert_eq_eps!(daylight_xy(6504.0), glam::Vec2::new(0.3127, 0.3291), 2e-4);
+        assert_eq_eps!(daylight_xy(5003.0), glam::Vec2::new(0.3457, 0.3585), 2e-4);
+        // the Planckian locus approximation is within 9e-5 in uv, which is a little more in xy
+        assert_eq_eps!(planckian_xy(2856.0), glam::Vec2::new(0.4476, 0.4074), 5e-4);
+
+        for kelvin in [1500.0, 2700.0, 4000.0, 6500.0, 10000.0] {
+            let color = Color::<LinearSrgb>::from_temperature(kelvin);
+            assert_eq_eps!(color.luminance(), 1.0, 1e-4);
+            let (cct, duv) = cct_duv(color);
+            assert_eq_eps!(cct / kelvin, 1.0, 1e-3);
+            assert_eq_eps!(duv, 0.0, 1e-4);
+        }
+
+        // warmer temperatures are redder
+        let warm = Color::<LinearSrgb>::from_temperature(2000.0);
+        let cool = Color::<LinearSrgb>::from_temperature(9000.0);
+        assert!(warm.r / warm.b > cool.r / cool.b);
+
+        // green tints are above the locus and magenta tints below it
+        let tinted = |tint: f32| {
+            let mut xyz = Color::<CieXyz>::from_temperature(5000.0);
+            xyz.y += tint;
+            cct_duv(xyz).1
+        };
+        assert!(tinted(0.05) > 0.0);
+        assert!(tinted(-0.05) < 0.0);
+
+        let xy = Color::<CieXyz>::from_xy_luminance(0.3, 0.4, 0.5).xy();
+        assert_eq_eps!(xy, glam::Vec2::new(0.3, 0.4), 1e-6);
+    }
+
+    #[test]
+    fn chromatic_adaptation() {
+        use adaptation::*;
+
+        // the Bradford D65 to D50 matrix, as published by Bruce Lindbloom
+        let expected = glam::Mat3::from_cols_array(&[
+            1.0478112, 0.0295424, -0.0092345, 0.0228866, 0.9904844, 0.0150436, -0.050127,
+            -0.0170491, 0.7521316,
+        ]);
+        let matrix = AdaptationMethod::Bradford.matrix(WhitePoint::D65, WhitePoint::D50);
+        assert!(matrix.abs_diff_eq(expected, 1e-5), "{}", matrix);
+
+        let d65 = Color::cie_xyz(0.95047, 1.0, 1.08883);
+        for method in [
+            AdaptationMethod::VonKries,
+            AdaptationMethod::Bradford,
+            AdaptationMethod::Cat02,
+            AdaptationMethod::Cat16,
+        ] {
+            // whites map onto each other
+            let adapted = d65.adapt(method, WhitePoint::D65, WhitePoint::A);
+            assert_eq_eps!(adapted.repr, Vec3::new(1.0985, 1.0, 0.35585), 1e-4);
+
+            // and adapting to the same white does nothing
+            let color = Color::linear_srgba(0.8, 0.4, 0.1, 0.5);
+            let same = color.adapt(method, WhitePoint::D50, WhitePoint::D50);
+            assert_eq_eps!(same.repr, color.repr, 1e-5);
+
+            let there_and_back = color.adapt(method, WhitePoint::D65, WhitePoint::F11).adapt(
+                method,
+                WhitePoint::F11,
+                WhitePoint::D65,
+            );
+            assert_eq_eps!(there_and_back.repr, color.repr, 1e-4);
+        }
+
+        // xy chromaticities can be used in place of white points
+        let d65_xy = glam::Vec2::new(0.31271, 0.32902);
+        let color = Color::linear_srgb(0.2, 0.5, 0.7);
+        assert_eq_eps!(
+            color
+                .adapt(AdaptationMethod::Cat16, d65_xy, WhitePoint::D50)
+                .repr,
+            color
+                .adapt(AdaptationMethod::Cat16, WhitePoint::D65, WhitePoint::D50)
+                .repr,
+            1e-3
+        );
+
+        // white under a warm light is neutral again once adapted back to D65
+        let tungsten = temperature::planckian_xy(3200.0);
+        let lit_white = Color::<LinearSrgb>::from_temperature(3200.0);
+        let balanced = lit_white.adapt(AdaptationMethod::Bradford, tungsten, WhitePoint::D65);
+        assert_eq_eps!(balanced.repr, Vec3::ONE, 2e-3);
+
+        // premultiplied, f64 and linear `Encoded` colors adapt the same, keeping their alpha
+        use details::encodings::ScRgbAF16Premultiplied;
+        let straight = Color::linear_srgba(0.8, 0.4, 0.1, 0.5);
+        let expected = straight.adapt(AdaptationMethod::Bradford, WhitePoint::D65, WhitePoint::D50);
+        let premultiplied = straight.convert::<LinearSrgbAPremultiplied>().adapt(
+            AdaptationMethod::Bradford,
+            WhitePoint::D65,
+            WhitePoint::D50,
+        );
+        assert_eq!(premultiplied.a, 0.5);
+        assert_eq_eps!(
+            premultiplied.convert::<LinearSrgbA>().repr,
+            expected.repr,
+            1e-5
+        );
+        let f64 =
+            straight
+                .to_f64()
+                .adapt(AdaptationMethod::Bradford, WhitePoint::D65, WhitePoint::D50);
+        assert_eq!(f64.a, 0.5);
+        assert_eq_eps!(f64.to_f32().repr, expected.repr, 1e-5);
+        let scrgb = straight.convert::<ScRgbAF16Premultiplied>().adapt(
+            AdaptationMethod::Bradford,
+            WhitePoint::D65,
+            WhitePoint::D50,
+        );
+        assert_eq_eps!(scrgb.convert::<LinearSrgbA>().repr, expected.repr, 1e-3);
+    }
+
+    #[test]
+    fn custom_linear_spaces() {
+        use details::linear_spaces::{AcesCg, Bt2020, CieXYZ, Srgb};
+        use details::traits::{
+            ColorEncoding, ConvertFrom, DisplayReferral, LinearConvertFromRaw, Referred,
+        };
+
+        linear_color_space! {
+            struct SrgbAgain {
+                primaries: [[0.64, 0.33], [0.30, 0.60], [0.15, 0.06]],
+                white_point: [0.3127, 0.3290],
+            }
+        }
+
+        linear_color_space! {
+            struct Ap1Again {
+                primaries: [[0.713, 0.293], [0.165, 0.830], [0.128, 0.044]],
+                white_point: [0.32168, 0.33767],
+            }
+        }
+
+        // the built-in spaces use white points given as XYZ values, which differ slightly from the xy ones
+        let color = Vec3::new(0.2, 0.5, 0.8);
+        let mut custom = color;
+        <CieXYZ as LinearConvertFromRaw<SrgbAgain>>::linear_part_raw(&mut custom);
+        let mut builtin = color;
+        <CieXYZ as LinearConvertFromRaw<Srgb>>::linear_part_raw(&mut builtin);
+        assert_eq_eps!(custom, builtin, 1e-3);
+
+        let mut aces = color;
+        <AcesCg as LinearConvertFromRaw<Ap1Again>>::linear_part_raw(&mut aces);
+        assert_eq_eps!(aces, color, 1e-3);
+        <Ap1Again as LinearConvertFromRaw<AcesCg>>::linear_part_raw(&mut aces);
+        assert_eq_eps!(aces, color, 1e-3);
+
+        let mut wide = color.as_dvec3();
+        <Bt2020 as LinearConvertFromRaw<SrgbAgain>>::linear_part_raw_f64(&mut wide);
+        <SrgbAgain as LinearConvertFromRaw<Bt2020>>::linear_part_raw_f64(&mut wide);
+        assert!(wide.abs_diff_eq(color.as_dvec3(), 1e-12));
+
+        struct LinearCamera;
+
+        impl ColorEncoding for LinearCamera {
+            type Repr = Vec3;
+            type ComponentStruct = details::component_structs::Rgb<f32>;
+            type LinearSpace = Ap1Again;
+            const NAME: &'static str = "LinearCamera";
+
+            fn src_transform_raw(repr: Vec3) -> (Vec3, f32) {
+                (repr, 1.0)
+            }
+
+            fn dst_transform_raw(raw: Vec3, _: f32) -> Vec3 {
+                raw
+            }
+        }
+
+        impl Referred for LinearCamera {
+            type Referral = DisplayReferral;
+        }
+
+        impl ConvertFrom<LinearSrgb> for LinearCamera {}
+        impl ConvertFrom<LinearCamera> for LinearSrgb {}
+
+        let srgb = Color::linear_srgb(0.8, 0.3, 0.1);
+        let camera = srgb.convert::<LinearCamera>();
+        assert_eq_eps!(camera.convert::<LinearSrgb>(), srgb, 1e-5);
+    }
+
+    #[test]
+    fn custom_encodings() {
+        use details::reprs::{F32ARepr, F32Repr};
+        use details::traits::ColorEncoding;
+
+        fn cmy_to_linear(repr: Vec3) -> (Vec3, f32) {
+            SrgbF32::src_transform_raw(Vec3::ONE - repr)
+        }
+
+        fn linear_to_cmy(raw: Vec3, alpha: f32) -> Vec3 {
+            Vec3::ONE - SrgbF32::dst_transform_raw(raw, alpha)
+        }
+
+        color_encoding! {
+            struct Cmy {
+                repr: F32Repr,
+                components: struct CmyComponents { c, m, y },
+                linear_space: details::linear_spaces::Srgb,
+                referred: display,
+                src_transform: cmy_to_linear,
+                dst_transform: linear_to_cmy,
+            }
+        }
+
+        let red = Color::srgb_u8(255, 0, 0).convert::<Cmy>();
+        assert_eq_eps!(red.repr, Vec3::new(0.0, 1.0, 1.0), 1e-6);
+        assert_eq!(red.m, 1.0);
+        assert_eq_eps!(red.convert::<SrgbF32>().repr, Vec3::X, 1e-6);
+        assert_eq!(red.convert::<SceneLinearSrgb>().repr, Vec3::X);
+        #[cfg(feature = "std")]
+        assert_eq!(
+            format!("{}", red),
+            "Color<Cmy>(c: 0.000, m: 1.000, y: 1.000)"
+        );
+
+        color_encoding! {
+            struct SceneLinearP3A {
+                repr: F32ARepr,
+                components: details::component_structs::RgbA<f32>,
+                linear_space: details::linear_spaces::DisplayP3,
+                referred: scene,
+                src_transform: |repr: glam::Vec4| (repr.truncate(), repr.w),
+                dst_transform: |raw: Vec3, alpha| raw.extend(alpha),
+            }
+        }
+
+        let srgb = Color::linear_srgba(0.8, 0.3, 0.1, 0.5);
+        let p3 = srgb.convert::<SceneLinearP3A>();
+        assert_eq!(p3.a, 0.5);
+        assert_eq_eps!(p3.convert::<SceneLinearSrgbA>().repr, srgb.repr, 1e-5);
+
+        // f64 and `Encoded` encodings are covered too
+        assert_eq_eps!(red.convert::<SrgbF64>().repr, glam::DVec3::X, 1e-6);
+        let adobe = red.convert::<AdobeRgbF32>();
+        assert_eq_eps!(adobe.convert::<Cmy>().repr, red.repr, 1e-5);
+        let plate = red.convert::<ArriLogC3>();
+        assert_eq_eps!(
+            plate.convert::<SceneLinearP3A>().repr.truncate(),
+            red.convert::<SceneLinearSrgb>()
+                .convert::<SceneLinearP3A>()
+                .repr
+                .truncate(),
+            1e-4
+        );
+    }
+
+    #[test]
+    fn color_encoding_covers_every_encoding() {
+        // `color_encoding!` lists the built-in encodings by hand, so check that every encoding declared in
+        // `encodings.rs` appears in the list of its `@display` arm.
+        let encodings = include_str!("details/encodings.rs");
+        let macros = include_str!("macros.rs");
+        let start = macros.find("(@display $name:ident) => {").unwrap();
+        let end = start + macros[start..].find("(@scene $name:ident) => {").unwrap();
+        let listed = &macros[start..end];
+        let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+        let mut count = 0;
+        for line in encodings.lines() {
+            let Some(name) = line
+                .strip_prefix("impl ColorEncoding for ")
+                .and_then(|rest| rest.strip_suffix(" {"))
+            else {
+                continue;
+            };
+            let found = listed.match_indices(name).any(|(i, _)| {
+                !listed[..i].ends_with(is_ident) && !listed[i + name.len()..].starts_with(is_ident)
+            });
+            assert!(found, "{} is missing from color_encoding!", name);
+            count += 1;
+        }
+        assert_eq!(count, 21);
+    }
+
+    #[test]
+    fn transfer_functions() {
+        use details::encodings::{Encoded, NoAlpha, PremultipliedAlpha};
+        use details::linear_spaces::{self, AcesCg, DisplayP3};
+        use details::reprs::{F32ARepr, F32Repr, U16Repr, U8ARepr, U8Repr};
+        use details::traits::TransferFunction;
+        use details::transfer_functions::*;
+
+        fn round_trip<Tf: TransferFunction>(linear: Vec3, eps: f32) {
+            assert_eq_eps!(Tf::decode(Tf::encode(linear)), linear, eps);
+        }
+
+        let values = Vec3::new(0.001, 0.18, 0.9);
+        round_trip::<Linear>(values, 0.0);
+        round_trip::<Srgb>(values, 1e-6);
+        round_trip::<Gamma22>(values, 1e-6);
+        round_trip::<Gamma24>(values, 1e-6);
+        round_trip::<Bt1886>(values, 1e-6);
+        round_trip::<Pq>(values * 10.0, 1e-3);
+        round_trip::<Hlg>(values, 1e-6);
+        round_trip::<AcesCct>(values * 100.0, 1e-3);
+        round_trip::<AcesCct>(Vec3::new(-0.01, 0.0, 0.005), 1e-6);
+        round_trip::<Log>(values, 1e-5);
+        round_trip::<Log<-4, 4>>(values.max(Vec3::splat(0.18 / 16.0)), 1e-5);
+
+        assert_eq_eps!(Pq::encode(Vec3::ONE).x, 0.5081, 1e-4);
+        assert_eq_eps!(Pq::decode(Vec3::ONE).x, 100.0, 1e-2);
+        assert_eq_eps!(Hlg::encode(Vec3::splat(1.0 / 12.0)).x, 0.5, 1e-6);
+        assert_eq_eps!(Hlg::encode(Vec3::ONE).x, 1.0, 1e-6);
+        assert_eq_eps!(AcesCct::encode(Vec3::splat(0.18)).x, 0.4136, 1e-4);
+        assert_eq_eps!(Log::<-10, 6>::encode(Vec3::splat(0.18)).x, 0.625, 1e-6);
+        assert_eq!(Log::<-10, 6>::encode(Vec3::ZERO).x, 0.0);
+        assert_eq_eps!(Gamma22::decode(Vec3::splat(0.5)).x, 0.2176, 1e-4);
+
+        // the generic encodings match the dedicated ones
+        let srgb = Color::srgba_u8(200, 100, 50, 128);
+        let generic = srgb.convert::<Encoded<
+            linear_spaces::Srgb,
+            Srgb,
+            U8ARepr,
+            details::encodings::SeparateAlpha,
+        >>();
+        assert_eq!(
+            generic.repr,
+            srgb.convert::<LinearSrgbA>().convert::<SrgbAU8>().repr
+        );
+        let premultiplied =
+            srgb.convert::<Encoded<linear_spaces::Srgb, Srgb, U8ARepr, PremultipliedAlpha>>();
+        assert_eq!(
+            premultiplied.repr,
+            srgb.convert::<SrgbAU8Premultiplied>().repr
+        );
+        let linear =
+            srgb.convert::<Encoded<linear_spaces::Srgb, Linear, F32ARepr, PremultipliedAlpha>>();
+        assert_eq_eps!(
+            linear.repr,
+            srgb.convert::<LinearSrgbAPremultiplied>().repr,
+            1e-6
+        );
+
+        let p3 = Color::srgb_u8(255, 0, 0).convert::<Encoded<DisplayP3, Gamma22, U16Repr>>();
+        assert_eq!(p3.r, 59964);
+        assert_eq_eps!(p3.convert::<SrgbF32>().repr, Vec3::X, 1e-4);
+        let narrow: Color<Encoded<DisplayP3, Srgb, U8Repr, NoAlpha>> =
+            p3.convert::<CieXyz>().convert();
+        assert_eq!(narrow.repr, [233, 51, 35]);
+
+        // scene-referred transfer functions convert like the scene-referred encodings
+        let cct =
+            Color::scene_linear_srgb(4.0, 0.18, 0.0).convert::<Encoded<AcesCg, AcesCct, F32Repr>>();
+        assert_eq_eps!(
+            cct.convert::<SceneLinearSrgb>().repr,
+            Vec3::new(4.0, 0.18, 0.0),
+            1e-4
+        );
+
+        // each instantiation is named after its space and transfer function
+        use details::traits::ColorEncoding;
+        assert_eq!(
+            <Encoded<DisplayP3, Gamma22, U16Repr> as ColorEncoding>::NAME,
+            "Encoded<DisplayP3, Gamma22>"
+        );
+        assert_eq!(ArriLogC3::NAME, "Encoded<ArriWideGamut3, LogC3>");
+        assert_eq!(AdobeRgbU8::NAME, "Encoded<AdobeRgb, AdobeRgb>");
+    }
+
+    #[test]
+    fn display_gammas() {
+        use details::encodings::{Bt1886F32, Bt1886U8, Gamma22F32, Gamma22U8, Gamma24U8};
+        use details::traits::TransferFunction;
+        use details::transfer_functions::{Bt1886, Gamma24};
+
+        let grey = Color::srgb_u8(128, 128, 128);
+        assert_eq!(grey.convert::<Gamma22U8>().repr, [127, 127, 127]);
+        assert_eq!(grey.convert::<Gamma24U8>().repr, [134, 134, 134]);
+        assert_eq!(
+            grey.convert::<Bt1886U8>().repr,
+            grey.convert::<Gamma24U8>().repr
+        );
+        assert_eq_eps!(
+            grey.convert::<Gamma22F32>().convert::<SrgbF32>().repr,
+            grey.convert::<SrgbF32>().repr,
+            1e-5
+        );
+
+        let values = Vec3::new(0.0, 0.3, 1.0);
+        assert_eq!(
+            Bt1886::<0, 100_000>::decode(values),
+            Gamma24::decode(values)
+        );
+
+        // a display with a 0.1 cd/m² black and a 100 cd/m² white
+        type Lifted = Bt1886<100, 100_000>;
+        assert_eq_eps!(Lifted::decode(Vec3::ZERO).x, 0.001, 1e-6);
+        assert_eq_eps!(Lifted::decode(Vec3::splat(0.5)).x, 0.2160, 1e-4);
+        assert_eq_eps!(Lifted::decode(Vec3::ONE).x, 1.0, 1e-6);
+        assert_eq_eps!(
+            Lifted::decode(Lifted::encode(values)),
+            values.max(Vec3::splat(0.001)),
+            1e-5
+        );
+        // only the ratio of the luminances matters
+        assert_eq_eps!(
+            Bt1886::<1, 1000>::encode(values),
+            Lifted::encode(values),
+            1e-6
+        );
+
+        let lifted = Color::linear_srgb(0.5, 0.2, 0.0).convert::<Bt1886F32<100, 100_000>>();
+        assert!(lifted.b < 0.0);
+        assert_eq_eps!(
+            lifted.convert::<LinearSrgb>().repr,
+            Vec3::new(0.5, 0.2, 0.001),
+            1e-5
+        );
+
+        assert_eq_eps!(grey.convert::<Gamma24U8>().luma(), 134.0 / 255.0, 1e-6);
+    }
+
+    #[test]
+    fn wide_gamut_photo_encodings() {
+        use details::encodings::{
+            AdobeRgbF32, AdobeRgbU16, AdobeRgbU8, ProPhotoRgbF32, ProPhotoRgbU16, ProPhotoRgbU8,
+        };
+
+        // reference values from Bruce Lindbloom's calculator, which uses Bradford adaptation for ProPhoto
+        let green = Color::srgb_u8(0, 255, 0).convert::<AdobeRgbF32>();
+        assert_eq_eps!(green.repr, Vec3::new(0.5649, 1.0, 0.2348), 2e-3);
+        let red = Color::srgb_u8(255, 0, 0).convert::<ProPhotoRgbF32>();
+        assert_eq_eps!(red.repr, Vec3::new(0.7022, 0.2757, 0.1036), 5e-3);
+
+        let white = Color::srgb_u8(255, 255, 255);
+        assert_eq_eps!(white.convert::<ProPhotoRgbF32>().repr, Vec3::ONE, 1e-4);
+        assert_eq!(white.convert::<AdobeRgbU8>().repr, [255, 255, 255]);
+
+        let color = Color::srgb_f32(0.8, 0.4, 0.1);
+        assert_eq_eps!(
+            color.convert::<AdobeRgbU16>().convert::<SrgbF32>().repr,
+            color.repr,
+            1e-4
+        );
+        assert_eq_eps!(
+            color.convert::<ProPhotoRgbU16>().convert::<Oklab>().repr,
+            color.convert::<Oklab>().repr,
+            1e-4
+        );
+        assert_eq_eps!(
+            color.convert::<ProPhotoRgbU8>().convert::<SrgbF32>().repr,
+            color.repr,
+            2e-2
+        );
+
+        // the linear toe of the ProPhoto curve
+        let dark = Color::linear_srgb(0.001, 0.001, 0.001).convert::<ProPhotoRgbF32>();
+        assert_eq_eps!(dark.repr, Vec3::splat(0.016), 1e-4);
+
+        let f32_mat = kolor::details::generated_matrices::PRO_PHOTO_D50_TO_ADOBE_1998_D65;
+        let f64_mat = matrices_f64::PRO_PHOTO_D50_TO_ADOBE_1998_D65;
+        assert!(f64_mat.abs_diff_eq(f32_mat.as_dmat3(), 1e-6));
+    }
+
+    #[test]
+    fn camera_log_encodings() {
+        use details::encodings::{
+            AcesCctF32, AcesCgF32, ArriLogC3, ArriLogC4, CanonLogCinemaGamut, RedLog3G10,
+            SonySLog3SGamut3, SonySLog3SGamut3Cine,
+        };
+        use details::traits::TransferFunction;
+        use details::transfer_functions::{CanonLog, Log3G10, LogC3, LogC4, SLog3};
+
+        // published code values for 18% grey
+        let grey = Vec3::splat(0.18);
+        assert_eq_eps!(LogC3::encode(grey), Vec3::splat(0.391), 1e-3);
+        assert_eq_eps!(LogC4::encode(grey), Vec3::splat(0.2784), 1e-3);
+        assert_eq_eps!(SLog3::encode(grey), Vec3::splat(0.4106), 1e-3);
+        assert_eq_eps!(CanonLog::encode(grey), Vec3::splat(0.3434), 1e-3);
+        assert_eq_eps!(Log3G10::encode(grey), Vec3::splat(0.3333), 1e-3);
+
+        let values = Vec3::new(0.002, 0.18, 4.0);
+        assert_eq_eps!(LogC3::decode(LogC3::encode(values)), values, 1e-4);
+        assert_eq_eps!(LogC4::decode(LogC4::encode(values)), values, 1e-4);
+        assert_eq_eps!(SLog3::decode(SLog3::encode(values)), values, 1e-4);
+        assert_eq_eps!(CanonLog::decode(CanonLog::encode(values)), values, 1e-4);
+        assert_eq_eps!(Log3G10::decode(Log3G10::encode(values)), values, 1e-4);
+
+        // grey stays neutral on its way into ACEScg
+        let plate = Color::<ArriLogC3>::from_repr(Vec3::splat(0.391));
+        assert_eq_eps!(plate.convert::<AcesCgF32>().repr, grey, 1e-3);
+        let plate = Color::<ArriLogC4>::from_repr(Vec3::splat(0.2784));
+        assert_eq_eps!(plate.convert::<AcesCgF32>().repr, grey, 1e-3);
+        let plate = Color::<SonySLog3SGamut3Cine>::from_repr(Vec3::splat(0.4106));
+        assert_eq_eps!(plate.convert::<AcesCgF32>().repr, grey, 1e-3);
+        let plate = Color::<RedLog3G10>::from_repr(Vec3::splat(0.3333));
+        assert_eq_eps!(plate.convert::<AcesCgF32>().repr, grey, 1e-3);
+
+        let color = Color::aces_cg(0.8, 0.3, 0.05);
+        assert_eq_eps!(
+            color.convert::<ArriLogC4>().convert::<AcesCgF32>().repr,
+            color.repr,
+            1e-4
+        );
+        assert_eq_eps!(
+            color
+                .convert::<SonySLog3SGamut3>()
+                .convert::<AcesCgF32>()
+                .repr,
+            color.repr,
+            1e-4
+        );
+        assert_eq_eps!(
+            color
+                .convert::<CanonLogCinemaGamut>()
+                .convert::<AcesCgF32>()
+                .repr,
+            color.repr,
+            1e-4
+        );
+        assert_eq_eps!(
+            color.convert::<AcesCctF32>().convert::<AcesCgF32>().repr,
+            color.repr,
+            1e-4
+        );
+        assert_eq_eps!(
+            color
+                .convert::<SceneLinearSrgb>()
+                .convert::<AcesCgF32>()
+                .repr,
+            color.repr,
+            1e-4
+        );
+
+        let red = Color::srgb_u8(255, 0, 0).convert::<AcesCgF32>();
+        assert_eq_eps!(red.repr, Vec3::new(0.6131, 0.0701, 0.0206), 2e-3);
+    }
+
+    #[test]
+    fn extended_range_encodings() {
+        use details::encodings::{
+            ExtendedSrgbAF32, ExtendedSrgbF32, ScRgbAF16, ScRgbAF16Premultiplied, ScRgbF16,
+        };
+        use details::reprs::F16;
+
+        // exact, rounded, subnormal and out of range half floats
+        assert_eq!(F16::from_f32(1.0), F16::ONE);
+        assert_eq!(F16::from_f32(-2.0).0, 0xc000);
+        assert_eq!(F16::from_f32(65504.0).0, 0x7bff);
+        assert_eq!(F16::from_f32(1.0e6).0, 0x7c00);
+        assert_eq!(F16::from_f32(1.0 + 3.0 / 4096.0).0, 0x3c01);
+        assert_eq!(F16::from_f32(1.0 + 1.0 / 2048.0), F16::ONE);
+        assert_eq!(F16::from_f32(1.0 + 3.0 / 2048.0).0, 0x3c02);
+        assert_eq!(F16::from_f32(5.9604645e-8).0, 0x0001);
+        assert_eq!(F16(0x0001).to_f32(), 5.9604645e-8);
+        assert_eq!(F16(0x3555).to_f32(), 0.33325195);
+        assert!(F16::from_f32(f32::NAN).to_f32().is_nan());
+
+        // the mirrored curve agrees with sRGB within 0..1 and doesn't clamp outside it
+        let color = Color::srgb_f32(0.2, 0.5, 0.9);
+        assert_eq_eps!(color.convert::<ExtendedSrgbF32>().repr, color.repr, 1e-5);
+        let wide = Color::linear_srgb(2.0, -0.5, 0.002);
+        let extended = wide.convert::<ExtendedSrgbF32>();
+        assert_eq_eps!(extended.repr.y, -0.735357, 1e-5);
+        assert!(extended.r > 1.0);
+        assert_eq_eps!(extended.convert::<LinearSrgb>(), wide, 1e-5);
+        assert_eq!(wide.convert::<SrgbU8>().repr, [255, 0, 6]);
+
+        // display-p3 red is out of the sRGB gamut, but survives the trip through scRGB
+        let p3_red = Color::<CieXyz>::from_repr(Vec3::new(0.48657, 0.22897, 0.0));
+        let sc = p3_red.convert::<ScRgbF16>();
+        assert!(sc.g.to_f32() < 0.0);
+        assert_eq_eps!(sc.convert::<CieXyz>(), p3_red, 1e-3);
+
+        let hdr = Color::linear_srgba(10.0, 0.5, 0.0, 0.5);
+        let sc = hdr.convert::<ScRgbAF16>();
+        assert_eq!(sc.r, F16::from_f32(10.0));
+        assert_eq!(sc.a, F16::from_f32(0.5));
+        let premul = hdr.convert::<ScRgbAF16Premultiplied>();
+        assert_eq!(premul.r, F16::from_f32(5.0));
+        assert_eq_eps!(premul.convert::<LinearSrgbA>(), hdr, 1e-3);
+        assert_eq_eps!(
+            hdr.convert::<ExtendedSrgbAF32>().convert::<LinearSrgbA>(),
+            hdr,
+            1e-4
+        );
+    }
+
+    #[test]
+    fn saturate() {
+        use details::encodings::{
+            AcesCgF32, ArriLogC3, ExtendedSrgbF32, Gamma22F32, LinearSrgbF64, OklabF64, ScRgbAF16,
+            SrgbAF64,
+        };
+        use details::reprs::F16;
+        use details::traits::TransferFunction;
+        use details::transfer_functions::LogC3;
+        use glam::{DVec3, DVec4, Vec4};
+
+        assert_eq!(Color::srgb_u8(0, 128, 255).saturate().repr, [0, 128, 255]);
+        assert_eq!(
+            Color::srgba_u8(1, 2, 3, 4).saturate().repr,
+            Color::srgba_u8(1, 2, 3, 4).repr
+        );
+
+        let out_of_range = Vec3::new(-0.5, 0.5, 1.5);
+        let clamped = Vec3::new(0.0, 0.5, 1.0);
+        assert_eq!(
+            Color::<SrgbF32>::from_repr(out_of_range).saturate().repr,
+            clamped
+        );
+        assert_eq!(
+            Color::<LinearSrgb>::from_repr(out_of_range).saturate().repr,
+            clamped
+        );
+        assert_eq!(
+            Color::srgba_f32(-0.5, 0.5, 1.5, 2.0).saturate().repr,
+            Vec4::new(0.0, 0.5, 1.0, 1.0)
+        );
+        assert_eq!(
+            Color::linear_srgba(1.5, -0.5, 0.5, -1.0).saturate().repr,
+            Vec4::new(1.0, 0.0, 0.5, 0.0)
+        );
+        assert_eq!(
+            Color::linear_srgba_premultiplied(0.8, -0.1, 0.2, 0.5)
+                .saturate()
+                .repr,
+            Vec4::new(0.5, 0.0, 0.2, 0.5)
+        );
+
+        // only lightness is clamped for perceptual encodings
+        assert_eq!(
+            Color::oklab(1.2, -0.4, 0.3).saturate().repr,
+            Vec3::new(1.0, -0.4, 0.3)
+        );
+        assert_eq!(
+            Color::<CieLab>::from_repr(Vec3::new(-3.0, 120.0, -90.0))
+                .saturate()
+                .repr,
+            Vec3::new(0.0, 120.0, -90.0)
+        );
+        assert_eq!(
+            Color::<ICtCpPq>::from_repr(Vec3::new(1.1, -0.6, 0.6))
+                .saturate()
+                .repr,
+            Vec3::new(1.0, -0.6, 0.6)
+        );
+
+        // scene-referred values are only bounded below
+        let hdr = Vec3::new(-0.1, 0.18, 40.0);
+        let non_negative = Vec3::new(0.0, 0.18, 40.0);
+        assert_eq!(
+            Color::scene_linear_srgb(-0.1, 0.18, 40.0).saturate().repr,
+            non_negative
+        );
+        assert_eq!(
+            Color::<AcesCgF32>::from_repr(hdr).saturate().repr,
+            non_negative
+        );
+        assert_eq!(
+            Color::<CieXyz>::from_repr(hdr).saturate().repr,
+            non_negative
+        );
+        assert_eq!(
+            Color::scene_linear_srgba(-0.1, 0.18, 40.0, 1.5)
+                .saturate()
+                .repr,
+            non_negative.extend(1.0)
+        );
+        let log = Color::<ArriLogC3>::from_repr(Vec3::new(0.0, 0.391, 1.2)).saturate();
+        assert_eq_eps!(LogC3::decode(log.repr).x, 0.0, 1e-6);
+        assert_eq!((log.repr.y, log.repr.z), (0.391, 1.2));
+
+        // encoded display values are clamped, extended-range ones are left alone
+        assert_eq!(
+            Color::<Gamma22F32>::from_repr(out_of_range).saturate().repr,
+            clamped
+        );
+        assert_eq!(
+            Color::<ExtendedSrgbF32>::from_repr(out_of_range)
+                .saturate()
+                .repr,
+            out_of_range
+        );
+        let sc = Color::linear_srgba(4.0, -0.25, 0.5, 1.0).convert::<ScRgbAF16>();
+        assert_eq!(sc.saturate().repr, sc.repr);
+        let alpha =
+            Color::<ScRgbAF16>::from_repr([F16::ONE, F16::ONE, F16::ONE, F16::from_f32(2.0)]);
+        assert_eq!(alpha.saturate().a, F16::ONE);
+
+        assert_eq!(
+            Color::<LinearSrgbF64>::from_repr(DVec3::new(-0.5, 0.5, 1.5))
+                .saturate()
+                .repr,
+            DVec3::new(0.0, 0.5, 1.0)
+        );
+        assert_eq!(
+            Color::<SrgbAF64>::from_repr(DVec4::new(2.0, 0.5, -1.0, 1.5))
+                .saturate()
+                .repr,
+            DVec4::new(1.0, 0.5, 0.0, 1.0)
+        );
+        assert_eq!(
+            Color::<OklabF64>::from_repr(DVec3::new(-0.1, 0.2, -0.2))
+                .saturate()
+                .repr,
+            DVec3::new(0.0, 0.2, -0.2)
+        );
+    }
+
+    #[test]
+    fn gamut_mapping() {
+        use details::encodings::{Encoded, ExtendedSrgbF32, ProPhotoRgbF32, SrgbAF32};
+        use details::reprs::F32Repr;
+        use gamut::GamutMapping;
+        use glam::Vec4;
+
+        type DisplayP3 = Encoded<linear_spaces::DisplayP3, transfer_functions::Srgb, F32Repr>;
+
+        let p3_green = Color::<DisplayP3>::from_repr(Vec3::new(0.0, 1.0, 0.0));
+        assert!(p3_green.is_in_gamut::<DisplayP3>());
+        assert!(!p3_green.is_in_gamut::<SrgbU8>());
+        assert!(p3_green.is_in_gamut::<ProPhotoRgbF32>());
+        for color in [
+            [255, 0, 0],
+            [0, 255, 0],
+            [0, 0, 255],
+            [255, 255, 255],
+            [0, 0, 0],
+        ] {
+            let color = Color::srgb_u8(color[0], color[1], color[2]);
+            assert!(color.is_in_gamut::<SrgbU8>());
+            assert!(color.is_in_gamut::<DisplayP3>());
+        }
+        assert!(!Color::scene_linear_srgb(2.0, 1.0, 1.0).is_in_gamut::<LinearSrgb>());
+
+        // white is in the gamut of XYZ-based encodings, even though its X and Z aren't within 0..1
+        use details::encodings::ScRgbF16;
+        let white = Color::srgb_u8(255, 255, 255);
+        assert!(white.convert::<CieXyz>().repr.z > 1.0);
+        assert!(white.is_in_gamut::<CieXyz>());
+        assert!(white.is_in_gamut::<Oklab>());
+        assert_eq_eps!(
+            white.gamut_map::<CieXyz>(GamutMapping::Clip),
+            white.convert::<CieXyz>(),
+            1e-6
+        );
+        assert!(!Color::cie_xyz(-0.1, 0.5, 0.5).is_in_gamut::<CieXyz>());
+        // ICtCp reaches far beyond SDR white
+        let bright = Color::scene_linear_srgb(50.0, 50.0, 50.0);
+        assert!(bright.is_in_gamut::<ICtCpPq>());
+        assert!(!Color::scene_linear_srgb(200.0, 200.0, 200.0).is_in_gamut::<ICtCpPq>());
+
+        // extended-range encodings keep values outside of 0..1
+        let hdr = Color::scene_linear_srgb(2.0, -0.25, 0.5);
+        assert!(hdr.is_in_gamut::<ScRgbF16>());
+        assert!(hdr.is_in_gamut::<ExtendedSrgbF32>());
+        for method in [
+            GamutMapping::Clip,
+            GamutMapping::OklchChroma,
+            GamutMapping::ACES_REFERENCE,
+        ] {
+            let mapped = hdr.gamut_map::<ExtendedSrgbF32>(method);
+            assert_eq_eps!(
+                mapped.convert::<LinearSrgb>().repr,
+                Vec3::new(2.0, -0.25, 0.5),
+                1e-5
+            );
+        }
+        let scrgb = hdr.gamut_map::<ScRgbF16>(GamutMapping::Clip);
+        assert_eq_eps!(
+            scrgb.convert::<LinearSrgb>().repr,
+            Vec3::new(2.0, -0.25, 0.5),
+            1e-3
+        );
+
+        // in-gamut colors pass through every method
+        let inside = Color::<DisplayP3>::from_repr(Vec3::new(0.5, 0.4, 0.3));
+        for method in [
+            GamutMapping::Clip,
+            GamutMapping::OklchChroma,
+            GamutMapping::ACES_REFERENCE,
+        ] {
+            assert_eq_eps!(
+                inside.gamut_map::<SrgbF32>(method),
+                inside.convert::<SrgbF32>(),
+                1e-5
+            );
+            let mapped = p3_green.gamut_map::<SrgbF32>(method);
+            assert!(mapped.is_in_gamut::<SrgbF32>());
+        }
+
+        assert_eq!(
+            p3_green.gamut_map::<LinearSrgb>(GamutMapping::Clip).repr,
+            Vec3::new(0.0, 1.0, 0.0)
+        );
+
+        // chroma reduction keeps lightness and hue
+        let origin = p3_green.convert::<Oklab>().repr;
+        let mapped = p3_green
+            .gamut_map::<SrgbF32>(GamutMapping::OklchChroma)
+            .convert::<Oklab>()
+            .repr;
+        assert_eq_eps!(mapped.x, origin.x, 0.02);
+        assert_eq_eps!(mapped.z.atan2(mapped.y), origin.z.atan2(origin.y), 0.05);
+        assert!(mapped.truncate().length() < origin.truncate().length());
+        assert_eq_eps!(
+            Color::linear_srgb(2.0, 1.5, 1.8)
+                .gamut_map::<SrgbF32>(GamutMapping::OklchChroma)
+                .repr,
+            Vec3::ONE,
+            1e-6
+        );
+
+        // a distance of the limit from the achromatic axis is compressed onto the gamut boundary
+        let negative = Color::linear_srgb(1.0, 0.5, 1.0 - 1.312);
+        let compressed = negative.gamut_map::<LinearSrgb>(GamutMapping::ACES_REFERENCE);
+        assert_eq_eps!(compressed.repr, Vec3::new(1.0, 0.5, 0.0), 1e-4);
+        let near_boundary = Color::linear_srgb(1.0, 0.5, 0.1);
+        let compressed = near_boundary.gamut_map::<LinearSrgb>(GamutMapping::ACES_REFERENCE);
+        assert!(compressed.b > 0.1 && compressed.r == 1.0 && compressed.g == 0.5);
+
+        // scene-referred colors can be mapped to display encodings, and alpha is kept
+        assert_eq!(
+            Color::scene_linear_srgb(4.0, 2.0, 0.5)
+                .gamut_map::<SrgbU8>(GamutMapping::Clip)
+                .repr,
+            Color::linear_srgb(1.0, 1.0, 0.5).convert::<SrgbU8>().repr
+        );
+        let translucent = Color::linear_srgba(1.2, 0.2, -0.1, 0.25);
+        let mapped = translucent.gamut_map::<SrgbAF32>(GamutMapping::OklchChroma);
+        assert!(mapped.is_in_gamut::<SrgbAF32>());
+        assert_eq!(mapped.repr.w, 0.25);
+        assert_ne!(mapped.repr, Vec4::new(1.0, 0.2, 0.0, 0.25));
+    }
+
+    #[test]
+    fn porter_duff() {
+        use details::encodings::{
+            Encoded, ExtendedSrgbAF32, LinearSrgbAF64, LinearSrgbAPremultipliedF64,
+            ScRgbAF16Premultiplied, SeparateAlpha,
+        };
+        use details::reprs::F32ARepr;
+        use details::traits::PorterDuff;
+        use glam::{DVec4, Vec4};
+
+        let src = Color::linear_srgba_premultiplied(0.5, 0.0, 0.0, 0.5);
+        let dst = Color::linear_srgba_premultiplied(0.0, 0.25, 0.0, 0.25);
+        let expected = [
+            (PorterDuff::Clear, Vec4::ZERO),
+            (PorterDuff::Source, src.repr),
+            (PorterDuff::Destination, dst.repr),
+            (PorterDuff::SourceOver, Vec4::new(0.5, 0.125, 0.0, 0.625)),
+            (
+                PorterDuff::DestinationOver,
+                Vec4::new(0.375, 0.25, 0.0, 0.625),
+            ),
+            (PorterDuff::SourceIn, Vec4::new(0.125, 0.0, 0.0, 0.125)),
+            (PorterDuff::DestinationIn, Vec4::new(0.0, 0.125, 0.0, 0.125)),
+            (PorterDuff::SourceOut, Vec4::new(0.375, 0.0, 0.0, 0.375)),
+            (
+                PorterDuff::DestinationOut,
+                Vec4::new(0.0, 0.125, 0.0, 0.125),
+            ),
+            (PorterDuff::SourceAtop, Vec4::new(0.125, 0.125, 0.0, 0.25)),
+            (
+                PorterDuff::DestinationAtop,
+                Vec4::new(0.375, 0.125, 0.0, 0.5),
+            ),
+            (PorterDuff::Xor, Vec4::new(0.375, 0.125, 0.0, 0.5)),
+            (PorterDuff::Plus, Vec4::new(0.5, 0.25, 0.0, 0.75)),
+        ];
+        for (op, expected) in expected {
+            assert_eq_eps!(src.porter_duff(dst, op).repr, expected, 1e-6);
+
+            // every other encoding agrees with the premultiplied linear result
+            assert_eq_eps!(
+                src.convert::<LinearSrgbA>()
+                    .porter_duff(dst.convert(), op)
+                    .convert::<LinearSrgbAPremultiplied>(),
+                Color::linear_srgba_premultiplied(expected.x, expected.y, expected.z, expected.w),
+                1e-6
+            );
+            let srgb = src.convert::<SrgbAF32>().porter_duff(dst.convert(), op);
+            assert_eq_eps!(
+                srgb.convert::<LinearSrgbAPremultiplied>().repr,
+                expected,
+                1e-5
+            );
+            let f64 = src.to_f64().convert::<LinearSrgbAF64>();
+            let comp = f64.porter_duff(dst.to_f64().convert(), op);
+            let comp = comp.convert::<LinearSrgbAPremultipliedF64>().to_f32();
+            assert_eq_eps!(comp.repr, expected, 1e-6);
+            let sc = src
+                .convert::<ScRgbAF16Premultiplied>()
+                .porter_duff(dst.convert(), op);
+            assert_eq_eps!(
+                sc.convert::<LinearSrgbAPremultiplied>().repr,
+                expected,
+                1e-3
+            );
+            let extended = src
+                .convert::<ExtendedSrgbAF32>()
+                .porter_duff(dst.convert(), op);
+            assert_eq_eps!(
+                extended.convert::<LinearSrgbAPremultiplied>().repr,
+                expected,
+                1e-5
+            );
+        }
+
+        assert_eq!(
+            src.alpha_over(dst).repr,
+            src.porter_duff(dst, PorterDuff::SourceOver).repr
+        );
+
+        // fully transparent results are black rather than NaN
+        let clear = Color::linear_srgba(0.2, 0.4, 0.6, 1.0)
+            .porter_duff(Color::linear_srgba(1.0, 1.0, 1.0, 0.5), PorterDuff::Clear);
+        assert_eq!(clear.repr, Vec4::ZERO);
+        let out = Color::srgba_u8(255, 0, 0, 255)
+            .porter_duff(Color::srgba_u8(0, 255, 0, 255), PorterDuff::SourceOut);
+        assert_eq!(out.repr, [0, 0, 0, 0]);
+        let inside = Color::srgba_u8(255, 0, 0, 255)
+            .porter_duff(Color::srgba_u8(0, 255, 0, 128), PorterDuff::SourceIn);
+        assert_eq_eps!(
+            inside.convert::<SrgbAF32>().repr,
+            Vec4::new(1.0, 0.0, 0.0, 128.0 / 255.0),
+            1.0 / 255.0
+        );
+        let atop = Color::srgba_u8(255, 0, 0, 255)
+            .convert::<SrgbAU8Premultiplied>()
+            .porter_duff(
+                Color::srgba_u8(0, 0, 255, 255).convert(),
+                PorterDuff::SourceAtop,
+            );
+        assert_eq_eps!(
+            atop.convert::<SrgbAF32>().repr,
+            Vec4::new(1.0, 0.0, 0.0, 1.0),
+            1.0 / 255.0
+        );
+
+        // scene-referred and wide gamut colors composite in their own spaces
+        let plus = Color::scene_linear_srgba(4.0, 0.0, 0.0, 1.0).porter_duff(
+            Color::scene_linear_srgba(2.0, 1.0, 0.0, 1.0),
+            PorterDuff::Plus,
+        );
+        assert_eq!(plus.repr, Vec4::new(6.0, 1.0, 0.0, 1.0));
+        type DisplayP3A =
+            Encoded<linear_spaces::DisplayP3, transfer_functions::Srgb, F32ARepr, SeparateAlpha>;
+        let p3 = Color::<DisplayP3A>::from_repr(Vec4::new(0.2, 0.9, 0.1, 0.8)).porter_duff(
+            Color::from_repr(Vec4::new(1.0, 1.0, 1.0, 0.5)),
+            PorterDuff::DestinationIn,
+        );
+        assert_eq_eps!(p3.repr, Vec4::new(1.0, 1.0, 1.0, 0.4), 1e-6);
+
+        let xor = Color::<LinearSrgbAF64>::from_repr(DVec4::new(1.0, 0.0, 0.0, 0.5)).porter_duff(
+            Color::from_repr(DVec4::new(0.0, 0.0, 1.0, 0.5)),
+            PorterDuff::Xor,
+        );
+        assert!(xor.repr.abs_diff_eq(DVec4::new(0.5, 0.0, 0.5, 0.5), 1e-12));
+
+        // encodings implementing only `composite` get the other operators by default
+        color_encoding! {
+            struct LinearP3A {
+                repr: F32ARepr,
+                components: details::component_structs::RgbA<f32>,
+                linear_space: linear_spaces::DisplayP3,
+                referred: display,
+                src_transform: |repr: Vec4| (repr.truncate(), repr.w),
+                dst_transform: |raw: Vec3, alpha| raw.extend(alpha),
+            }
+        }
+
+        impl details::traits::AlphaOver for LinearP3A {
+            fn composite(over: Color<Self>, under: Color<Self>) -> Color<Self> {
+                let over = (over.repr.truncate() * over.a).extend(over.a);
+                let under = (under.repr.truncate() * under.a).extend(under.a);
+                let comp = over + under * (1.0 - over.w);
+                Color::from_repr((comp.truncate() / comp.w).extend(comp.w))
+            }
+        }
+
+        let src = Color::<LinearP3A>::from_repr(Vec4::new(1.0, 0.0, 0.0, 0.5));
+        let dst = Color::<LinearP3A>::from_repr(Vec4::new(0.0, 0.0, 1.0, 0.5));
+        assert_eq_eps!(
+            src.porter_duff(dst, PorterDuff::SourceOver).repr,
+            src.alpha_over(dst).repr,
+            1e-6
+        );
+        assert_eq_eps!(
+            src.porter_duff(dst, PorterDuff::Xor).repr,
+            Vec4::new(0.5, 0.0, 0.5, 0.5),
+            1e-6
+        );
+    }
+
+    #[test]
+    fn premultiplied_zero_alpha() {
+        use glam::{DVec4, Vec4};
+
+        let transparent = Color::<LinearSrgbAPremultiplied>::from_repr(Vec4::ZERO);
+        assert_eq!(transparent.convert::<LinearSrgbA>().repr, Vec4::ZERO);
+        assert_eq!(transparent.convert::<SrgbAU8>().repr, [0, 0, 0, 0]);
+
+        let transparent_u8 = Color::<SrgbAU8Premultiplied>::from_repr([0, 0, 0, 0]);
+        assert_eq!(transparent_u8.convert::<SrgbAF32>().repr, Vec4::ZERO);
+
+        let transparent_f64 = Color::<LinearSrgbAPremultipliedF64>::from_repr(DVec4::ZERO);
+        assert_eq!(
+            transparent_f64.convert::<LinearSrgbAF64>().repr,
+            DVec4::ZERO
+        );
+    }
+
+    #[test]
+    fn exposure() {
+        assert_eq_eps!(exposure::ev100(1.0, 1.0, 100.0), 0.0, 1e-6);
+        assert_eq_eps!(exposure::ev100(2.0, 1.0, 100.0), 2.0, 1e-6);
+        assert_eq_eps!(exposure::ev100(1.0, 1.0, 200.0), -1.0, 1e-6);
+        assert_eq_eps!(exposure::luminance_from_ev100(0.0), 0.125, 1e-6);
+        assert_eq_eps!(exposure::illuminance_from_ev100(0.0), 2.5, 1e-6);
+        assert_eq_eps!(
+            exposure::ev100_from_luminance(exposure::luminance_from_ev100(9.5)),
+            9.5,
+            1e-5
+        );
+        assert_eq_eps!(
+            exposure::ev100_from_illuminance(exposure::illuminance_from_ev100(-3.0)),
+            -3.0,
+            1e-5
+        );
+        assert_eq_eps!(exposure::exposure_from_ev100(0.0), 1.0 / 1.2, 1e-6);
+        assert_eq_eps!(exposure::radiometric_to_photometric(1.0), 683.0, 1e-6);
+        assert_eq_eps!(
+            exposure::photometric_to_radiometric(exposure::radiometric_to_photometric(0.3)),
+            0.3,
+            1e-6
+        );
+
+        let color = Color::scene_linear_srgba(0.25, 0.5, 1.0, 0.5);
+        assert_eq!(
+            color.expose(2.0).repr,
+            Color::scene_linear_srgba(1.0, 2.0, 4.0, 0.5).repr
+        );
+        assert_eq!(color.expose(-1.0).expose(1.0).repr, color.repr);
+
+        let white = Color::scene_linear_srgb(1.0, 1.0, 1.0);
+        assert_eq_eps!(white.luminance_nits(100.0), 100.0, 1e-3);
+        assert_eq_eps!(
+            Color::scene_linear_srgb(0.0, 1.0, 0.0).luminance_nits(1.0),
+            0.7152,
+            1e-3
+        );
+        assert_eq_eps!(
+            Color::linear_srgb_f64(1.0, 1.0, 1.0).luminance_nits(80.0),
+            80.0,
+            1e-3
+        );
+    }
+
+    #[test]
+    fn tonemapping() {
+        use tonemap::*;
+
+        let operators: [&dyn Tonemapper; 9] = [
+            &Reinhard,
+            &ReinhardExtended::default(),
+            &Hable::default(),
+            &AcesNarkowicz,
+            &AcesHill,
+            &AgX::new(AgXLook::Base),
+            &AgX::new(AgXLook::Punchy),
+            &AgX::new(AgXLook::Golden),
+            &KhronosPbrNeutral,
+        ];
+
+        for op in operators {
+            let black = op.tonemap_raw(Vec3::ZERO);
+            assert!(black.max_element() < 0.001, "{:?}", black);
+
+            let mut prev = black;
+            for i in 1..=64 {
+                let x = 2f32.powf(i as f32 / 4.0 - 8.0);
+                let mapped = op.tonemap_raw(Vec3::splat(x));
+                assert!(mapped.cmpge(Vec3::ZERO).all() && mapped.cmple(Vec3::ONE).all());
+                assert!(mapped.x >= prev.x - 1e-6, "not monotonic at {}", x);
+                prev = mapped;
+            }
+            assert!(prev.max_element() > 0.8, "{:?}", prev);
+        }
+
+        assert_eq_eps!(Reinhard.tonemap_raw(Vec3::ONE).x, 0.5, 1e-6);
+        assert_eq_eps!(
+            ReinhardExtended::new(4.0).tonemap_raw(Vec3::splat(4.0)).x,
+            1.0,
+            1e-6
+        );
+        assert_eq_eps!(AcesNarkowicz.tonemap_raw(Vec3::ONE).x, 2.54 / 3.16, 1e-6);
+        assert_eq_eps!(Hable::default().tonemap_raw(Vec3::splat(5.6)).x, 1.0, 1e-6);
+        // below the compression threshold, PBR Neutral only subtracts a small offset
+        assert_eq_eps!(
+            KhronosPbrNeutral.tonemap_raw(Vec3::new(0.5, 0.3, 0.2)),
+            Vec3::new(0.46, 0.26, 0.16),
+            1e-6
+        );
+
+        let hdr = Color::scene_linear_srgb(4.0, 1.5, 0.25);
+        let ldr: Color<SrgbU8> = hdr.tonemap(&AcesHill);
+        assert_eq!(ldr, AcesHill.tonemap::<SrgbU8>(hdr));
+        let expected = Color::<LinearSrgb>::from_repr(AcesHill.tonemap_raw(hdr.repr));
+        assert_eq!(ldr, expected.convert::<SrgbU8>());
+    }
+
+    #[test]
+    fn scene_referred() {
+        // display-referred colors carry over to scene-referred ones unchanged
+        let display = Color::srgb_u8(255, 128, 0);
+        let scene = display.convert::<SceneLinearSrgb>();
+        assert_eq!(scene.repr, display.convert::<LinearSrgb>().repr);
+        let scene_alpha = Color::srgba_u8(255, 128, 0, 64).convert::<SceneLinearSrgbA>();
+        assert_eq_eps!(scene_alpha.a, 64.0 / 255.0, 1e-6);
+
+        let hdr = Color::scene_linear_srgb(4.0, 0.5, -0.25);
+        assert_eq!(
+            hdr.clamp_to_display().repr,
+            Color::linear_srgb(1.0, 0.5, 0.0).repr
+        );
+        let hdr_alpha = Color::scene_linear_srgba(4.0, 0.5, -0.25, 0.5);
+        assert_eq!(
+            hdr_alpha.clamp_to_display().repr,
+            Color::linear_srgba(1.0, 0.5, 0.0, 0.5).repr
+        );
+
+        let mapped: Color<LinearSrgbA> = hdr_alpha.tonemap(&tonemap::Reinhard);
+        assert_eq_eps!(mapped.repr, glam::Vec4::new(0.8, 1.0 / 3.0, 0.0, 0.5), 1e-6);
+    }
+
     #[test]
     fn perceptual_blend() {
         let start = Color::srgb_u8(105, 220, 58);
@@ -0,0 +1,635 @@
+//! Support code for the [`color!`][crate::color!], [`linear_color_space!`][crate::linear_color_space!] and
+//! [`color_encoding!`][crate::color_encoding!] macros. Not public API.
+
+use crate::const_math;
+use crate::hex::parse_hex;
+
+/// Creates a [`Color`][crate::Color] from a literal, validating and converting it at compile time.
+///
+/// The first token names the encoding of the literal, followed by either a hex string or the color's components
+/// (optionally separated by commas). The result is a `const` value, so using it has no runtime cost, and it can
+/// be used to initialize `const` and `static` items.
+///
+/// | Syntax | Result |
+/// |---|---|
+/// | `color!(srgb_u8 "#6633cc")` or `color!(srgb_u8 102, 51, 153)` | [`Color<SrgbU8>`][crate::details::encodings::SrgbU8] |
+/// | `color!(srgba_u8 "#6633cc80")` or `color!(srgba_u8 102, 51, 153, 128)` | [`Color<SrgbAU8>`][crate::details::encodings::SrgbAU8] |
+/// | `color!(srgb_f32 0.4, 0.2, 0.6)` | [`Color<SrgbF32>`][crate::details::encodings::SrgbF32] |
+/// | `color!(srgba_f32 0.4, 0.2, 0.6, 0.5)` | [`Color<SrgbAF32>`][crate::details::encodings::SrgbAF32] |
+/// | `color!(linear_srgb 0.2, 0.3, 0.4)` | [`Color<LinearSrgb>`][crate::details::encodings::LinearSrgb] |
+/// | `color!(linear_srgba 0.2, 0.3, 0.4, 0.5)` | [`Color<LinearSrgbA>`][crate::details::encodings::LinearSrgbA] |
+/// | `color!(oklab 0.7, -0.05, 0.1)` | [`Color<Oklab>`][crate::details::encodings::Oklab] |
+/// | `color!(oklch 0.7 0.1 250deg)` | [`Color<Oklab>`][crate::details::encodings::Oklab], converted from polar form |
+///
+/// Hues may be given in `deg` (the default if no unit is given), `rad`, `grad` or `turn`.
+///
+/// ```
+/// use colstodian::{color, Color};
+/// use colstodian::details::encodings::*;
+/// # use colstodian::*;
+/// # use colstodian::equals_eps::*;
+///
+/// const ACCENT: Color<SrgbU8> = color!(srgb_u8 "#6633cc");
+/// const SHADOW: Color<LinearSrgb> = color!(linear_srgb 0.2, 0.3, 0.4);
+/// const SKY: Color<Oklab> = color!(oklch 0.7 0.1 0.25turn);
+///
+/// assert_eq!(ACCENT, Color::srgb_u8(0x66, 0x33, 0xcc));
+/// assert_eq!(SHADOW, Color::linear_srgb(0.2, 0.3, 0.4));
+/// assert_eq_eps!(SKY, Color::oklab(0.7, 0.0, 0.1), 0.00001);
+/// ```
+///
+/// Malformed literals are rejected at compile time:
+///
+/// ```compile_fail
+/// # use colstodian::color;
+/// let invalid = color!(srgb_u8 "#6633zz");
+/// ```
+///
+/// ```compile_fail
+/// # use colstodian::color;
+/// let out_of_range = color!(srgb_u8 102, 51, 300);
+/// ```
+///
+/// ```compile_fail
+/// # use colstodian::color;
+/// let wrong_unit = color!(oklch 0.7 0.1deg 250);
+/// ```
+#[macro_export]
+macro_rules! color {
+    (srgb_u8 $hex:literal) => {
+        $crate::color!(@const SrgbU8, {
+            let [r, g, b, _] = $crate::macros::hex(stringify!($hex), false);
+            $crate::Color::from_repr([r, g, b])
+        })
+    };
+    (srgba_u8 $hex:literal) => {
+        $crate::color!(@const SrgbAU8, $crate::Color::from_repr($crate::macros::hex(stringify!($hex), true)))
+    };
+    (srgb_u8 $r:literal $(,)? $g:literal $(,)? $b:literal) => {
+        $crate::color!(@const SrgbU8, $crate::Color::from_repr([
+            $crate::macros::u8_component(stringify!($r)),
+            $crate::macros::u8_component(stringify!($g)),
+            $crate::macros::u8_component(stringify!($b)),
+        ]))
+    };
+    (srgba_u8 $r:literal $(,)? $g:literal $(,)? $b:literal $(,)? $a:literal) => {
+        $crate::color!(@const SrgbAU8, $crate::Color::from_repr([
+            $crate::macros::u8_component(stringify!($r)),
+            $crate::macros::u8_component(stringify!($g)),
+            $crate::macros::u8_component(stringify!($b)),
+            $crate::macros::u8_component(stringify!($a)),
+        ]))
+    };
+    (srgb_f32 $($c:literal),+ $(,)?) => { $crate::color!(@f32x3 SrgbF32 $($c)+) };
+    (srgb_f32 $($c:literal)+) => { $crate::color!(@f32x3 SrgbF32 $($c)+) };
+    (srgba_f32 $($c:literal),+ $(,)?) => { $crate::color!(@f32x4 SrgbAF32 $($c)+) };
+    (srgba_f32 $($c:literal)+) => { $crate::color!(@f32x4 SrgbAF32 $($c)+) };
+    (linear_srgb $($c:literal),+ $(,)?) => { $crate::color!(@f32x3 LinearSrgb $($c)+) };
+    (linear_srgb $($c:literal)+) => { $crate::color!(@f32x3 LinearSrgb $($c)+) };
+    (linear_srgba $($c:literal),+ $(,)?) => { $crate::color!(@f32x4 LinearSrgbA $($c)+) };
+    (linear_srgba $($c:literal)+) => { $crate::color!(@f32x4 LinearSrgbA $($c)+) };
+    (oklab $($c:literal),+ $(,)?) => { $crate::color!(@f32x3 Oklab $($c)+) };
+    (oklab $($c:literal)+) => { $crate::color!(@f32x3 Oklab $($c)+) };
+    (oklch $l:literal $(,)? $c:literal $(,)? $h:literal $(,)?) => {
+        $crate::color!(@const Oklab, $crate::Color::from_repr($crate::macros::oklch_to_oklab(
+            $crate::macros::f32_component(stringify!($l)),
+            $crate::macros::f32_component(stringify!($c)),
+            $crate::macros::hue_component(stringify!($h)),
+        )))
+    };
+
+    (@f32x3 $enc:ident $x:literal $y:literal $z:literal) => {
+        $crate::color!(@const $enc, $crate::Color::from_repr($crate::macros::Vec3::new(
+            $crate::macros::f32_component(stringify!($x)),
+            $crate::macros::f32_component(stringify!($y)),
+            $crate::macros::f32_component(stringify!($z)),
+        )))
+    };
+    (@f32x4 $enc:ident $x:literal $y:literal $z:literal $w:literal) => {
+        $crate::color!(@const $enc, $crate::Color::from_repr($crate::macros::Vec4::new(
+            $crate::macros::f32_component(stringify!($x)),
+            $crate::macros::f32_component(stringify!($y)),
+            $crate::macros::f32_component(stringify!($z)),
+            $crate::macros::f32_component(stringify!($w)),
+        )))
+    };
+    (@const $enc:ident, $value:expr) => {{
+        const COLOR: $crate::Color<$crate::details::encodings::$enc> = $value;
+        COLOR
+    }};
+}
+
+/// Declares a custom [`LinearColorSpace`][crate::details::traits::LinearColorSpace] from the `xy` chromaticities of its primaries and white point.
+///
+/// The matrices converting to and from every built-in linear space are derived at compile time, in the same way as
+/// the built-in spaces' own matrices (see [`crate::details::matrices_f64`]), and
+/// [`LinearConvertFromRaw`][crate::details::traits::LinearConvertFromRaw] is implemented in both directions. The space's
+/// [`LinearSpaceDefinition`][crate::details::matrices_f64::LinearSpaceDefinition] is available as an associated
+/// `DEFINITION` constant. As its chromaticities are not one of `kolor`'s presets, its
+/// [`PRIMARIES`][crate::details::traits::LinearColorSpace::PRIMARIES] and
+/// [`WHITE_POINT`][crate::details::traits::LinearColorSpace::WHITE_POINT] are `NONE`.
+///
+/// ```
+/// use colstodian::linear_color_space;
+/// use colstodian::details::linear_spaces::Srgb;
+/// use colstodian::details::traits::LinearConvertFromRaw;
+/// # use glam::Vec3;
+///
+/// linear_color_space! {
+///     /// The native color space of a calibrated camera.
+///     pub struct CameraNative {
+///         primaries: [[0.7, 0.3], [0.17, 0.79], [0.13, 0.05]],
+///         white_point: [0.3127, 0.329],
+///     }
+/// }
+///
+/// let mut white = Vec3::ONE;
+/// <Srgb as LinearConvertFromRaw<CameraNative>>::linear_part_raw(&mut white);
+/// assert!(white.abs_diff_eq(Vec3::ONE, 1e-3));
+/// ```
+#[macro_export]
+macro_rules! linear_color_space {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            primaries: [[$rx:expr, $ry:expr], [$gx:expr, $gy:expr], [$bx:expr, $by:expr]] $(,)?
+            white_point: [$wx:expr, $wy:expr] $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name;
+
+        impl $name {
+            /// The chromaticities defining this linear color space.
+            pub const DEFINITION: $crate::details::matrices_f64::LinearSpaceDefinition =
+                $crate::details::matrices_f64::LinearSpaceDefinition::from_xy(
+                    [[$rx, $ry], [$gx, $gy], [$bx, $by]],
+                    [$wx, $wy],
+                );
+        }
+
+        impl $crate::details::traits::LinearColorSpace for $name {
+            const PRIMARIES: $crate::macros::RGBPrimaries = $crate::macros::RGBPrimaries::NONE;
+            const WHITE_POINT: $crate::macros::WhitePoint = $crate::macros::WhitePoint::NONE;
+            const NAME: &'static str = stringify!($name);
+        }
+
+        impl $crate::details::traits::LinearConvertFromRaw<$name> for $name {
+            #[inline(always)]
+            fn linear_part_raw(_: &mut $crate::macros::Vec3) {}
+
+            #[inline(always)]
+            fn linear_part_raw_f64(_: &mut $crate::macros::DVec3) {}
+        }
+
+        $crate::linear_color_space!(@conversions $name,
+            Srgb => BT_709_D65,
+            CieXYZ => CIE_XYZ_D65,
+            Bt2020 => BT_2020_D65,
+            AcesCg => AP1_D60,
+            Aces2065 => AP0_D60,
+            DisplayP3 => P3_D65,
+            AdobeRgb => ADOBE_1998_D65,
+            ProPhotoRgb => PRO_PHOTO_D50
+        );
+    };
+    (@conversions $name:ident, $($space:ident => $definition:ident),*) => {$(
+        $crate::linear_color_space!(@conversion
+            $name => $crate::details::linear_spaces::$space,
+            $name::DEFINITION,
+            $crate::details::matrices_f64::$definition
+        );
+        $crate::linear_color_space!(@conversion
+            $crate::details::linear_spaces::$space => $name,
+            $crate::details::matrices_f64::$definition,
+            $name::DEFINITION
+        );
+    )*};
+    (@conversion $src:ty => $dst:ty, $src_definition:expr, $dst_definition:expr) => {
+        impl $crate::details::traits::LinearConvertFromRaw<$src> for $dst {
+            #[inline(always)]
+            fn linear_part_raw(color: &mut $crate::macros::Vec3) {
+                const MATRIX: $crate::macros::Mat3 = $crate::details::matrices_f64::to_mat3(
+                    &$crate::details::matrices_f64::conversion_between(&$src_definition, &$dst_definition),
+                );
+                *color = MATRIX * *color;
+            }
+
+            #[inline(always)]
+            fn linear_part_raw_f64(color: &mut $crate::macros::DVec3) {
+                const MATRIX: $crate::macros::DMat3 =
+                    $crate::details::matrices_f64::conversion_between(&$src_definition, &$dst_definition);
+                *color = MATRIX * *color;
+            }
+        }
+    };
+}
+
+/// Declares a custom [`ColorEncoding`][crate::details::traits::ColorEncoding], along with its conversions to and from
+/// the built-in encodings.
+///
+/// An encoding is given by its repr, its component struct, its linear space, whether it is
+/// [display-referred][crate::details::traits::DisplayReferred] or [scene-referred][crate::details::traits::SceneReferred],
+/// and its transforms to and from that linear space (see
+/// [`src_transform_raw`][crate::details::traits::ColorEncoding::src_transform_raw] and
+/// [`dst_transform_raw`][crate::details::traits::ColorEncoding::dst_transform_raw]). The transforms may be closures
+/// or paths to functions. The linear space may be a built-in one or one declared with
+/// [`linear_color_space!`][crate::linear_color_space!].
+///
+/// The component struct may be an existing type, or declared in place as `struct Name { a, b, c }`, in which case a
+/// `#[repr(C)]` struct with one field of the repr's element type per name is generated, along with its
+/// [`ComponentStructFor`][crate::details::traits::ComponentStructFor] implementation. Its layout is checked against
+/// the repr at compile time.
+///
+/// [`ConvertFrom`][crate::details::traits::ConvertFrom] is implemented against every built-in encoding, including
+/// the `f64` ones and every [`Encoded`][crate::details::encodings::Encoded] encoding, following the same rules as the
+/// built-in encodings: display-referred encodings convert in both directions with each other, and can be converted
+/// to scene-referred encodings, but not from them. Conversions
+/// between two custom encodings can be added with a one-line `impl ConvertFrom<A> for B {}`.
+///
+/// ```
+/// use colstodian::{color_encoding, Color};
+/// use colstodian::details::encodings::{AdobeRgbF32, LinearSrgb};
+/// use colstodian::details::linear_spaces::DisplayP3;
+/// use colstodian::details::reprs::F32Repr;
+/// # use colstodian::*;
+/// # use colstodian::equals_eps::*;
+///
+/// color_encoding! {
+///     /// Linear Display P3, with its components stored as `f32`s.
+///     pub struct LinearDisplayP3 {
+///         repr: F32Repr,
+///         components: struct LinearP3Components { r, g, b },
+///         linear_space: DisplayP3,
+///         referred: display,
+///         src_transform: |repr| (repr, 1.0),
+///         dst_transform: |raw, _| raw,
+///     }
+/// }
+///
+/// let red: Color<LinearDisplayP3> = Color::linear_srgb(1.0, 0.0, 0.0).convert();
+/// assert!(red.r < 1.0 && red.g > 0.0);
+/// assert_eq_eps!(red.convert::<LinearSrgb>(), Color::linear_srgb(1.0, 0.0, 0.0), 1e-5);
+///
+/// let adobe: Color<AdobeRgbF32> = red.convert();
+/// assert_eq_eps!(adobe.convert::<LinearDisplayP3>(), red, 1e-5);
+/// ```
+#[macro_export]
+macro_rules! color_encoding {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            repr: $repr:ty,
+            components: struct $components:ident { $($field:ident),+ $(,)? },
+            linear_space: $space:ty,
+            referred: $referred:ident,
+            src_transform: $src:expr,
+            dst_transform: $dst:expr $(,)?
+        }
+    ) => {
+        #[doc = concat!("The components of [`", stringify!($name), "`].")]
+        #[repr(C)]
+        #[derive(Clone, Copy, PartialEq, Debug)]
+        $vis struct $components {
+            $(pub $field: <$repr as $crate::details::traits::ColorRepr>::Element,)+
+        }
+
+        const _: () = assert!(
+            ::core::mem::size_of::<$components>() == ::core::mem::size_of::<$repr>()
+                && ::core::mem::align_of::<$components>() <= ::core::mem::align_of::<$repr>(),
+            concat!("the components of `", stringify!($name), "` don't match the layout of its repr"),
+        );
+
+        // SAFETY: `$components` is `#[repr(C)]`, made only of the repr's elements, and checked above to have the
+        // same size as the repr and no greater alignment.
+        unsafe impl $crate::details::traits::ComponentStructFor<$repr> for $components {
+            fn cast(repr: &$repr) -> &Self {
+                // SAFETY: see the layout check above
+                unsafe { &*(repr as *const $repr as *const Self) }
+            }
+
+            fn cast_mut(repr: &mut $repr) -> &mut Self {
+                // SAFETY: see the layout check above
+                unsafe { &mut *(repr as *mut $repr as *mut Self) }
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl ::core::fmt::Display for $components {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::macros::fmt_components(f, &[$((stringify!($field), &self.$field)),+])
+            }
+        }
+
+        $crate::color_encoding! {
+            $(#[$meta])*
+            $vis struct $name {
+                repr: $repr,
+                components: $components,
+                linear_space: $space,
+                referred: $referred,
+                src_transform: $src,
+                dst_transform: $dst,
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            repr: $repr:ty,
+            components: $components:ty,
+            linear_space: $space:ty,
+            referred: $referred:ident,
+            src_transform: $src:expr,
+            dst_transform: $dst:expr $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name;
+
+        impl $crate::details::traits::ColorEncoding for $name {
+            type Repr = $repr;
+
+            type ComponentStruct = $components;
+
+            type LinearSpace = $space;
+
+            const NAME: &'static str = stringify!($name);
+
+            #[inline]
+            fn src_transform_raw(repr: Self::Repr) -> ($crate::macros::Vec3, f32) {
+                let transform: fn(Self::Repr) -> ($crate::macros::Vec3, f32) = $src;
+                transform(repr)
+            }
+
+            #[inline]
+            fn dst_transform_raw(raw: $crate::macros::Vec3, alpha: f32) -> Self::Repr {
+                let transform: fn($crate::macros::Vec3, f32) -> Self::Repr = $dst;
+                transform(raw, alpha)
+            }
+        }
+
+        $crate::color_encoding!(@$referred $name);
+    };
+    (@display $name:ident) => {
+        impl $crate::details::traits::Referred for $name {
+            type Referral = $crate::details::traits::DisplayReferral;
+        }
+
+        $crate::color_encoding!(@convert_both $name,
+            SrgbU8, SrgbF32, SrgbAU8, SrgbAF32, SrgbAU8Premultiplied, LinearSrgb, LinearSrgbA,
+            LinearSrgbAPremultiplied, Oklab, CieXyz, CieLab, ICtCpPq, SrgbF64, SrgbAF64, LinearSrgbF64,
+            LinearSrgbAF64, LinearSrgbAPremultipliedF64, OklabF64
+        );
+        $crate::color_encoding!(@convert_to $name, SceneLinearSrgb, SceneLinearSrgbA, AcesCgF32);
+        $crate::color_encoding!(@convert_encoded $name);
+    };
+    (@scene $name:ident) => {
+        impl $crate::details::traits::Referred for $name {
+            type Referral = $crate::details::traits::SceneReferral;
+        }
+
+        $crate::color_encoding!(@convert_from $name,
+            SrgbU8, SrgbF32, SrgbAU8, SrgbAF32, SrgbAU8Premultiplied, LinearSrgb, LinearSrgbA,
+            LinearSrgbAPremultiplied, Oklab, CieXyz, CieLab, ICtCpPq, SrgbF64, SrgbAF64, LinearSrgbF64,
+            LinearSrgbAF64, LinearSrgbAPremultipliedF64, OklabF64
+        );
+        $crate::color_encoding!(@convert_both $name, SceneLinearSrgb, SceneLinearSrgbA, AcesCgF32);
+        $crate::color_encoding!(@convert_encoded $name);
+    };
+    // `Encoded` encodings are covered generically, in whichever directions `ReferralFrom` allows.
+    (@convert_encoded $name:ident) => {
+        impl<Space, Tf, Repr, Alpha> $crate::details::traits::ConvertFrom<
+            $crate::details::encodings::Encoded<Space, Tf, Repr, Alpha>,
+        > for $name
+        where
+            Space: $crate::details::traits::LinearColorSpace + 'static,
+            <$name as $crate::details::traits::ColorEncoding>::LinearSpace:
+                $crate::details::traits::LinearConvertFromRaw<Space>,
+            Tf: $crate::details::traits::TransferFunction,
+            Repr: $crate::details::traits::EncodedRepr<Alpha>,
+            Alpha: $crate::details::traits::AlphaMode,
+            $name: $crate::details::traits::ReferralFrom<
+                $crate::details::encodings::Encoded<Space, Tf, Repr, Alpha>,
+            >,
+        {
+        }
+
+        impl<Space, Tf, Repr, Alpha> $crate::details::traits::ConvertFrom<$name>
+            for $crate::details::encodings::Encoded<Space, Tf, Repr, Alpha>
+        where
+            Space: $crate::details::traits::LinearColorSpace
+                + $crate::details::traits::LinearConvertFromRaw<
+                    <$name as $crate::details::traits::ColorEncoding>::LinearSpace,
+                > + 'static,
+            Tf: $crate::details::traits::TransferFunction,
+            Repr: $crate::details::traits::EncodedRepr<Alpha>,
+            Alpha: $crate::details::traits::AlphaMode,
+            $crate::details::encodings::Encoded<Space, Tf, Repr, Alpha>:
+                $crate::details::traits::ReferralFrom<$name>,
+        {
+        }
+    };
+    (@convert_both $name:ident, $($encoding:ident),+) => {
+        $crate::color_encoding!(@convert_from $name, $($encoding),+);
+        $crate::color_encoding!(@convert_to $name, $($encoding),+);
+    };
+    (@convert_from $name:ident, $($encoding:ident),+) => {$(
+        impl $crate::details::traits::ConvertFrom<$crate::details::encodings::$encoding> for $name {}
+    )+};
+    (@convert_to $name:ident, $($encoding:ident),+) => {$(
+        impl $crate::details::traits::ConvertFrom<$name> for $crate::details::encodings::$encoding {}
+    )+};
+}
+
+pub use glam::{DMat3, DVec3, Mat3, Vec3, Vec4};
+pub use kolor::details::color::{RGBPrimaries, WhitePoint};
+
+/// Formats the named components of a struct declared by [`color_encoding!`][crate::color_encoding!] in the same
+/// way as the built-in component structs.
+#[cfg(not(target_arch = "spirv"))]
+pub fn fmt_components(
+    f: &mut core::fmt::Formatter<'_>,
+    components: &[(&str, &dyn core::fmt::Display)],
+) -> core::fmt::Result {
+    for (i, (name, value)) in components.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{}: {:.3}", name, value)?;
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    None,
+    Degrees,
+    Radians,
+    Gradians,
+    Turns,
+}
+
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Parses a stringified numeric literal with an optional unit suffix, returning the value, the unit,
+/// and whether the literal was an integer.
+const fn parse_number(s: &str) -> (f64, Unit, bool) {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+
+    let negative = i < bytes.len() && bytes[i] == b'-';
+    if negative {
+        i += 1;
+        // `stringify!` may put whitespace between the sign and the number
+        while i < bytes.len() && bytes[i] == b' ' {
+            i += 1;
+        }
+    }
+
+    let mut mantissa: f64 = 0.0;
+    let mut scale: i32 = 0;
+    let mut digits = 0;
+    let mut is_integer = true;
+    let mut seen_point = false;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b.is_ascii_digit() {
+            mantissa = mantissa * 10.0 + (b - b'0') as f64;
+            if seen_point {
+                scale -= 1;
+            }
+            digits += 1;
+        } else if b == b'.' && !seen_point {
+            seen_point = true;
+            is_integer = false;
+        } else if b != b'_' {
+            break;
+        }
+        i += 1;
+    }
+    if digits == 0 {
+        panic!("invalid number in `color!` literal");
+    }
+
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        i += 1;
+        let exp_negative = i < bytes.len() && bytes[i] == b'-';
+        if i < bytes.len() && (bytes[i] == b'-' || bytes[i] == b'+') {
+            i += 1;
+        }
+        let mut exp = 0;
+        let mut exp_digits = 0;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            exp = exp * 10 + (bytes[i] - b'0') as i32;
+            exp_digits += 1;
+            i += 1;
+        }
+        if exp_digits == 0 {
+            panic!("invalid exponent in `color!` literal");
+        }
+        scale += if exp_negative { -exp } else { exp };
+        is_integer = false;
+    }
+
+    let mut value = mantissa;
+    while scale > 0 {
+        value *= 10.0;
+        scale -= 1;
+    }
+    while scale < 0 {
+        value /= 10.0;
+        scale += 1;
+    }
+    if negative {
+        value = -value;
+    }
+
+    let (_, suffix) = bytes.split_at(i);
+    let unit = if suffix.is_empty()
+        || bytes_eq(suffix, b"f32")
+        || bytes_eq(suffix, b"f64")
+        || bytes_eq(suffix, b"u8")
+    {
+        Unit::None
+    } else if bytes_eq(suffix, b"deg") {
+        Unit::Degrees
+    } else if bytes_eq(suffix, b"rad") {
+        Unit::Radians
+    } else if bytes_eq(suffix, b"grad") {
+        Unit::Gradians
+    } else if bytes_eq(suffix, b"turn") {
+        Unit::Turns
+    } else {
+        panic!("unknown unit in `color!` literal")
+    };
+
+    (value, unit, is_integer)
+}
+
+/// Parses a unitless `f32` component.
+pub const fn f32_component(s: &str) -> f32 {
+    match parse_number(s) {
+        (value, Unit::None, _) => value as f32,
+        _ => panic!("`color!` component must not have a unit"),
+    }
+}
+
+/// Parses an integer `0..=255` component.
+pub const fn u8_component(s: &str) -> u8 {
+    match parse_number(s) {
+        (value, Unit::None, true) if value >= 0.0 && value <= 255.0 => value as u8,
+        _ => panic!("`color!` component must be an integer in `0..=255`"),
+    }
+}
+
+/// Parses a hue, returning it in degrees.
+pub const fn hue_component(s: &str) -> f32 {
+    let degrees = match parse_number(s) {
+        (value, Unit::None | Unit::Degrees, _) => value,
+        (value, Unit::Radians, _) => value.to_degrees(),
+        (value, Unit::Gradians, _) => value * 0.9,
+        (value, Unit::Turns, _) => value * 360.0,
+    };
+    degrees as f32
+}
+
+/// Parses a stringified hex string literal (including its quotes) into `[r, g, b, a]`.
+pub const fn hex(s: &str, allow_alpha: bool) -> [u8; 4] {
+    let bytes = s.as_bytes();
+    if bytes.len() < 2 || bytes[0] != b'"' || bytes[bytes.len() - 1] != b'"' {
+        panic!("`color!` hex color must be a string literal");
+    }
+    let (_, rest) = bytes.split_at(1);
+    let (inner, _) = rest.split_at(rest.len() - 1);
+    let inner = match core::str::from_utf8(inner) {
+        Ok(inner) => inner,
+        Err(_) => panic!("`color!` hex color must be valid UTF-8"),
+    };
+    match parse_hex(inner, allow_alpha) {
+        Ok(rgba) => rgba,
+        Err(_) => panic!("invalid hex color in `color!` literal"),
+    }
+}
+
+/// Converts Oklch with a hue in degrees to Oklab.
+pub const fn oklch_to_oklab(lightness: f32, chroma: f32, hue_degrees: f32) -> Vec3 {
+    let (sin, cos) = const_math::sin_cos((hue_degrees as f64).to_radians());
+    Vec3::new(
+        lightness,
+        (chroma as f64 * cos) as f32,
+        (chroma as f64 * sin) as f32,
+    )
+}
@@ -0,0 +1,655 @@
+//! The CSS Color Level 4 (and X11) named colors as [`Color<SrgbU8>`] constants.
+//!
+//! All 148 CSS named colors are available as constants, along with a case-insensitive name lookup in
+//! [`from_name`] and a reverse lookup of the perceptually closest named color in [`nearest`].
+//!
+//! ```
+//! use colstodian::{named, Color};
+//!
+//! assert_eq!(named::CORNFLOWER_BLUE, Color::srgb_u8(100, 149, 237));
+//! assert_eq!(named::from_name("CornflowerBlue"), Some(named::CORNFLOWER_BLUE));
+//! assert_eq!(named::nearest(Color::srgb_u8(101, 150, 230)).0, "cornflowerblue");
+//! ```
+
+use crate::encodings::{Oklab, SrgbU8};
+use crate::{Color, ColorInto};
+
+/// The CSS named color `aliceblue` (`#f0f8ff`).
+pub const ALICE_BLUE: Color<SrgbU8> = Color::srgb_u8(240, 248, 255);
+
+/// The CSS named color `antiquewhite` (`#faebd7`).
+pub const ANTIQUE_WHITE: Color<SrgbU8> = Color::srgb_u8(250, 235, 215);
+
+/// The CSS named color `aqua` (`#00ffff`).
+pub const AQUA: Color<SrgbU8> = Color::srgb_u8(0, 255, 255);
+
+/// The CSS named color `aquamarine` (`#7fffd4`).
+pub const AQUAMARINE: Color<SrgbU8> = Color::srgb_u8(127, 255, 212);
+
+/// The CSS named color `azure` (`#f0ffff`).
+pub const AZURE: Color<SrgbU8> = Color::srgb_u8(240, 255, 255);
+
+/// The CSS named color `beige` (`#f5f5dc`).
+pub const BEIGE: Color<SrgbU8> = Color::srgb_u8(245, 245, 220);
+
+/// The CSS named color `bisque` (`#ffe4c4`).
+pub const BISQUE: Color<SrgbU8> = Color::srgb_u8(255, 228, 196);
+
+/// The CSS named color `black` (`#000000`).
+pub const BLACK: Color<SrgbU8> = Color::srgb_u8(0, 0, 0);
+
+/// The CSS named color `blanchedalmond` (`#ffebcd`).
+pub const BLANCHED_ALMOND: Color<SrgbU8> = Color::srgb_u8(255, 235, 205);
+
+/// The CSS named color `blue` (`#0000ff`).
+pub const BLUE: Color<SrgbU8> = Color::srgb_u8(0, 0, 255);
+
+/// The CSS named color `blueviolet` (`#8a2be2`).
+pub const BLUE_VIOLET: Color<SrgbU8> = Color::srgb_u8(138, 43, 226);
+
+/// The CSS named color `brown` (`#a52a2a`).
+pub const BROWN: Color<SrgbU8> = Color::srgb_u8(165, 42, 42);
+
+/// The CSS named color `burlywood` (`#deb887`).
+pub const BURLY_WOOD: Color<SrgbU8> = Color::srgb_u8(222, 184, 135);
+
+/// The CSS named color `cadetblue` (`#5f9ea0`).
+pub const CADET_BLUE: Color<SrgbU8> = Color::srgb_u8(95, 158, 160);
+
+/// The CSS named color `chartreuse` (`#7fff00`).
+pub const CHARTREUSE: Color<SrgbU8> = Color::srgb_u8(127, 255, 0);
+
+/// The CSS named color `chocolate` (`#d2691e`).
+pub const CHOCOLATE: Color<SrgbU8> = Color::srgb_u8(210, 105, 30);
+
+/// The CSS named color `coral` (`#ff7f50`).
+pub const CORAL: Color<SrgbU8> = Color::srgb_u8(255, 127, 80);
+
+/// The CSS named color `cornflowerblue` (`#6495ed`).
+pub const CORNFLOWER_BLUE: Color<SrgbU8> = Color::srgb_u8(100, 149, 237);
+
+/// The CSS named color `cornsilk` (`#fff8dc`).
+pub const CORNSILK: Color<SrgbU8> = Color::srgb_u8(255, 248, 220);
+
+/// The CSS named color `crimson` (`#dc143c`).
+pub const CRIMSON: Color<SrgbU8> = Color::srgb_u8(220, 20, 60);
+
+/// The CSS named color `cyan` (`#00ffff`).
+pub const CYAN: Color<SrgbU8> = Color::srgb_u8(0, 255, 255);
+
+/// The CSS named color `darkblue` (`#00008b`).
+pub const DARK_BLUE: Color<SrgbU8> = Color::srgb_u8(0, 0, 139);
+
+/// The CSS named color `darkcyan` (`#008b8b`).
+pub const DARK_CYAN: Color<SrgbU8> = Color::srgb_u8(0, 139, 139);
+
+/// The CSS named color `darkgoldenrod` (`#b8860b`).
+pub const DARK_GOLDENROD: Color<SrgbU8> = Color::srgb_u8(184, 134, 11);
+
+/// The CSS named color `darkgray` (`#a9a9a9`).
+pub const DARK_GRAY: Color<SrgbU8> = Color::srgb_u8(169, 169, 169);
+
+/// The CSS named color `darkgreen` (`#006400`).
+pub const DARK_GREEN: Color<SrgbU8> = Color::srgb_u8(0, 100, 0);
+
+/// The CSS named color `darkgrey` (`#a9a9a9`).
+pub const DARK_GREY: Color<SrgbU8> = Color::srgb_u8(169, 169, 169);
+
+/// The CSS named color `darkkhaki` (`#bdb76b`).
+pub const DARK_KHAKI: Color<SrgbU8> = Color::srgb_u8(189, 183, 107);
+
+/// The CSS named color `darkmagenta` (`#8b008b`).
+pub const DARK_MAGENTA: Color<SrgbU8> = Color::srgb_u8(139, 0, 139);
+
+/// The CSS named color `darkolivegreen` (`#556b2f`).
+pub const DARK_OLIVE_GREEN: Color<SrgbU8> = Color::srgb_u8(85, 107, 47);
+
+/// The CSS named color `darkorange` (`#ff8c00`).
+pub const DARK_ORANGE: Color<SrgbU8> = Color::srgb_u8(255, 140, 0);
+
+/// The CSS named color `darkorchid` (`#9932cc`).
+pub const DARK_ORCHID: Color<SrgbU8> = Color::srgb_u8(153, 50, 204);
+
+/// The CSS named color `darkred` (`#8b0000`).
+pub const DARK_RED: Color<SrgbU8> = Color::srgb_u8(139, 0, 0);
+
+/// The CSS named color `darksalmon` (`#e9967a`).
+pub const DARK_SALMON: Color<SrgbU8> = Color::srgb_u8(233, 150, 122);
+
+/// The CSS named color `darkseagreen` (`#8fbc8f`).
+pub const DARK_SEA_GREEN: Color<SrgbU8> = Color::srgb_u8(143, 188, 143);
+
+/// The CSS named color `darkslateblue` (`#483d8b`).
+pub const DARK_SLATE_BLUE: Color<SrgbU8> = Color::srgb_u8(72, 61, 139);
+
+/// The CSS named color `darkslategray` (`#2f4f4f`).
+pub const DARK_SLATE_GRAY: Color<SrgbU8> = Color::srgb_u8(47, 79, 79);
+
+/// The CSS named color `darkslategrey` (`#2f4f4f`).
+pub const DARK_SLATE_GREY: Color<SrgbU8> = Color::srgb_u8(47, 79, 79);
+
+/// The CSS named color `darkturquoise` (`#00ced1`).
+pub const DARK_TURQUOISE: Color<SrgbU8> = Color::srgb_u8(0, 206, 209);
+
+/// The CSS named color `darkviolet` (`#9400d3`).
+pub const DARK_VIOLET: Color<SrgbU8> = Color::srgb_u8(148, 0, 211);
+
+/// The CSS named color `deeppink` (`#ff1493`).
+pub const DEEP_PINK: Color<SrgbU8> = Color::srgb_u8(255, 20, 147);
+
+/// The CSS named color `deepskyblue` (`#00bfff`).
+pub const DEEP_SKY_BLUE: Color<SrgbU8> = Color::srgb_u8(0, 191, 255);
+
+/// The CSS named color `dimgray` (`#696969`).
+pub const DIM_GRAY: Color<SrgbU8> = Color::srgb_u8(105, 105, 105);
+
+/// The CSS named color `dimgrey` (`#696969`).
+pub const DIM_GREY: Color<SrgbU8> = Color::srgb_u8(105, 105, 105);
+
+/// The CSS named color `dodgerblue` (`#1e90ff`).
+pub const DODGER_BLUE: Color<SrgbU8> = Color::srgb_u8(30, 144, 255);
+
+/// The CSS named color `firebrick` (`#b22222`).
+pub const FIREBRICK: Color<SrgbU8> = Color::srgb_u8(178, 34, 34);
+
+/// The CSS named color `floralwhite` (`#fffaf0`).
+pub const FLORAL_WHITE: Color<SrgbU8> = Color::srgb_u8(255, 250, 240);
+
+/// The CSS named color `forestgreen` (`#228b22`).
+pub const FOREST_GREEN: Color<SrgbU8> = Color::srgb_u8(34, 139, 34);
+
+/// The CSS named color `fuchsia` (`#ff00ff`).
+pub const FUCHSIA: Color<SrgbU8> = Color::srgb_u8(255, 0, 255);
+
+/// The CSS named color `gainsboro` (`#dcdcdc`).
+pub const GAINSBORO: Color<SrgbU8> = Color::srgb_u8(220, 220, 220);
+
+/// The CSS named color `ghostwhite` (`#f8f8ff`).
+pub const GHOST_WHITE: Color<SrgbU8> = Color::srgb_u8(248, 248, 255);
+
+/// The CSS named color `gold` (`#ffd700`).
+pub const GOLD: Color<SrgbU8> = Color::srgb_u8(255, 215, 0);
+
+/// The CSS named color `goldenrod` (`#daa520`).
+pub const GOLDENROD: Color<SrgbU8> = Color::srgb_u8(218, 165, 32);
+
+/// The CSS named color `gray` (`#808080`).
+pub const GRAY: Color<SrgbU8> = Color::srgb_u8(128, 128, 128);
+
+/// The CSS named color `green` (`#008000`).
+pub const GREEN: Color<SrgbU8> = Color::srgb_u8(0, 128, 0);
+
+/// The CSS named color `greenyellow` (`#adff2f`).
+pub const GREEN_YELLOW: Color<SrgbU8> = Color::srgb_u8(173, 255, 47);
+
+/// The CSS named color `grey` (`#808080`).
+pub const GREY: Color<SrgbU8> = Color::srgb_u8(128, 128, 128);
+
+/// The CSS named color `honeydew` (`#f0fff0`).
+pub const HONEYDEW: Color<SrgbU8> = Color::srgb_u8(240, 255, 240);
+
+/// The CSS named color `hotpink` (`#ff69b4`).
+pub const HOT_PINK: Color<SrgbU8> = Color::srgb_u8(255, 105, 180);
+
+/// The CSS named color `indianred` (`#cd5c5c`).
+pub const INDIAN_RED: Color<SrgbU8> = Color::srgb_u8(205, 92, 92);
+
+/// The CSS named color `indigo` (`#4b0082`).
+pub const INDIGO: Color<SrgbU8> = Color::srgb_u8(75, 0, 130);
+
+/// The CSS named color `ivory` (`#fffff0`).
+pub const IVORY: Color<SrgbU8> = Color::srgb_u8(255, 255, 240);
+
+/// The CSS named color `khaki` (`#f0e68c`).
+pub const KHAKI: Color<SrgbU8> = Color::srgb_u8(240, 230, 140);
+
+/// The CSS named color `lavender` (`#e6e6fa`).
+pub const LAVENDER: Color<SrgbU8> = Color::srgb_u8(230, 230, 250);
+
+/// The CSS named color `lavenderblush` (`#fff0f5`).
+pub const LAVENDER_BLUSH: Color<SrgbU8> = Color::srgb_u8(255, 240, 245);
+
+/// The CSS named color `lawngreen` (`#7cfc00`).
+pub const LAWN_GREEN: Color<SrgbU8> = Color::srgb_u8(124, 252, 0);
+
+/// The CSS named color `lemonchiffon` (`#fffacd`).
+pub const LEMON_CHIFFON: Color<SrgbU8> = Color::srgb_u8(255, 250, 205);
+
+/// The CSS named color `lightblue` (`#add8e6`).
+pub const LIGHT_BLUE: Color<SrgbU8> = Color::srgb_u8(173, 216, 230);
+
+/// The CSS named color `lightcoral` (`#f08080`).
+pub const LIGHT_CORAL: Color<SrgbU8> = Color::srgb_u8(240, 128, 128);
+
+/// The CSS named color `lightcyan` (`#e0ffff`).
+pub const LIGHT_CYAN: Color<SrgbU8> = Color::srgb_u8(224, 255, 255);
+
+/// The CSS named color `lightgoldenrodyellow` (`#fafad2`).
+pub const LIGHT_GOLDENROD_YELLOW: Color<SrgbU8> = Color::srgb_u8(250, 250, 210);
+
+/// The CSS named color `lightgray` (`#d3d3d3`).
+pub const LIGHT_GRAY: Color<SrgbU8> = Color::srgb_u8(211, 211, 211);
+
+/// The CSS named color `lightgreen` (`#90ee90`).
+pub const LIGHT_GREEN: Color<SrgbU8> = Color::srgb_u8(144, 238, 144);
+
+/// The CSS named color `lightgrey` (`#d3d3d3`).
+pub const LIGHT_GREY: Color<SrgbU8> = Color::srgb_u8(211, 211, 211);
+
+/// The CSS named color `lightpink` (`#ffb6c1`).
+pub const LIGHT_PINK: Color<SrgbU8> = Color::srgb_u8(255, 182, 193);
+
+/// The CSS named color `lightsalmon` (`#ffa07a`).
+pub const LIGHT_SALMON: Color<SrgbU8> = Color::srgb_u8(255, 160, 122);
+
+/// The CSS named color `lightseagreen` (`#20b2aa`).
+pub const LIGHT_SEA_GREEN: Color<SrgbU8> = Color::srgb_u8(32, 178, 170);
+
+/// The CSS named color `lightskyblue` (`#87cefa`).
+pub const LIGHT_SKY_BLUE: Color<SrgbU8> = Color::srgb_u8(135, 206, 250);
+
+/// The CSS named color `lightslategray` (`#778899`).
+pub const LIGHT_SLATE_GRAY: Color<SrgbU8> = Color::srgb_u8(119, 136, 153);
+
+/// The CSS named color `lightslategrey` (`#778899`).
+pub const LIGHT_SLATE_GREY: Color<SrgbU8> = Color::srgb_u8(119, 136, 153);
+
+/// The CSS named color `lightsteelblue` (`#b0c4de`).
+pub const LIGHT_STEEL_BLUE: Color<SrgbU8> = Color::srgb_u8(176, 196, 222);
+
+/// The CSS named color `lightyellow` (`#ffffe0`).
+pub const LIGHT_YELLOW: Color<SrgbU8> = Color::srgb_u8(255, 255, 224);
+
+/// The CSS named color `lime` (`#00ff00`).
+pub const LIME: Color<SrgbU8> = Color::srgb_u8(0, 255, 0);
+
+/// The CSS named color `limegreen` (`#32cd32`).
+pub const LIME_GREEN: Color<SrgbU8> = Color::srgb_u8(50, 205, 50);
+
+/// The CSS named color `linen` (`#faf0e6`).
+pub const LINEN: Color<SrgbU8> = Color::srgb_u8(250, 240, 230);
+
+/// The CSS named color `magenta` (`#ff00ff`).
+pub const MAGENTA: Color<SrgbU8> = Color::srgb_u8(255, 0, 255);
+
+/// The CSS named color `maroon` (`#800000`).
+pub const MAROON: Color<SrgbU8> = Color::srgb_u8(128, 0, 0);
+
+/// The CSS named color `mediumaquamarine` (`#66cdaa`).
+pub const MEDIUM_AQUAMARINE: Color<SrgbU8> = Color::srgb_u8(102, 205, 170);
+
+/// The CSS named color `mediumblue` (`#0000cd`).
+pub const MEDIUM_BLUE: Color<SrgbU8> = Color::srgb_u8(0, 0, 205);
+
+/// The CSS named color `mediumorchid` (`#ba55d3`).
+pub const MEDIUM_ORCHID: Color<SrgbU8> = Color::srgb_u8(186, 85, 211);
+
+/// The CSS named color `mediumpurple` (`#9370db`).
+pub const MEDIUM_PURPLE: Color<SrgbU8> = Color::srgb_u8(147, 112, 219);
+
+/// The CSS named color `mediumseagreen` (`#3cb371`).
+pub const MEDIUM_SEA_GREEN: Color<SrgbU8> = Color::srgb_u8(60, 179, 113);
+
+/// The CSS named color `mediumslateblue` (`#7b68ee`).
+pub const MEDIUM_SLATE_BLUE: Color<SrgbU8> = Color::srgb_u8(123, 104, 238);
+
+/// The CSS named color `mediumspringgreen` (`#00fa9a`).
+pub const MEDIUM_SPRING_GREEN: Color<SrgbU8> = Color::srgb_u8(0, 250, 154);
+
+/// The CSS named color `mediumturquoise` (`#48d1cc`).
+pub const MEDIUM_TURQUOISE: Color<SrgbU8> = Color::srgb_u8(72, 209, 204);
+
+/// The CSS named color `mediumvioletred` (`#c71585`).
+pub const MEDIUM_VIOLET_RED: Color<SrgbU8> = Color::srgb_u8(199, 21, 133);
+
+/// The CSS named color `midnightblue` (`#191970`).
+pub const MIDNIGHT_BLUE: Color<SrgbU8> = Color::srgb_u8(25, 25, 112);
+
+/// The CSS named color `mintcream` (`#f5fffa`).
+pub const MINT_CREAM: Color<SrgbU8> = Color::srgb_u8(245, 255, 250);
+
+/// The CSS named color `mistyrose` (`#ffe4e1`).
+pub const MISTY_ROSE: Color<SrgbU8> = Color::srgb_u8(255, 228, 225);
+
+/// The CSS named color `moccasin` (`#ffe4b5`).
+pub const MOCCASIN: Color<SrgbU8> = Color::srgb_u8(255, 228, 181);
+
+/// The CSS named color `navajowhite` (`#ffdead`).
+pub const NAVAJO_WHITE: Color<SrgbU8> = Color::srgb_u8(255, 222, 173);
+
+/// The CSS named color `navy` (`#000080`).
+pub const NAVY: Color<SrgbU8> = Color::srgb_u8(0, 0, 128);
+
+/// The CSS named color `oldlace` (`#fdf5e6`).
+pub const OLD_LACE: Color<SrgbU8> = Color::srgb_u8(253, 245, 230);
+
+/// The CSS named color `olive` (`#808000`).
+pub const OLIVE: Color<SrgbU8> = Color::srgb_u8(128, 128, 0);
+
+/// The CSS named color `olivedrab` (`#6b8e23`).
+pub const OLIVE_DRAB: Color<SrgbU8> = Color::srgb_u8(107, 142, 35);
+
+/// The CSS named color `orange` (`#ffa500`).
+pub const ORANGE: Color<SrgbU8> = Color::srgb_u8(255, 165, 0);
+
+/// The CSS named color `orangered` (`#ff4500`).
+pub const ORANGE_RED: Color<SrgbU8> = Color::srgb_u8(255, 69, 0);
+
+/// The CSS named color `orchid` (`#da70d6`).
+pub const ORCHID: Color<SrgbU8> = Color::srgb_u8(218, 112, 214);
+
+/// The CSS named color `palegoldenrod` (`#eee8aa`).
+pub const PALE_GOLDENROD: Color<SrgbU8> = Color::srgb_u8(238, 232, 170);
+
+/// The CSS named color `palegreen` (`#98fb98`).
+pub const PALE_GREEN: Color<SrgbU8> = Color::srgb_u8(152, 251, 152);
+
+/// The CSS named color `paleturquoise` (`#afeeee`).
+pub const PALE_TURQUOISE: Color<SrgbU8> = Color::srgb_u8(175, 238, 238);
+
+/// The CSS named color `palevioletred` (`#db7093`).
+pub const PALE_VIOLET_RED: Color<SrgbU8> = Color::srgb_u8(219, 112, 147);
+
+/// The CSS named color `papayawhip` (`#ffefd5`).
+pub const PAPAYA_WHIP: Color<SrgbU8> = Color::srgb_u8(255, 239, 213);
+
+/// The CSS named color `peachpuff` (`#ffdab9`).
+pub const PEACH_PUFF: Color<SrgbU8> = Color::srgb_u8(255, 218, 185);
+
+/// The CSS named color `peru` (`#cd853f`).
+pub const PERU: Color<SrgbU8> = Color::srgb_u8(205, 133, 63);
+
+/// The CSS named color `pink` (`#ffc0cb`).
+pub const PINK: Color<SrgbU8> = Color::srgb_u8(255, 192, 203);
+
+/// The CSS named color `plum` (`#dda0dd`).
+pub const PLUM: Color<SrgbU8> = Color::srgb_u8(221, 160, 221);
+
+/// The CSS named color `powderblue` (`#b0e0e6`).
+pub const POWDER_BLUE: Color<SrgbU8> = Color::srgb_u8(176, 224, 230);
+
+/// The CSS named color `purple` (`#800080`).
+pub const PURPLE: Color<SrgbU8> = Color::srgb_u8(128, 0, 128);
+
+/// The CSS named color `rebeccapurple` (`#663399`).
+pub const REBECCA_PURPLE: Color<SrgbU8> = Color::srgb_u8(102, 51, 153);
+
+/// The CSS named color `red` (`#ff0000`).
+pub const RED: Color<SrgbU8> = Color::srgb_u8(255, 0, 0);
+
+/// The CSS named color `rosybrown` (`#bc8f8f`).
+pub const ROSY_BROWN: Color<SrgbU8> = Color::srgb_u8(188, 143, 143);
+
+/// The CSS named color `royalblue` (`#4169e1`).
+pub const ROYAL_BLUE: Color<SrgbU8> = Color::srgb_u8(65, 105, 225);
+
+/// The CSS named color `saddlebrown` (`#8b4513`).
+pub const SADDLE_BROWN: Color<SrgbU8> = Color::srgb_u8(139, 69, 19);
+
+/// The CSS named color `salmon` (`#fa8072`).
+pub const SALMON: Color<SrgbU8> = Color::srgb_u8(250, 128, 114);
+
+/// The CSS named color `sandybrown` (`#f4a460`).
+pub const SANDY_BROWN: Color<SrgbU8> = Color::srgb_u8(244, 164, 96);
+
+/// The CSS named color `seagreen` (`#2e8b57`).
+pub const SEA_GREEN: Color<SrgbU8> = Color::srgb_u8(46, 139, 87);
+
+/// The CSS named color `seashell` (`#fff5ee`).
+pub const SEASHELL: Color<SrgbU8> = Color::srgb_u8(255, 245, 238);
+
+/// The CSS named color `sienna` (`#a0522d`).
+pub const SIENNA: Color<SrgbU8> = Color::srgb_u8(160, 82, 45);
+
+/// The CSS named color `silver` (`#c0c0c0`).
+pub const SILVER: Color<SrgbU8> = Color::srgb_u8(192, 192, 192);
+
+/// The CSS named color `skyblue` (`#87ceeb`).
+pub const SKY_BLUE: Color<SrgbU8> = Color::srgb_u8(135, 206, 235);
+
+/// The CSS named color `slateblue` (`#6a5acd`).
+pub const SLATE_BLUE: Color<SrgbU8> = Color::srgb_u8(106, 90, 205);
+
+/// The CSS named color `slategray` (`#708090`).
+pub const SLATE_GRAY: Color<SrgbU8> = Color::srgb_u8(112, 128, 144);
+
+/// The CSS named color `slategrey` (`#708090`).
+pub const SLATE_GREY: Color<SrgbU8> = Color::srgb_u8(112, 128, 144);
+
+/// The CSS named color `snow` (`#fffafa`).
+pub const SNOW: Color<SrgbU8> = Color::srgb_u8(255, 250, 250);
+
+/// The CSS named color `springgreen` (`#00ff7f`).
+pub const SPRING_GREEN: Color<SrgbU8> = Color::srgb_u8(0, 255, 127);
+
+/// The CSS named color `steelblue` (`#4682b4`).
+pub const STEEL_BLUE: Color<SrgbU8> = Color::srgb_u8(70, 130, 180);
+
+/// The CSS named color `tan` (`#d2b48c`).
+pub const TAN: Color<SrgbU8> = Color::srgb_u8(210, 180, 140);
+
+/// The CSS named color `teal` (`#008080`).
+pub const TEAL: Color<SrgbU8> = Color::srgb_u8(0, 128, 128);
+
+/// The CSS named color `thistle` (`#d8bfd8`).
+pub const THISTLE: Color<SrgbU8> = Color::srgb_u8(216, 191, 216);
+
+/// The CSS named color `tomato` (`#ff6347`).
+pub const TOMATO: Color<SrgbU8> = Color::srgb_u8(255, 99, 71);
+
+/// The CSS named color `turquoise` (`#40e0d0`).
+pub const TURQUOISE: Color<SrgbU8> = Color::srgb_u8(64, 224, 208);
+
+/// The CSS named color `violet` (`#ee82ee`).
+pub const VIOLET: Color<SrgbU8> = Color::srgb_u8(238, 130, 238);
+
+/// The CSS named color `wheat` (`#f5deb3`).
+pub const WHEAT: Color<SrgbU8> = Color::srgb_u8(245, 222, 179);
+
+/// The CSS named color `white` (`#ffffff`).
+pub const WHITE: Color<SrgbU8> = Color::srgb_u8(255, 255, 255);
+
+/// The CSS named color `whitesmoke` (`#f5f5f5`).
+pub const WHITE_SMOKE: Color<SrgbU8> = Color::srgb_u8(245, 245, 245);
+
+/// The CSS named color `yellow` (`#ffff00`).
+pub const YELLOW: Color<SrgbU8> = Color::srgb_u8(255, 255, 0);
+
+/// The CSS named color `yellowgreen` (`#9acd32`).
+pub const YELLOW_GREEN: Color<SrgbU8> = Color::srgb_u8(154, 205, 50);
+
+/// Every named color, keyed by its lowercase CSS name. `gray` and `grey` spellings are both included.
+pub const ALL: [(&str, Color<SrgbU8>); 148] = [
+    ("aliceblue", ALICE_BLUE),
+    ("antiquewhite", ANTIQUE_WHITE),
+    ("aqua", AQUA),
+    ("aquamarine", AQUAMARINE),
+    ("azure", AZURE),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHED_ALMOND),
+    ("blue", BLUE),
+    ("blueviolet", BLUE_VIOLET),
+    ("brown", BROWN),
+    ("burlywood", BURLY_WOOD),
+    ("cadetblue", CADET_BLUE),
+    ("chartreuse", CHARTREUSE),
+    ("chocolate", CHOCOLATE),
+    ("coral", CORAL),
+    ("cornflowerblue", CORNFLOWER_BLUE),
+    ("cornsilk", CORNSILK),
+    ("crimson", CRIMSON),
+    ("cyan", CYAN),
+    ("darkblue", DARK_BLUE),
+    ("darkcyan", DARK_CYAN),
+    ("darkgoldenrod", DARK_GOLDENROD),
+    ("darkgray", DARK_GRAY),
+    ("darkgreen", DARK_GREEN),
+    ("darkgrey", DARK_GREY),
+    ("darkkhaki", DARK_KHAKI),
+    ("darkmagenta", DARK_MAGENTA),
+    ("darkolivegreen", DARK_OLIVE_GREEN),
+    ("darkorange", DARK_ORANGE),
+    ("darkorchid", DARK_ORCHID),
+    ("darkred", DARK_RED),
+    ("darksalmon", DARK_SALMON),
+    ("darkseagreen", DARK_SEA_GREEN),
+    ("darkslateblue", DARK_SLATE_BLUE),
+    ("darkslategray", DARK_SLATE_GRAY),
+    ("darkslategrey", DARK_SLATE_GREY),
+    ("darkturquoise", DARK_TURQUOISE),
+    ("darkviolet", DARK_VIOLET),
+    ("deeppink", DEEP_PINK),
+    ("deepskyblue", DEEP_SKY_BLUE),
+    ("dimgray", DIM_GRAY),
+    ("dimgrey", DIM_GREY),
+    ("dodgerblue", DODGER_BLUE),
+    ("firebrick", FIREBRICK),
+    ("floralwhite", FLORAL_WHITE),
+    ("forestgreen", FOREST_GREEN),
+    ("fuchsia", FUCHSIA),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOST_WHITE),
+    ("gold", GOLD),
+    ("goldenrod", GOLDENROD),
+    ("gray", GRAY),
+    ("green", GREEN),
+    ("greenyellow", GREEN_YELLOW),
+    ("grey", GREY),
+    ("honeydew", HONEYDEW),
+    ("hotpink", HOT_PINK),
+    ("indianred", INDIAN_RED),
+    ("indigo", INDIGO),
+    ("ivory", IVORY),
+    ("khaki", KHAKI),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDER_BLUSH),
+    ("lawngreen", LAWN_GREEN),
+    ("lemonchiffon", LEMON_CHIFFON),
+    ("lightblue", LIGHT_BLUE),
+    ("lightcoral", LIGHT_CORAL),
+    ("lightcyan", LIGHT_CYAN),
+    ("lightgoldenrodyellow", LIGHT_GOLDENROD_YELLOW),
+    ("lightgray", LIGHT_GRAY),
+    ("lightgreen", LIGHT_GREEN),
+    ("lightgrey", LIGHT_GREY),
+    ("lightpink", LIGHT_PINK),
+    ("lightsalmon", LIGHT_SALMON),
+    ("lightseagreen", LIGHT_SEA_GREEN),
+    ("lightskyblue", LIGHT_SKY_BLUE),
+    ("lightslategray", LIGHT_SLATE_GRAY),
+    ("lightslategrey", LIGHT_SLATE_GREY),
+    ("lightsteelblue", LIGHT_STEEL_BLUE),
+    ("lightyellow", LIGHT_YELLOW),
+    ("lime", LIME),
+    ("limegreen", LIME_GREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("maroon", MAROON),
+    ("mediumaquamarine", MEDIUM_AQUAMARINE),
+    ("mediumblue", MEDIUM_BLUE),
+    ("mediumorchid", MEDIUM_ORCHID),
+    ("mediumpurple", MEDIUM_PURPLE),
+    ("mediumseagreen", MEDIUM_SEA_GREEN),
+    ("mediumslateblue", MEDIUM_SLATE_BLUE),
+    ("mediumspringgreen", MEDIUM_SPRING_GREEN),
+    ("mediumturquoise", MEDIUM_TURQUOISE),
+    ("mediumvioletred", MEDIUM_VIOLET_RED),
+    ("midnightblue", MIDNIGHT_BLUE),
+    ("mintcream", MINT_CREAM),
+    ("mistyrose", MISTY_ROSE),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJO_WHITE),
+    ("navy", NAVY),
+    ("oldlace", OLD_LACE),
+    ("olive", OLIVE),
+    ("olivedrab", OLIVE_DRAB),
+    ("orange", ORANGE),
+    ("orangered", ORANGE_RED),
+    ("orchid", ORCHID),
+    ("palegoldenrod", PALE_GOLDENROD),
+    ("palegreen", PALE_GREEN),
+    ("paleturquoise", PALE_TURQUOISE),
+    ("palevioletred", PALE_VIOLET_RED),
+    ("papayawhip", PAPAYA_WHIP),
+    ("peachpuff", PEACH_PUFF),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("plum", PLUM),
+    ("powderblue", POWDER_BLUE),
+    ("purple", PURPLE),
+    ("rebeccapurple", REBECCA_PURPLE),
+    ("red", RED),
+    ("rosybrown", ROSY_BROWN),
+    ("royalblue", ROYAL_BLUE),
+    ("saddlebrown", SADDLE_BROWN),
+    ("salmon", SALMON),
+    ("sandybrown", SANDY_BROWN),
+    ("seagreen", SEA_GREEN),
+    ("seashell", SEASHELL),
+    ("sienna", SIENNA),
+    ("silver", SILVER),
+    ("skyblue", SKY_BLUE),
+    ("slateblue", SLATE_BLUE),
+    ("slategray", SLATE_GRAY),
+    ("slategrey", SLATE_GREY),
+    ("snow", SNOW),
+    ("springgreen", SPRING_GREEN),
+    ("steelblue", STEEL_BLUE),
+    ("tan", TAN),
+    ("teal", TEAL),
+    ("thistle", THISTLE),
+    ("tomato", TOMATO),
+    ("turquoise", TURQUOISE),
+    ("violet", VIOLET),
+    ("wheat", WHEAT),
+    ("white", WHITE),
+    ("whitesmoke", WHITE_SMOKE),
+    ("yellow", YELLOW),
+    ("yellowgreen", YELLOW_GREEN),
+];
+
+/// Looks up a named color by its CSS name.
+///
+/// The lookup is ASCII case-insensitive and ignores spaces, underscores and hyphens, so `"cornflowerblue"`,
+/// `"CornflowerBlue"` and `"CORNFLOWER_BLUE"` all find [`CORNFLOWER_BLUE`].
+pub fn from_name(name: &str) -> Option<Color<SrgbU8>> {
+    let normalized = || {
+        name.bytes()
+            .filter(|b| !matches!(b, b' ' | b'_' | b'-'))
+            .map(|b| b.to_ascii_lowercase())
+    };
+
+    ALL.iter()
+        .find(|(candidate, _)| candidate.bytes().eq(normalized()))
+        .map(|&(_, color)| color)
+}
+
+/// Finds the named color which is perceptually closest to `color`, returning its CSS name and value.
+///
+/// Closeness is measured as the euclidean distance between the colors in [`Oklab`]. If several named colors
+/// share the same value (like `gray` and `grey`), the one which comes first alphabetically is returned.
+///
+/// ```
+/// # use colstodian::{named, Color};
+/// let (name, color) = named::nearest(Color::srgb_u8(250, 10, 10));
+///
+/// assert_eq!(name, "red");
+/// assert_eq!(color, named::RED);
+/// ```
+pub fn nearest(color: impl ColorInto<Color<Oklab>>) -> (&'static str, Color<SrgbU8>) {
+    let target = color.color_into().repr;
+
+    let mut best = ALL[0];
+    let mut best_distance = f32::INFINITY;
+    for &(name, candidate) in ALL.iter() {
+        let distance = candidate.convert::<Oklab>().repr.distance_squared(target);
+        if distance < best_distance {
+            best = (name, candidate);
+            best_distance = distance;
+        }
+    }
+
+    best
+}
@@ -0,0 +1,152 @@
+//! Colors of light sources specified by their correlated color temperature (CCT), in Kelvin, and estimation of the
+//! CCT of arbitrary colors.
+//!
+//! Temperatures are mapped to chromaticities in [`CieXyz`] along either the Planckian locus (the colors of ideal
+//! blackbody radiators, such as incandescent lights) or the CIE daylight locus (the colors of natural daylight,
+//! such as the D-series standard illuminants).
+//!
+//! ```
+//! use colstodian::{Color, temperature::*};
+//! use colstodian::basic_encodings::LinearSrgb;
+//! # use colstodian::equals_eps::*;
+//! # use colstodian::*;
+//!
+//! let candle = Color::<LinearSrgb>::from_temperature(1900.0);
+//! assert!(candle.r > candle.g && candle.g > candle.b);
+//!
+//! let (cct, duv) = cct_duv(Color::<LinearSrgb>::from_daylight_temperature(6504.0));
+//! assert_eq_eps!(cct, 6504.0, 5.0);
+//! assert!(duv > 0.0);
+//! ```
+
+use crate::encodings::CieXyz;
+use crate::linear_spaces;
+use crate::traits::{ConvertFrom, LinearConvertFromRaw};
+use crate::{Color, ColorInto};
+
+use glam::{DVec2, Vec2};
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use num_traits::float::Float;
+
+/// The lowest temperature, in Kelvin, supported along the Planckian locus.
+pub const MIN_PLANCKIAN_TEMPERATURE: f32 = 1000.0;
+
+/// The highest temperature, in Kelvin, supported along the Planckian locus.
+pub const MAX_PLANCKIAN_TEMPERATURE: f32 = 15000.0;
+
+/// The lowest temperature, in Kelvin, supported along the CIE daylight locus.
+pub const MIN_DAYLIGHT_TEMPERATURE: f32 = 4000.0;
+
+/// The highest temperature, in Kelvin, supported along the CIE daylight locus.
+pub const MAX_DAYLIGHT_TEMPERATURE: f32 = 25000.0;
+
+/// The CIE 1960 `uv` chromaticity of a blackbody at `kelvin`, using the rational approximation of Krystek (1985),
+/// which is within `9e-5` of the exact locus over its range.
+fn planckian_uv(kelvin: f64) -> DVec2 {
+    let t = kelvin;
+    let t2 = t * t;
+    let u = (0.860117757 + 1.54118254e-4 * t + 1.28641212e-7 * t2)
+        / (1.0 + 8.42420235e-4 * t + 7.08145163e-7 * t2);
+    let v = (0.317398726 + 4.22806245e-5 * t + 4.20481691e-8 * t2)
+        / (1.0 - 2.89741816e-5 * t + 1.61456053e-7 * t2);
+    DVec2::new(u, v)
+}
+
+/// The `xy` chromaticity of a blackbody radiator at `kelvin`.
+///
+/// `kelvin` is clamped to [`MIN_PLANCKIAN_TEMPERATURE`]`..=`[`MAX_PLANCKIAN_TEMPERATURE`].
+pub fn planckian_xy(kelvin: f32) -> Vec2 {
+    let kelvin = kelvin.clamp(MIN_PLANCKIAN_TEMPERATURE, MAX_PLANCKIAN_TEMPERATURE);
+    let uv = planckian_uv(kelvin as f64);
+    let denom = 2.0 * uv.x - 8.0 * uv.y + 4.0;
+    Vec2::new((3.0 * uv.x / denom) as f32, (2.0 * uv.y / denom) as f32)
+}
+
+/// The `xy` chromaticity of CIE daylight with a correlated color temperature of `kelvin`.
+///
+/// `kelvin` is clamped to [`MIN_DAYLIGHT_TEMPERATURE`]`..=`[`MAX_DAYLIGHT_TEMPERATURE`]. Note that the standard
+/// illuminant D65 has a temperature of about 6504K, rather than 6500K, due to a revision of Planck's constant.
+pub fn daylight_xy(kelvin: f32) -> Vec2 {
+    let t = kelvin.clamp(MIN_DAYLIGHT_TEMPERATURE, MAX_DAYLIGHT_TEMPERATURE) as f64;
+    let (t2, t3) = (t * t, t * t * t);
+    let x = if t <= 7000.0 {
+        -4.6070e9 / t3 + 2.9678e6 / t2 + 0.09911e3 / t + 0.244063
+    } else {
+        -2.0064e9 / t3 + 1.9018e6 / t2 + 0.24748e3 / t + 0.237040
+    };
+    let y = -3.0 * x * x + 2.870 * x - 0.275;
+    Vec2::new(x as f32, y as f32)
+}
+
+impl<E> Color<E>
+where
+    E: ConvertFrom<CieXyz>,
+    E::LinearSpace: LinearConvertFromRaw<linear_spaces::CieXYZ>,
+{
+    /// The color of a blackbody radiator at `kelvin`, normalized to a luminance of `1.0`.
+    ///
+    /// See [`planckian_xy`] for the supported range.
+    #[inline]
+    pub fn from_temperature(kelvin: f32) -> Self {
+        let xy = planckian_xy(kelvin);
+        Color::<CieXyz>::from_xy_luminance(xy.x, xy.y, 1.0).convert()
+    }
+
+    /// The color of CIE daylight with a correlated color temperature of `kelvin`, normalized to a luminance of `1.0`.
+    ///
+    /// See [`daylight_xy`] for the supported range.
+    #[inline]
+    pub fn from_daylight_temperature(kelvin: f32) -> Self {
+        let xy = daylight_xy(kelvin);
+        Color::<CieXyz>::from_xy_luminance(xy.x, xy.y, 1.0).convert()
+    }
+}
+
+/// Estimates the correlated color temperature (in Kelvin) of `color`, and its `Duv`: its signed distance from the
+/// Planckian locus in the CIE 1960 `uv` diagram, positive above the locus (towards green) and negative below
+/// it (towards magenta).
+///
+/// This follows Ohno's (2014) method: a cascading search for the nearest point on the locus, then a triangular
+/// solution between its neighbours. The CCT is only meaningful for colors close to the locus, typically with a
+/// `Duv` of magnitude below `0.05`, and is clamped to
+/// [`MIN_PLANCKIAN_TEMPERATURE`]`..=`[`MAX_PLANCKIAN_TEMPERATURE`].
+pub fn cct_duv(color: impl ColorInto<Color<CieXyz>>) -> (f32, f32) {
+    const SAMPLES: usize = 15;
+    const STEPS: usize = 6;
+
+    let xyz = color.color_into().repr.as_dvec3();
+    let denom = xyz.x + 15.0 * xyz.y + 3.0 * xyz.z;
+    let uv = DVec2::new(4.0 * xyz.x / denom, 6.0 * xyz.y / denom);
+
+    // the locus is close to evenly spaced in reciprocal temperature, so search in mireds
+    let mut low = 1e6 / MAX_PLANCKIAN_TEMPERATURE as f64;
+    let mut high = 1e6 / MIN_PLANCKIAN_TEMPERATURE as f64;
+    for _ in 0..STEPS {
+        let step = (high - low) / (SAMPLES - 1) as f64;
+        let mut nearest = 0;
+        let mut nearest_distance = f64::INFINITY;
+        for i in 0..SAMPLES {
+            let distance = planckian_uv(1e6 / (low + step * i as f64)).distance_squared(uv);
+            if distance < nearest_distance {
+                nearest = i;
+                nearest_distance = distance;
+            }
+        }
+        let center = low + step * nearest as f64;
+        (low, high) = ((center - step).max(low), (center + step).min(high));
+    }
+
+    let (t_low, t_high) = (1e6 / low, 1e6 / high);
+    let (uv_low, uv_high) = (planckian_uv(t_low), planckian_uv(t_high));
+    let d_low = uv.distance(uv_low);
+    let d_high = uv.distance(uv_high);
+    let l = uv_low.distance(uv_high);
+    let x = (d_low * d_low - d_high * d_high + l * l) / (2.0 * l);
+    let t = t_low + (t_high - t_low) * x / l;
+    let v_locus = uv_low.y + (uv_high.y - uv_low.y) * x / l;
+    let duv = (d_low * d_low - x * x).max(0.0).sqrt() * (uv.y - v_locus).signum();
+
+    let t = (t as f32).clamp(MIN_PLANCKIAN_TEMPERATURE, MAX_PLANCKIAN_TEMPERATURE);
+    (t, duv as f32)
+}
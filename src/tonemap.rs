@@ -0,0 +1,342 @@
+//! Tone mapping operators, which compress scene-referred [`SceneLinearSrgb`] colors with unbounded brightness
+//! into the displayable `0.0..=1.0` range.
+//!
+//! Every operator implements [`Tonemapper`], which produces a [`DisplayReferred`] [`Color`] in any encoding that
+//! can be converted from [`LinearSrgb`]. [`Color::tonemap`] is available as a shorthand.
+//!
+//! ```
+//! use colstodian::{Color, tonemap::*};
+//! use colstodian::basic_encodings::SrgbU8;
+//!
+//! let hdr = Color::scene_linear_srgb(4.0, 1.5, 0.25);
+//!
+//! let ldr: Color<SrgbU8> = AcesHill.tonemap(hdr);
+//! let punchy: Color<SrgbU8> = hdr.tonemap(&AgX::new(AgXLook::Punchy));
+//! # let _ = (ldr, punchy);
+//! ```
+//!
+//! Each operator only maps brightness; apply exposure to the scene-referred color first.
+
+use crate::encodings::{LinearSrgb, LinearSrgbA, SceneLinearSrgb, SceneLinearSrgbA};
+use crate::linear_spaces;
+use crate::traits::{ConvertFrom, DisplayReferred, LinearConvertFromRaw};
+use crate::{Color, ColorInto};
+
+use glam::{Mat3, Vec3};
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use num_traits::float::Float;
+
+/// A tone mapping operator, mapping scene-referred linear sRGB values to display-referred linear sRGB values.
+///
+/// This trait is object safe, so operators can be chosen at runtime as a `&dyn Tonemapper`.
+pub trait Tonemapper {
+    /// Maps a scene-referred linear sRGB triple to a display-referred linear sRGB triple, which should
+    /// be within `0.0..=1.0`.
+    fn tonemap_raw(&self, color: Vec3) -> Vec3;
+
+    /// Tone maps `color` and converts the result to `DstEnc`.
+    #[inline]
+    fn tonemap<DstEnc>(&self, color: impl ColorInto<Color<SceneLinearSrgb>>) -> Color<DstEnc>
+    where
+        Self: Sized,
+        DstEnc: DisplayReferred + ConvertFrom<LinearSrgb>,
+        DstEnc::LinearSpace: LinearConvertFromRaw<linear_spaces::Srgb>,
+    {
+        color.color_into().tonemap(self)
+    }
+}
+
+impl Color<SceneLinearSrgb> {
+    /// Tone maps `self` with `tonemapper` and converts the result to `DstEnc`.
+    ///
+    /// See the [`tonemap`][crate::tonemap] module for the available operators.
+    #[inline]
+    pub fn tonemap<DstEnc, T>(self, tonemapper: &T) -> Color<DstEnc>
+    where
+        T: Tonemapper + ?Sized,
+        DstEnc: DisplayReferred + ConvertFrom<LinearSrgb>,
+        DstEnc::LinearSpace: LinearConvertFromRaw<linear_spaces::Srgb>,
+    {
+        Color::<LinearSrgb>::from_repr(tonemapper.tonemap_raw(self.repr)).convert()
+    }
+}
+
+impl Color<SceneLinearSrgbA> {
+    /// Tone maps the color components of `self` with `tonemapper`, keeping alpha unchanged, and converts the
+    /// result to `DstEnc`.
+    ///
+    /// See the [`tonemap`][crate::tonemap] module for the available operators.
+    #[inline]
+    pub fn tonemap<DstEnc, T>(self, tonemapper: &T) -> Color<DstEnc>
+    where
+        T: Tonemapper + ?Sized,
+        DstEnc: DisplayReferred + ConvertFrom<LinearSrgbA>,
+        DstEnc::LinearSpace: LinearConvertFromRaw<linear_spaces::Srgb>,
+    {
+        let mapped = tonemapper.tonemap_raw(self.repr.truncate());
+        Color::<LinearSrgbA>::from_repr(mapped.extend(self.repr.w)).convert()
+    }
+}
+
+/// The simple Reinhard operator, `x / (1 + x)`, applied per channel.
+///
+/// Never quite reaches white, so bright colors look somewhat dull.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Reinhard;
+
+impl Tonemapper for Reinhard {
+    #[inline]
+    fn tonemap_raw(&self, color: Vec3) -> Vec3 {
+        let color = color.max(Vec3::ZERO);
+        color / (Vec3::ONE + color)
+    }
+}
+
+/// The extended Reinhard operator, `x * (1 + x / white²) / (1 + x)`, applied per channel.
+///
+/// Input values of `white` and above map to `1.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReinhardExtended {
+    /// The smallest input value which is mapped to pure white.
+    pub white: f32,
+}
+
+impl ReinhardExtended {
+    /// Creates the operator with the given white point.
+    #[inline]
+    pub const fn new(white: f32) -> Self {
+        Self { white }
+    }
+}
+
+impl Default for ReinhardExtended {
+    #[inline]
+    fn default() -> Self {
+        Self::new(4.0)
+    }
+}
+
+impl Tonemapper for ReinhardExtended {
+    #[inline]
+    fn tonemap_raw(&self, color: Vec3) -> Vec3 {
+        let color = color.max(Vec3::ZERO);
+        let mapped = color * (Vec3::ONE + color / (self.white * self.white)) / (Vec3::ONE + color);
+        mapped.min(Vec3::ONE)
+    }
+}
+
+/// John Hable's filmic operator from Uncharted 2.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hable {
+    /// Exposure applied before the curve. The reference implementation uses `2.0`.
+    pub exposure_bias: f32,
+    /// The linear white point, which is mapped to `1.0`. The reference implementation uses `11.2`.
+    pub white: f32,
+}
+
+impl Hable {
+    #[inline]
+    fn partial(x: Vec3) -> Vec3 {
+        const A: f32 = 0.15; // shoulder strength
+        const B: f32 = 0.50; // linear strength
+        const C: f32 = 0.10; // linear angle
+        const D: f32 = 0.20; // toe strength
+        const E: f32 = 0.02; // toe numerator
+        const F: f32 = 0.30; // toe denominator
+
+        ((x * (A * x + C * B) + D * E) / (x * (A * x + B) + D * F)) - Vec3::splat(E / F)
+    }
+}
+
+impl Default for Hable {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            exposure_bias: 2.0,
+            white: 11.2,
+        }
+    }
+}
+
+impl Tonemapper for Hable {
+    #[inline]
+    fn tonemap_raw(&self, color: Vec3) -> Vec3 {
+        let color = color.max(Vec3::ZERO);
+        let mapped =
+            Self::partial(color * self.exposure_bias) / Self::partial(Vec3::splat(self.white));
+        mapped.clamp(Vec3::ZERO, Vec3::ONE)
+    }
+}
+
+/// Krzysztof Narkowicz's curve fit of the ACES reference rendering transform, applied per channel.
+///
+/// This is cheap but oversaturates bright colors compared to [`AcesHill`]. The fit was made against
+/// an input pre-exposed by `0.6`, so scene values may need to be scaled down to match the brightness of
+/// other operators.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AcesNarkowicz;
+
+impl Tonemapper for AcesNarkowicz {
+    #[inline]
+    fn tonemap_raw(&self, color: Vec3) -> Vec3 {
+        const A: f32 = 2.51;
+        const B: f32 = 0.03;
+        const C: f32 = 2.43;
+        const D: f32 = 0.59;
+        const E: f32 = 0.14;
+
+        let x = color.max(Vec3::ZERO);
+        ((x * (A * x + B)) / (x * (C * x + D) + E)).clamp(Vec3::ZERO, Vec3::ONE)
+    }
+}
+
+/// Stephen Hill's fit of the ACES reference rendering and output device transforms, including the
+/// conversion to and from the ACES color space.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AcesHill;
+
+impl AcesHill {
+    /// sRGB => XYZ => D65_2_D60 => AP1 => RRT_SAT
+    #[rustfmt::skip]
+    const INPUT: Mat3 = Mat3::from_cols_array(&[
+        0.59719, 0.07600, 0.02840,
+        0.35458, 0.90834, 0.13383,
+        0.04823, 0.01566, 0.83777,
+    ]);
+
+    /// ODT_SAT => XYZ => D60_2_D65 => sRGB
+    #[rustfmt::skip]
+    const OUTPUT: Mat3 = Mat3::from_cols_array(&[
+        1.60475, -0.10208, -0.00327,
+        -0.53108, 1.10813, -0.07276,
+        -0.07367, -0.00605, 1.07602,
+    ]);
+}
+
+impl Tonemapper for AcesHill {
+    #[inline]
+    fn tonemap_raw(&self, color: Vec3) -> Vec3 {
+        let v = Self::INPUT * color.max(Vec3::ZERO);
+        let a = v * (v + Vec3::splat(0.0245786)) - Vec3::splat(0.000090537);
+        let b = v * (0.983729 * v + Vec3::splat(0.432951)) + Vec3::splat(0.238081);
+        (Self::OUTPUT * (a / b)).clamp(Vec3::ZERO, Vec3::ONE)
+    }
+}
+
+/// The creative "look" applied by [`AgX`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AgXLook {
+    /// The base AgX transform, with no look applied.
+    #[default]
+    Base,
+    /// Increased contrast and saturation.
+    Punchy,
+    /// A warm, slightly desaturated look.
+    Golden,
+}
+
+/// Troy Sobotka's AgX view transform, using the widely used polynomial approximation of its default contrast curve.
+///
+/// AgX desaturates very bright colors towards white rather than skewing their hue, which tends to look
+/// more natural than per-channel operators.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AgX {
+    /// The look applied after the base transform.
+    pub look: AgXLook,
+}
+
+// the matrices are kept exactly as published, so results match other implementations
+#[allow(clippy::excessive_precision)]
+impl AgX {
+    #[rustfmt::skip]
+    const INSET: Mat3 = Mat3::from_cols_array(&[
+        0.842479062253094, 0.0423282422610123, 0.0423756549057051,
+        0.0784335999999992, 0.878468636469772, 0.0784336,
+        0.0792237451477643, 0.0791661274605434, 0.879142973793104,
+    ]);
+
+    #[rustfmt::skip]
+    const OUTSET: Mat3 = Mat3::from_cols_array(&[
+        1.19687900512017, -0.0528968517574562, -0.0529716355144438,
+        -0.0980208811401368, 1.15190312990417, -0.0980434501171241,
+        -0.0990297440797205, -0.0989611768448433, 1.15107367264116,
+    ]);
+
+    const MIN_EV: f32 = -12.47393;
+    const MAX_EV: f32 = 4.026069;
+
+    /// Creates the operator with the given look.
+    #[inline]
+    pub const fn new(look: AgXLook) -> Self {
+        Self { look }
+    }
+
+    #[inline]
+    fn contrast(x: Vec3) -> Vec3 {
+        let x2 = x * x;
+        let x4 = x2 * x2;
+        15.5 * x4 * x2 - 40.14 * x4 * x + 31.96 * x4 - 6.868 * x2 * x + 0.4298 * x2 + 0.1191 * x
+            - Vec3::splat(0.00232)
+    }
+
+    #[inline]
+    fn apply_look(&self, color: Vec3) -> Vec3 {
+        let (slope, power, saturation) = match self.look {
+            AgXLook::Base => return color,
+            AgXLook::Punchy => (Vec3::ONE, Vec3::splat(1.35), 1.4),
+            AgXLook::Golden => (Vec3::new(1.0, 0.9, 0.5), Vec3::splat(0.8), 0.8),
+        };
+        let luma = color.dot(Vec3::new(0.2126, 0.7152, 0.0722));
+        let graded = (color * slope).max(Vec3::ZERO);
+        let graded = Vec3::new(
+            graded.x.powf(power.x),
+            graded.y.powf(power.y),
+            graded.z.powf(power.z),
+        );
+        Vec3::splat(luma) + saturation * (graded - Vec3::splat(luma))
+    }
+}
+
+impl Tonemapper for AgX {
+    #[inline]
+    fn tonemap_raw(&self, color: Vec3) -> Vec3 {
+        let inset = Self::INSET * color.max(Vec3::ZERO);
+        let log = Vec3::new(inset.x.log2(), inset.y.log2(), inset.z.log2())
+            .clamp(Vec3::splat(Self::MIN_EV), Vec3::splat(Self::MAX_EV));
+        let normalized = (log - Vec3::splat(Self::MIN_EV)) / (Self::MAX_EV - Self::MIN_EV);
+        let display = Self::OUTSET * self.apply_look(Self::contrast(normalized));
+        // the curve produces values encoded for a 2.2 gamma display, so decode them back to linear
+        display.clamp(Vec3::ZERO, Vec3::ONE).powf(2.2)
+    }
+}
+
+/// The Khronos PBR Neutral operator, which keeps colors below a brightness threshold nearly unchanged so
+/// that base colors in product renders stay faithful.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct KhronosPbrNeutral;
+
+impl Tonemapper for KhronosPbrNeutral {
+    #[inline]
+    fn tonemap_raw(&self, color: Vec3) -> Vec3 {
+        const START_COMPRESSION: f32 = 0.8 - 0.04;
+        const DESATURATION: f32 = 0.15;
+
+        let mut color = color.max(Vec3::ZERO);
+        let x = color.min_element();
+        let offset = if x < 0.08 { x - 6.25 * x * x } else { 0.04 };
+        color -= Vec3::splat(offset);
+
+        let peak = color.max_element();
+        if peak < START_COMPRESSION {
+            return color;
+        }
+
+        let d = 1.0 - START_COMPRESSION;
+        let new_peak = 1.0 - d * d / (peak + d - START_COMPRESSION);
+        color *= new_peak / peak;
+
+        let g = 1.0 - 1.0 / (DESATURATION * (peak - new_peak) + 1.0);
+        color.lerp(Vec3::splat(new_peak), g)
+    }
+}